@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 use std::env;
 use dotenv;
 use square_ox::builder::Builder;
-use square_ox::errors::ValidationError;
 use square_ox::objects::{Response, Address};
 
 
@@ -71,8 +70,7 @@ async fn list_availability(
         .location_id(location_id.clone())
         .start_at_range(query_params.start_at, query_params.end_at)
         .segment_filters(query_params.segment_id)
-        .build()
-        .await
+        .build_sync()
     {
         Ok(p) => p,
         Err(e) => {
@@ -85,9 +83,9 @@ async fn list_availability(
         Ok(r) => HttpResponse::Ok()
             .set_header("Access-Control-Allow-Origin", "*")
             .json(r),
-        Err(_) => {
+        Err(e) => {
             println!("Failed to create payment");
-            HttpResponse::BadRequest().json(ValidationError)
+            HttpResponse::BadRequest().json(e)
         }
     }
 }
@@ -0,0 +1,752 @@
+/*!
+Derive macros generating the builder implementations of [square-ox](https://docs.rs/square-ox)
+objects.
+
+`#[derive(SquareBuilder)]` on a struct generates
+* a setter on `Builder<Struct, _>` for every field, taking anything that converts into the type of
+  the field, and an `add_` helper pushing a single element for every `Vec` field or inserting a
+  single entry for every `HashMap` field,
+* an implementation of `Validate` checking the fields marked with `#[required]` and
+  `#[validate(...)]`, which reports all violations at once,
+* an implementation of `AddField` for every field marked with `#[sub_builder]`.
+
+# Attributes
+* `#[required]` - the field has to be set, or hold at least one element for `Vec` and `String`
+  fields. Fields of any other type, such as ids, must not be empty according to their `is_empty`
+  method.
+* `#[validate(non_empty)]` - an optional `Vec` field has to hold at least one element when set.
+* `#[validate(one_of = "group")]` - at least one of the fields of the group has to be set.
+* `#[validate(with = "path::to::function")]` - the function, taking a reference to the field and
+  returning a `Result<(), String>`, decides whether its value is accepted.
+* `#[sub_builder]` - objects of the type of the field, or of the type of its elements, can be
+  built by a sub builder and added to this object.
+* `#[idempotency_key]` - the field is set to a new idempotency key whenever the object is
+  validated, and gets no setter.
+* `#[builder(skip)]` - the field gets no setter, apart from the ones added by `preset`.
+* `#[builder(each = "name")]` - the name of the helper pushing a single element, instead of the
+  `add_` prefixed singular of the field name.
+* `#[builder(setter = "name")]` - the name of the setter, instead of the name of the field.
+* `#[builder(preset(name = "expression", ...))]` - adds a setter without arguments for every name,
+  which sets the field to the value of the expression, e.g. `active = "VendorStatus::Active"`.
+* `#[builder(flatten(field: Type, ...))]` - on a field holding an object, or an `Option` of one,
+  forwards the setters of the listed fields of that object, instead of generating a setter for the
+  whole field. The listed fields take doc comments and the `#[builder(...)]` attributes of the
+  fields of the struct, and an `Option` field is set to its default value before the first
+  forwarded setter is applied.
+* `#[validate(with = "path::to::function")]` - on the struct, the function, taking a reference to
+  the object and a `&mut ValidationError`, records the violations of rules spanning several fields.
+* `#[builder(skip_validate)]` - on the struct, leaves the implementation of `Validate` to be
+  written by hand.
+
+Fields holding a `Timestamp`, `IsoDuration` or `TimeRange`, or an `Option` of one, including the
+listed fields of flattened objects, are checked by the `check` method of their value, which
+records the timestamps and durations that Square would not accept.
+
+The path of a field within violations follows its `#[serde(rename = "...")]` attribute.
+ */
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, Fields, GenericArgument, Ident,
+    LitStr, Path, PathArguments, Result, Token, Type,
+};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+
+#[proc_macro_derive(
+    SquareBuilder,
+    attributes(required, validate, sub_builder, idempotency_key, builder)
+)]
+pub fn derive_square_builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+// the shape of the type of a field, which decides how it is set, pushed to and validated
+enum Shape {
+    Option(Type),
+    OptionVec(Type),
+    OptionMap(Type, Type),
+    Vec(Type),
+    Map(Type, Type),
+    String,
+    Other,
+}
+
+struct FieldSpec {
+    ident: Ident,
+    ty: Type,
+    shape: Shape,
+    docs: Vec<Attribute>,
+    path: String,
+    required: bool,
+    non_empty: bool,
+    one_of: Option<String>,
+    with: Option<Path>,
+    sub_builder: bool,
+    idempotency_key: bool,
+    skip: bool,
+    each: Option<Ident>,
+    setter: Option<Ident>,
+    presets: Vec<(Ident, Expr)>,
+    flatten: Vec<FieldSpec>,
+}
+
+// a field of a flattened object, `field: Type` preceded by its attributes
+struct FlattenedField {
+    attrs: Vec<Attribute>,
+    ident: Ident,
+    ty: Type,
+}
+
+impl Parse for FlattenedField {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+
+        Ok(FlattenedField { attrs, ident, ty })
+    }
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "SquareBuilder can not be derived for generic structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(
+                &input.ident,
+                "SquareBuilder can only be derived for structs with named fields",
+            )),
+        },
+        _ => return Err(Error::new_spanned(
+            &input.ident,
+            "SquareBuilder can only be derived for structs",
+        )),
+    };
+
+    let mut skip_validate = false;
+    let mut rules = Vec::new();
+    for attr in &input.attrs {
+        if attr.path().is_ident("builder") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip_validate") {
+                    skip_validate = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip_validate`"))
+                }
+            })?;
+        } else if attr.path().is_ident("validate") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("with") {
+                    rules.push(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `with`"))
+                }
+            })?;
+        }
+    }
+
+    let specs = fields.iter()
+        .map(|field| parse_field(field.ident.clone().unwrap(), field.ty.clone(), &field.attrs))
+        .collect::<Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let setters = specs.iter().map(|spec| setters(spec, &quote!(self.body)));
+    let add_fields = specs.iter()
+        .filter(|spec| spec.sub_builder)
+        .map(|spec| add_field(name, spec));
+    let validate = if skip_validate {
+        quote!()
+    } else {
+        validate(name, &specs, &rules)?
+    };
+
+    Ok(quote! {
+        impl<__P: ::square_ox::builder::ParentBuilder> ::square_ox::builder::Builder<#name, __P> {
+            #(#setters)*
+        }
+
+        #validate
+
+        #(#add_fields)*
+    })
+}
+
+fn parse_field(ident: Ident, ty: Type, attrs: &[Attribute]) -> Result<FieldSpec> {
+    let mut spec = FieldSpec {
+        path: ident.to_string(),
+        shape: shape(&ty),
+        ident,
+        ty,
+        docs: attrs.iter().filter(|attr| attr.path().is_ident("doc")).cloned().collect(),
+        required: false,
+        non_empty: false,
+        one_of: None,
+        with: None,
+        sub_builder: false,
+        idempotency_key: false,
+        skip: false,
+        each: None,
+        setter: None,
+        presets: Vec::new(),
+        flatten: Vec::new(),
+    };
+
+    for attr in attrs {
+        if attr.path().is_ident("required") {
+            spec.required = true;
+        } else if attr.path().is_ident("sub_builder") {
+            spec.sub_builder = true;
+        } else if attr.path().is_ident("idempotency_key") {
+            spec.idempotency_key = true;
+        } else if attr.path().is_ident("validate") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("non_empty") {
+                    spec.non_empty = true;
+                } else if meta.path.is_ident("one_of") {
+                    spec.one_of = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("with") {
+                    spec.with = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `non_empty`, `one_of` or `with`"));
+                }
+                Ok(())
+            })?;
+        } else if attr.path().is_ident("builder") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    spec.skip = true;
+                } else if meta.path.is_ident("each") {
+                    let each = meta.value()?.parse::<LitStr>()?;
+                    spec.each = Some(Ident::new(&each.value(), each.span()));
+                } else if meta.path.is_ident("setter") {
+                    let setter = meta.value()?.parse::<LitStr>()?;
+                    spec.setter = Some(Ident::new(&setter.value(), setter.span()));
+                } else if meta.path.is_ident("preset") {
+                    meta.parse_nested_meta(|preset| {
+                        let name = preset.path.get_ident()
+                            .ok_or_else(|| preset.error("expected the name of the setter"))?
+                            .clone();
+                        let value = preset.value()?.parse::<LitStr>()?.parse::<Expr>()?;
+                        spec.presets.push((name, value));
+                        Ok(())
+                    })?;
+                } else if meta.path.is_ident("flatten") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let fields = Punctuated::<FlattenedField, Token![,]>::parse_terminated(&content)?;
+                    for field in fields {
+                        let field = parse_field(field.ident, field.ty, &field.attrs)?;
+                        if field.required || field.non_empty || field.one_of.is_some()
+                            || field.with.is_some() || field.sub_builder || field.idempotency_key {
+                            return Err(Error::new_spanned(
+                                &field.ident,
+                                "flattened fields are validated by their own object and only \
+                                 take `#[builder(...)]` attributes",
+                            ));
+                        }
+                        spec.flatten.push(field);
+                    }
+                } else {
+                    return Err(meta.error(
+                        "expected `skip`, `each`, `setter`, `preset` or `flatten`",
+                    ));
+                }
+                Ok(())
+            })?;
+        } else if attr.path().is_ident("serde") {
+            // only the rename is of interest, everything else is left to serde
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") && meta.input.peek(Token![=]) {
+                    spec.path = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.input.peek(Token![=]) {
+                    meta.value()?.parse::<Expr>()?;
+                } else if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|nested| {
+                        if nested.input.peek(Token![=]) {
+                            nested.value()?.parse::<Expr>()?;
+                        }
+                        Ok(())
+                    })?;
+                }
+                Ok(())
+            });
+        }
+    }
+
+    if spec.non_empty && !matches!(spec.shape, Shape::OptionVec(_)) {
+        return Err(Error::new_spanned(
+            &spec.ty,
+            "#[validate(non_empty)] can only be used on `Option<Vec<_>>` fields, \
+             use #[required] on `Vec` fields",
+        ));
+    }
+    if spec.sub_builder && matches!(spec.shape, Shape::String | Shape::Map(..) | Shape::OptionMap(..)) {
+        return Err(Error::new_spanned(
+            &spec.ty,
+            "#[sub_builder] can not be used on `String` and `HashMap` fields",
+        ));
+    }
+    if !spec.flatten.is_empty() && !matches!(spec.shape, Shape::Option(_) | Shape::Other) {
+        return Err(Error::new_spanned(
+            &spec.ty,
+            "#[builder(flatten(...))] can only be used on fields holding an object or an `Option` \
+             of one",
+        ));
+    }
+
+    Ok(spec)
+}
+
+// the type argument of the type if its last path segment is the given name, e.g. `T` of `Vec<T>`
+fn inner_type<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    match type_arguments(ty, name)?.as_slice() {
+        [ty] => Some(ty),
+        _ => None,
+    }
+}
+
+// the key and value types of the type if its last path segment is the given name, e.g. `K` and `V`
+// of `HashMap<K, V>`
+fn entry_types(ty: &Type, name: &str) -> Option<(Type, Type)> {
+    match type_arguments(ty, name)?.as_slice() {
+        [key, value] => Some(((*key).clone(), (*value).clone())),
+        _ => None,
+    }
+}
+
+fn type_arguments<'a>(ty: &'a Type, name: &str) -> Option<Vec<&'a Type>> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != name {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter()
+            .map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+fn shape(ty: &Type) -> Shape {
+    if let Some(inner) = inner_type(ty, "Option") {
+        if let Some(element) = inner_type(inner, "Vec") {
+            Shape::OptionVec(element.clone())
+        } else if let Some((key, value)) = entry_types(inner, "HashMap") {
+            Shape::OptionMap(key, value)
+        } else {
+            Shape::Option(inner.clone())
+        }
+    } else if let Some(element) = inner_type(ty, "Vec") {
+        Shape::Vec(element.clone())
+    } else if let Some((key, value)) = entry_types(ty, "HashMap") {
+        Shape::Map(key, value)
+    } else if matches!(ty, Type::Path(path) if path.path.is_ident("String")) {
+        Shape::String
+    } else {
+        Shape::Other
+    }
+}
+
+// the name of the helper pushing a single element, e.g. add_line_item for line_items
+fn each_name(spec: &FieldSpec) -> Ident {
+    if let Some(each) = &spec.each {
+        return each.clone();
+    }
+
+    let name = spec.ident.to_string();
+    let singular = if let Some(stem) = name.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if name.ends_with("sses") || name.ends_with("xes") || name.ends_with("ches") {
+        name[..name.len() - 2].to_string()
+    } else if let Some(stem) = name.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        name
+    };
+
+    format_ident!("add_{}", singular)
+}
+
+// the type of the argument of a setter taking a value of the type, and the expression converting
+// the argument into the value. Primitives are taken as they are, so that literals like `10` keep
+// inferring their type from the field.
+fn argument(ty: &Type, name: &Ident) -> (TokenStream2, TokenStream2) {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128",
+        "usize", "f32", "f64",
+    ];
+
+    match ty {
+        Type::Path(path) if PRIMITIVES.iter().any(|primitive| path.path.is_ident(primitive)) => {
+            (quote!(#ty), quote!(#name))
+        },
+        _ => (quote!(impl Into<#ty>), quote!(#name.into())),
+    }
+}
+
+// the setters of the field, which set it within the object the expression `body` refers to
+fn setters(spec: &FieldSpec, body: &TokenStream2) -> TokenStream2 {
+    if spec.idempotency_key {
+        return quote!();
+    }
+
+    let docs = &spec.docs;
+    let field = {
+        let ident = &spec.ident;
+        quote!(#body.#ident)
+    };
+    let ident = spec.setter.as_ref().unwrap_or(&spec.ident);
+
+    let presets = spec.presets.iter().map(|(name, value)| {
+        let value = match spec.shape {
+            Shape::Option(_) | Shape::OptionVec(_) | Shape::OptionMap(..) => quote!(Some(#value)),
+            _ => quote!(#value),
+        };
+
+        quote! {
+            pub fn #name(mut self) -> Self {
+                #field = #value;
+
+                self
+            }
+        }
+    });
+    let presets = quote!(#(#presets)*);
+
+    if spec.skip {
+        return presets;
+    }
+
+    if !spec.flatten.is_empty() {
+        let object = match spec.shape {
+            Shape::Option(_) => quote!(#field.get_or_insert_with(Default::default)),
+            _ => field,
+        };
+        let setters = spec.flatten.iter().map(|spec| setters(spec, &object));
+
+        return quote! {
+            #presets
+            #(#setters)*
+        };
+    }
+
+    let setters = match &spec.shape {
+        Shape::Option(inner) => {
+            let (param, value) = argument(inner, ident);
+
+            quote! {
+                #(#docs)*
+                pub fn #ident(mut self, #ident: #param) -> Self {
+                    #field = Some(#value);
+
+                    self
+                }
+            }
+        },
+        Shape::OptionVec(element) | Shape::Vec(element) => {
+            let each = each_name(spec);
+            let each_doc = format!("Adds a single element to `{}`.", ident);
+            let (param, value) = argument(element, &format_ident!("element"));
+            let (set, list) = match spec.shape {
+                Shape::Vec(_) => (quote!(#ident.into()), field.clone()),
+                _ => (quote!(Some(#ident.into())), quote!(#field.get_or_insert_with(Vec::new))),
+            };
+
+            quote! {
+                #(#docs)*
+                pub fn #ident(mut self, #ident: impl Into<Vec<#element>>) -> Self {
+                    #field = #set;
+
+                    self
+                }
+
+                #[doc = #each_doc]
+                pub fn #each(mut self, element: #param) -> Self {
+                    #list.push(#value);
+
+                    self
+                }
+            }
+        },
+        Shape::OptionMap(key, entry) | Shape::Map(key, entry) => {
+            let each = each_name(spec);
+            let each_doc = format!("Inserts a single entry into `{}`.", ident);
+            let (key_param, key_value) = argument(key, &format_ident!("key"));
+            let (param, value) = argument(entry, &format_ident!("value"));
+            let (set, map) = match spec.shape {
+                Shape::Map(..) => (quote!(#ident.into()), field.clone()),
+                _ => (
+                    quote!(Some(#ident.into())),
+                    quote!(#field.get_or_insert_with(Default::default)),
+                ),
+            };
+
+            quote! {
+                #(#docs)*
+                pub fn #ident(mut self, #ident: impl Into<::std::collections::HashMap<#key, #entry>>)
+                    -> Self {
+                    #field = #set;
+
+                    self
+                }
+
+                #[doc = #each_doc]
+                pub fn #each(mut self, key: #key_param, value: #param) -> Self {
+                    #map.insert(#key_value, #value);
+
+                    self
+                }
+            }
+        },
+        Shape::String | Shape::Other => {
+            let (param, value) = argument(&spec.ty, ident);
+
+            quote! {
+                #(#docs)*
+                pub fn #ident(mut self, #ident: #param) -> Self {
+                    #field = #value;
+
+                    self
+                }
+            }
+        },
+    };
+
+    quote! {
+        #setters
+        #presets
+    }
+}
+
+// an expression that is true when the field of self is set
+fn is_set(spec: &FieldSpec) -> TokenStream2 {
+    let ident = &spec.ident;
+
+    match &spec.shape {
+        Shape::Option(_) => quote!(self.#ident.is_some()),
+        Shape::OptionVec(_) | Shape::OptionMap(..) => {
+            quote!(self.#ident.as_ref().map_or(false, |v| !v.is_empty()))
+        },
+        Shape::Vec(_) | Shape::Map(..) | Shape::String => quote!(!self.#ident.is_empty()),
+        Shape::Other => quote!(true),
+    }
+}
+
+// the types whose values are checked by their own `check` method, such as timestamps that Square
+// would not accept
+const CHECKED: &[&str] = &["Timestamp", "IsoDuration", "TimeRange"];
+
+fn is_checked(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.path.segments.last()
+            .is_some_and(|segment| CHECKED.iter().any(|name| segment.ident == name)),
+        _ => false,
+    }
+}
+
+// the checks of the value of the field within `object`, and of the values of its flattened fields
+fn value_checks(spec: &FieldSpec, object: &TokenStream2, prefix: &str) -> Vec<TokenStream2> {
+    let ident = &spec.ident;
+    let path = format!("{}{}", prefix, spec.path);
+
+    if !spec.flatten.is_empty() {
+        let prefix = format!("{}.", path);
+        let (inner, open) = match spec.shape {
+            Shape::Option(_) => (quote!(object), quote!(if let Some(object) = &#object.#ident)),
+            _ => (quote!(#object.#ident), quote!()),
+        };
+        let checks: Vec<_> = spec.flatten.iter()
+            .flat_map(|spec| value_checks(spec, &inner, &prefix))
+            .collect();
+
+        return if checks.is_empty() {
+            checks
+        } else {
+            vec![quote!(#open { #(#checks)* })]
+        };
+    }
+
+    match &spec.shape {
+        Shape::Option(inner) if is_checked(inner) => vec![quote! {
+            if let Some(value) = &#object.#ident {
+                value.check(#path, &mut errors);
+            }
+        }],
+        Shape::Other if is_checked(&spec.ty) => vec![quote!(#object.#ident.check(#path, &mut errors);)],
+        _ => Vec::new(),
+    }
+}
+
+fn validate(name: &Ident, specs: &[FieldSpec], rules: &[Path]) -> Result<TokenStream2> {
+    let mut checks = Vec::new();
+
+    for spec in specs {
+        checks.extend(value_checks(spec, &quote!(self), ""));
+
+        let ident = &spec.ident;
+        let path = &spec.path;
+
+        if spec.required {
+            checks.push(match spec.shape {
+                Shape::Option(_) => quote!(errors.require(#path, &self.#ident);),
+                Shape::OptionVec(_) | Shape::OptionMap(..) => {
+                    let set = is_set(spec);
+                    quote! {
+                        errors.check(#set, #path, ::square_ox::errors::ViolationReason::Required);
+                    }
+                },
+                Shape::Vec(_) | Shape::Map(..) => quote! {
+                    errors.check(!self.#ident.is_empty(), #path,
+                                 ::square_ox::errors::ViolationReason::Empty);
+                },
+                Shape::String | Shape::Other => quote! {
+                    errors.check(!self.#ident.is_empty(), #path,
+                                 ::square_ox::errors::ViolationReason::Required);
+                },
+            });
+        }
+
+        if spec.non_empty {
+            checks.push(quote! {
+                errors.check(self.#ident.as_ref().map_or(true, |v| !v.is_empty()), #path,
+                             ::square_ox::errors::ViolationReason::Empty);
+            });
+        }
+
+        if let Some(with) = &spec.with {
+            checks.push(quote! {
+                if let Err(reason) = #with(&self.#ident) {
+                    errors.invalid(#path, reason);
+                }
+            });
+        }
+    }
+
+    // the one_of groups, in the order in which they first appear
+    let mut groups: Vec<(&str, Vec<&FieldSpec>)> = Vec::new();
+    for spec in specs {
+        if let Some(group) = &spec.one_of {
+            match groups.iter_mut().find(|(name, _)| name == group) {
+                Some((_, members)) => members.push(spec),
+                None => groups.push((group, vec![spec])),
+            }
+        }
+    }
+    for (_, members) in groups {
+        let paths = members.iter().map(|spec| &spec.path);
+        let set = members.iter().map(|spec| is_set(spec));
+
+        checks.push(quote! {
+            errors.require_one_of("", &[#((#paths, #set)),*]);
+        });
+    }
+
+    for rule in rules {
+        checks.push(quote!(#rule(&self, &mut errors);));
+    }
+
+    let keys: Vec<_> = specs.iter()
+        .filter(|spec| spec.idempotency_key)
+        .map(|spec| {
+            let ident = &spec.ident;
+            match spec.shape {
+                Shape::Option(_) => Ok(quote! {
+                    self.#ident = Some(::square_ox::builder::new_idempotency_key());
+                }),
+                Shape::String => Ok(quote! {
+                    self.#ident = ::square_ox::builder::new_idempotency_key();
+                }),
+                _ => Err(Error::new_spanned(
+                    &spec.ty,
+                    "#[idempotency_key] can only be used on `Option<String>` and `String` fields",
+                )),
+            }
+        })
+        .collect::<Result<_>>()?;
+
+    let receiver = if keys.is_empty() { quote!(self) } else { quote!(mut self) };
+
+    Ok(quote! {
+        impl ::square_ox::builder::Validate for #name {
+            fn validate(#receiver) -> Result<Self, ::square_ox::errors::ValidationError> {
+                let mut errors = ::square_ox::errors::ValidationError::new();
+                #(#checks)*
+
+                #(#keys)*
+
+                errors.into_result(self)
+            }
+        }
+    })
+}
+
+fn add_field(name: &Ident, spec: &FieldSpec) -> TokenStream2 {
+    let ident = &spec.ident;
+    let path = &spec.path;
+
+    let (element, add, field_path) = match &spec.shape {
+        Shape::Option(inner) => (
+            inner.clone(),
+            quote!(self.#ident = Some(field);),
+            quote!(::square_ox::errors::FieldPath::from(#path)),
+        ),
+        Shape::OptionVec(element) => (
+            element.clone(),
+            quote!(self.#ident.get_or_insert_with(Vec::new).push(field);),
+            quote! {
+                ::square_ox::errors::FieldPath::from(#path)
+                    .index(self.#ident.as_ref().map_or(0, Vec::len))
+            },
+        ),
+        Shape::Vec(element) => (
+            element.clone(),
+            quote!(self.#ident.push(field);),
+            quote!(::square_ox::errors::FieldPath::from(#path).index(self.#ident.len())),
+        ),
+        Shape::Other => (
+            spec.ty.clone(),
+            quote!(self.#ident = field;),
+            quote!(::square_ox::errors::FieldPath::from(#path)),
+        ),
+        // rejected while parsing the attributes of the field
+        Shape::String | Shape::Map(..) | Shape::OptionMap(..) => unreachable!(),
+    };
+
+    quote! {
+        impl ::square_ox::builder::AddField<#element> for #name {
+            fn add_field(&mut self, field: #element) {
+                #add
+            }
+
+            fn field_path(&self) -> ::square_ox::errors::FieldPath {
+                #field_path
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+/*!
+Bank Accounts functionality of the [Square API](https://developer.squareup.com).
+ */
+
+use crate::api::{query_pairs, SquareAPI, Verb};
+use crate::client::SquareClient;
+use crate::errors::SquareError;
+use crate::objects::BankAccount;
+use crate::objects::ids::LocationId;
+use crate::response::SquareResponse;
+
+use serde::Serialize;
+
+impl SquareClient {
+    /// Returns a [BankAccounts](BankAccounts) object through which you can make calls specifically
+    /// to the Bank Accounts endpoint of the [Square API](https://developer.squareup.com).
+    /// # Example: Retrieve a bank account by its id.
+    /// ```rust
+    /// use square_ox::{
+    ///         response::{SquareResponse, ResponseError},
+    ///         client::SquareClient,
+    ///     };
+    ///
+    /// async {
+    ///     let bank_account = SquareClient::new("some_token")
+    ///         .bank_accounts()
+    ///         .get("some_bank_account_id".to_string())
+    ///         .await;
+    ///     };
+    /// ```
+    pub fn bank_accounts(&self) -> BankAccounts<'_> {
+        BankAccounts {
+            client: self,
+        }
+    }
+}
+
+/// Allows you to make calls to the [Square API](https://developer.squareup.com) at the Bank
+/// Accounts endpoint with all currently implemented methods.
+pub struct BankAccounts<'a> {
+    client: &'a SquareClient,
+}
+
+impl<'a> BankAccounts<'a> {
+    /// Returns a list of [BankAccount](BankAccount) objects linked to a Square account.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/bank-accounts/list-bank-accounts)
+    ///
+    /// # Arguments
+    /// * `parameters` - The [ListBankAccountsParams](ListBankAccountsParams) created through the
+    ///   [ListBankAccountsParametersBuilder](ListBankAccountsParametersBuilder)
+    pub async fn list(self, parameters: Option<ListBankAccountsParams>)
+                      -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::BankAccounts("".to_string()),
+            None::<&BankAccount>,
+            query_pairs(parameters.as_ref())?,
+        ).await
+    }
+
+    /// Returns details of a [BankAccount](BankAccount) linked to a Square account.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/bank-accounts/get-bank-account)
+    ///
+    /// # Arguments
+    /// * `bank_account_id` - Square-issued ID of the desired [BankAccount](BankAccount).
+    pub async fn get(self, bank_account_id: String)
+                     -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::BankAccounts(format!("/{}", bank_account_id)),
+            None::<&BankAccount>,
+            None,
+        ).await
+    }
+
+    /// Returns details of a [BankAccount](BankAccount) identified by V1 bank account ID.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/bank-accounts/get-bank-account-by-v1-id)
+    ///
+    /// # Arguments
+    /// * `v1_bank_account_id` - Connect V1 ID of the desired [BankAccount](BankAccount).
+    pub async fn get_by_v1_id(self, v1_bank_account_id: String)
+                              -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::BankAccounts(format!("/by-v1-id/{}", v1_bank_account_id)),
+            None::<&BankAccount>,
+            None,
+        ).await
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ListBankAccountsParametersBuilder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Default)]
+pub struct ListBankAccountsParametersBuilder {
+    cursor: Option<String>,
+    limit: Option<i32>,
+    location_id: Option<String>,
+}
+
+impl ListBankAccountsParametersBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The pagination cursor returned by a previous call to this endpoint.
+    /// Use it in the next `list` request to retrieve the next set of results.
+    pub fn cursor(mut self, cursor: String) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+
+    /// Upper limit on the number of bank accounts to return in the response.
+    /// Currently, 1000 is the largest supported limit.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Location ID. You can specify this optional filter to retrieve only the linked bank
+    /// accounts belonging to a specific location.
+    pub fn location_id(mut self, location_id: impl Into<LocationId>) -> Self {
+        self.location_id = Some(location_id.into().into());
+
+        self
+    }
+
+    pub fn build_sync(self) -> ListBankAccountsParams {
+        let ListBankAccountsParametersBuilder {
+            cursor,
+            limit,
+            location_id,
+        } = self;
+
+        ListBankAccountsParams {
+            cursor,
+            limit,
+            location_id,
+        }
+    }
+
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListBankAccountsParams {
+        self.build_sync()
+    }
+}
+
+/// The query parameters of [BankAccounts::list](BankAccounts::list), built through the
+/// [ListBankAccountsParametersBuilder](ListBankAccountsParametersBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListBankAccountsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_id: Option<String>,
+}
+
+#[cfg(test)]
+mod test_bank_accounts {
+    use super::*;
+
+    #[test]
+    fn test_list_bank_accounts_parameters_builder() {
+        let expected = vec![
+            ("limit".to_string(), "10".to_string()),
+            ("location_id".to_string(), "L1JC53TYHS40Z".to_string()),
+        ];
+
+        let actual = ListBankAccountsParametersBuilder::new()
+            .location_id("L1JC53TYHS40Z".to_string())
+            .limit(10)
+            .build_sync();
+
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_list_bank_accounts() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let res = sut.bank_accounts()
+            .list(None)
+            .await;
+
+        assert!(res.is_ok())
+    }
+}
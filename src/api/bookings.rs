@@ -3,19 +3,22 @@ Bookings functionality of the [Square API](https://developer.squareup.com).
  */
 
 use crate::client::SquareClient;
-use crate::api::{Verb, SquareAPI};
-use crate::errors::{SquareError, SearchQueryBuildError, BookingsPostBuildError, BookingsCancelBuildError, ValidationError};
+use crate::datetime::Timestamp;
+use crate::api::{query_pairs, Verb, SquareAPI};
+use crate::errors::{BuildError, SquareError, ValidationError, ViolationReason};
 use crate::response::SquareResponse;
 use crate::objects::{AppointmentSegment, Booking, FilterValue, enums::BusinessAppointmentSettingsBookingLocationType, StartAtRange, SegmentFilter, AvailabilityQueryFilter};
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use crate::builder::{Builder, ParentBuilder, Validate};
+use crate::builder::{Builder, ParentBuilder, SquareBuilder, Validate};
+use crate::builder::typestate::{Missing, Present, TypedBuilder};
+use crate::objects::ids::{BookingId, CustomerId, LocationId, TeamMemberId};
 
 impl SquareClient {
-    pub fn bookings(&self) -> Bookings {
+    pub fn bookings(&self) -> Bookings<'_> {
         Bookings {
-            client: &self
+            client: self
         }
     }
 }
@@ -29,15 +32,15 @@ impl<'a> Bookings<'a> {
     /// and get the response back.
     ///
     /// # Arguments
-    /// * `search_query` - A vector of search query parameter created through the
-    /// [ListBookingsQueryBuilder](ListBookingsQueryBuilder)
-    pub async fn list(self, search_query: Option<Vec<(String, String)>>)
+    /// * `search_query` - The [ListBookingsParams](ListBookingsParams) created through the
+    ///   [ListBookingsQueryBuilder](ListBookingsQueryBuilder)
+    pub async fn list(self, search_query: Option<ListBookingsParams>)
                                -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
             SquareAPI::Bookings("".to_string()),
             None::<&BookingsPost>,
-            search_query,
+            query_pairs(search_query.as_ref())?,
         ).await
     }
 
@@ -71,16 +74,18 @@ impl<'a> Bookings<'a> {
         ).await
     }
 
-    /// Update a booking with the given [BookingsPost](BookingsPost) to the Square API
-    /// and get the response back.
+    /// Update a booking with the given [BookingsUpdate](BookingsUpdate) to the Square API
+    /// and get the response back. Only the fields set on the [BookingsUpdate](BookingsUpdate) are
+    /// changed.
     ///
     /// # Arguments
-    /// * `updated_booking` - A [BookingsPost](BookingsPost).
-    pub async fn update(self, updated_booking: BookingsPost, booking_id: String)
+    /// * `updated_booking` - A [BookingsUpdate](BookingsUpdate).
+    /// * `booking_id` - The id of the booking to update.
+    pub async fn update(self, updated_booking: BookingsUpdate, booking_id: impl Into<BookingId>)
                                 -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::PUT,
-            SquareAPI::Bookings(format!("/{}", booking_id)),
+            SquareAPI::Bookings(format!("/{}", booking_id.into())),
             Some(&updated_booking),
             None,
         ).await
@@ -90,23 +95,38 @@ impl<'a> Bookings<'a> {
     ///
     /// # Arguments
     /// * `booking_id` - The id of the booking as a String
-    pub async fn retrieve(self, booking_id: String)
+    pub async fn retrieve(self, booking_id: impl Into<BookingId>)
                                   -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
-            SquareAPI::Bookings(format!("/{}", booking_id)),
+            SquareAPI::Bookings(format!("/{}", booking_id.into())),
             None::<&BookingsPost>,
             None,
         ).await
     }
 
-    /// Create a booking with the given [Bookings](Bookings) to the Square API
+    /// Bulk retrieve existing bookings from the Square API.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/bookings-api/bulk-retrieve-bookings)
+    ///
+    /// # Arguments
+    /// * `body` - A [BulkRetrieveBookingsBody](BulkRetrieveBookingsBody) holding the booking ids.
+    pub async fn bulk_retrieve(self, body: BulkRetrieveBookingsBody)
+                               -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Bookings("/bulk-retrieve".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Cancel a booking with the given [BookingsCancel](BookingsCancel) to the Square API
     /// and get the response back.
     ///
     /// # Arguments
     /// * `booking_to_cancel` - A [BookingsCancel](BookingsCancel) created from the
-    /// [BookingsCancelBuilder](BookingsCancelBuilder)
-    pub async fn cancel(&self, booking_to_cancel: BookingsCancel)
+    ///   [BookingsCancelBuilder](BookingsCancelBuilder)
+    pub async fn cancel(self, booking_to_cancel: BookingsCancel)
                                 -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::POST,
@@ -128,18 +148,57 @@ impl<'a> Bookings<'a> {
         ).await
     }
 
+    /// Lists location booking profiles of a seller at the
+    /// [Square API](https://developer.squareup.com).
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/bookings-api/list-location-booking-profiles)
+    ///
+    /// # Arguments
+    /// * `search_query` - A search query created by the
+    ///   [ListLocationBookingProfilesQueryBuilder](ListLocationBookingProfilesQueryBuilder).
+    pub async fn list_location_profiles(
+        self,
+        search_query: Option<ListLocationBookingProfilesParams>
+    )
+        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::Bookings("/location-booking-profiles".to_string()),
+            None::<&BookingsPost>,
+            query_pairs(search_query.as_ref())?,
+        ).await
+    }
+
+    /// Retrieves a seller's location booking profile at the
+    /// [Square API](https://developer.squareup.com).
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/bookings-api/retrieve-location-booking-profile)
+    ///
+    /// # Arguments
+    /// * `location_id` - The id of the location to retrieve the booking profile for.
+    pub async fn retrieve_location_profile(self, location_id: impl Into<LocationId>)
+                                           -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::Bookings(format!("/location-booking-profiles/{}", location_id.into())),
+            None::<&BookingsPost>,
+            None,
+        ).await
+    }
+
     /// Lists booking profiles for team members at the [Square API](https://developer.squareup.com).
     ///
     /// # Arguments
     /// * `search_query` - A search query created by the
-    /// [ListTeamMemberBookingsProfileBuilder](ListTeamMemberBookingsProfileBuilder).
-    pub async fn list_team_member_profiles(self, search_query: Option<Vec<(String, String)>>)
-                                                   -> Result<SquareResponse, SquareError> {
+    ///   [ListTeamMemberBookingsProfileBuilder](ListTeamMemberBookingsProfileBuilder).
+    pub async fn list_team_member_profiles(
+        self,
+        search_query: Option<ListTeamMemberBookingProfilesParams>
+    )
+        -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
             SquareAPI::Bookings("/team-member-booking-profiles".to_string()),
             None::<&BookingsPost>,
-            search_query,
+            query_pairs(search_query.as_ref())?,
         ).await
     }
 
@@ -147,16 +206,33 @@ impl<'a> Bookings<'a> {
     ///
     /// # Arguments
     /// * `team_member_id` - The id of the team member you would like to retrieve from the
-    /// [Square API](https://developer.squareup.com).
-    pub async fn retrieve_team_member_profiles(self, team_member_id: String)
+    ///   [Square API](https://developer.squareup.com).
+    pub async fn retrieve_team_member_profiles(self, team_member_id: impl Into<TeamMemberId>)
                                                        -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
-            SquareAPI::Bookings(format!("/team-member-booking-profiles/{}", team_member_id)),
+            SquareAPI::Bookings(format!("/team-member-booking-profiles/{}", team_member_id.into())),
             None::<&BookingsPost>,
             None,
         ).await
     }
+
+    /// Retrieves one or more team members' booking profiles at the
+    /// [Square API](https://developer.squareup.com).
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/bookings-api/bulk-retrieve-team-member-booking-profiles)
+    ///
+    /// # Arguments
+    /// * `body` - A [BulkRetrieveTeamMemberProfilesBody](BulkRetrieveTeamMemberProfilesBody)
+    ///   holding the team member ids.
+    pub async fn bulk_retrieve_team_member_profiles(self, body: BulkRetrieveTeamMemberProfilesBody)
+                                                    -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Bookings("/team-member-booking-profiles/bulk-retrieve".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -194,16 +270,16 @@ impl ListBookingsQueryBuilder {
 
     /// The team member for whom to retrieve bookings.
     /// If this is not set, bookings of all members are retrieved.
-    pub fn team_member_id(mut self, team_member_id: String) -> Self {
-        self.team_member_id = Some(team_member_id);
+    pub fn team_member_id(mut self, team_member_id: impl Into<TeamMemberId>) -> Self {
+        self.team_member_id = Some(team_member_id.into().into());
 
         self
     }
 
     /// The location for which to retrieve bookings.
     /// If this is not set, all locations' bookings are retrieved.
-    pub fn location_id(mut self, location_id: String) -> Self {
-        self.location_id = Some(location_id);
+    pub fn location_id(mut self, location_id: impl Into<LocationId>) -> Self {
+        self.location_id = Some(location_id.into().into());
 
         self
     }
@@ -216,8 +292,8 @@ impl ListBookingsQueryBuilder {
     // UTC: 2020-01-26T02:25:34Z
     //
     // Pacific Standard Time with UTC offset: 2020-01-25T18:25:34-08:00
-    pub fn start_at_min(mut self, start_at_min: String) -> Self {
-        self.start_at_min = Some(start_at_min);
+    pub fn start_at_min(mut self, start_at_min: impl Into<Timestamp>) -> Self {
+        self.start_at_min = Some(start_at_min.into().into());
 
         self
     }
@@ -230,13 +306,13 @@ impl ListBookingsQueryBuilder {
     // UTC: 2020-01-26T02:25:34Z
     //
     // Pacific Standard Time with UTC offset: 2020-01-25T18:25:34-08:00
-    pub fn start_at_max(mut self, start_at_max: String) -> Self {
-        self.start_at_max = Some(start_at_max);
+    pub fn start_at_max(mut self, start_at_max: impl Into<Timestamp>) -> Self {
+        self.start_at_max = Some(start_at_max.into().into());
 
         self
     }
 
-    pub async fn build(self) -> Vec<(String, String)> {
+    pub fn build_sync(self) -> ListBookingsParams {
         let ListBookingsQueryBuilder {
             limit,
             cursor,
@@ -244,39 +320,42 @@ impl ListBookingsQueryBuilder {
             location_id,
             start_at_min,
             start_at_max,
-
         } = self;
 
-        let mut res = vec![];
-
-        if let Some(limit) = limit {
-            res.push(("limit".to_string(), limit.to_string()))
-        }
-
-        if let Some(cursor) = cursor {
-            res.push(("cursor".to_string(), cursor))
-        }
-
-        if let Some(team_member_id) = team_member_id {
-            res.push(("team_member_id".to_string(), team_member_id))
-        }
-
-        if let Some(location_id) = location_id {
-            res.push(("location_id".to_string(), location_id))
-        }
-
-        if let Some(start_at_min) = start_at_min {
-            res.push(("start_at_min".to_string(), start_at_min))
-        }
-
-        if let Some(start_at_max) = start_at_max {
-            res.push(("start_at_max".to_string(), start_at_max))
+        ListBookingsParams {
+            limit,
+            cursor,
+            team_member_id,
+            location_id,
+            start_at_min,
+            start_at_max,
         }
+    }
 
-        res
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListBookingsParams {
+        self.build_sync()
     }
 }
 
+/// The query parameters of [Bookings::list](Bookings::list), built through the
+/// [ListBookingsQueryBuilder](ListBookingsQueryBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListBookingsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team_member_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_at_min: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_at_max: Option<String>,
+}
+
 // -------------------------------------------------------------------------------------------------
 // ListTeamMemberBookingsProfileBuilder implementation
 // -------------------------------------------------------------------------------------------------
@@ -317,13 +396,13 @@ impl ListTeamMemberBookingsProfileBuilder {
 
     /// Indicates whether to include only team members enabled at the given location in the
     /// returned result.
-    pub fn location_id(mut self, location_id: String) -> Self {
-        self.location_id = Some(location_id);
+    pub fn location_id(mut self, location_id: impl Into<LocationId>) -> Self {
+        self.location_id = Some(location_id.into().into());
 
         self
     }
 
-    pub async fn build(self) -> Vec<(String, String)> {
+    pub fn build_sync(self) -> ListTeamMemberBookingProfilesParams {
         let ListTeamMemberBookingsProfileBuilder {
             limit,
             cursor,
@@ -331,25 +410,93 @@ impl ListTeamMemberBookingsProfileBuilder {
             location_id,
         } = self;
 
-        let mut res = vec![];
-
-        if let Some(limit) = limit {
-            res.push(("limit".to_string(), limit.to_string()))
-        }
-        if let Some(cursor) = cursor {
-            res.push(("cursor".to_string(), cursor))
-        }
-        if let Some(bookable_only) = bookable_only {
-            res.push(("bookable_only".to_string(), bookable_only.to_string()))
+        ListTeamMemberBookingProfilesParams {
+            limit,
+            cursor,
+            bookable_only,
+            location_id,
         }
-        if let Some(location_id) = location_id {
-            res.push(("location_id".to_string(), location_id))
+    }
+
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListTeamMemberBookingProfilesParams {
+        self.build_sync()
+    }
+}
+
+/// The query parameters of
+/// [Bookings::list_team_member_profiles](Bookings::list_team_member_profiles), built through the
+/// [ListTeamMemberBookingsProfileBuilder](ListTeamMemberBookingsProfileBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListTeamMemberBookingProfilesParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bookable_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_id: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// ListLocationBookingProfilesQueryBuilder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Default)]
+pub struct ListLocationBookingProfilesQueryBuilder {
+    limit: Option<i32>,
+    cursor: Option<String>,
+}
+
+impl ListLocationBookingProfilesQueryBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The maximum number of results to return in a paged response.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// The pagination cursor from the preceding response to return the next page of the results.
+    /// Do not set this when retrieving the first page of the results.
+    pub fn cursor(mut self, cursor: String) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+
+    pub fn build_sync(self) -> ListLocationBookingProfilesParams {
+        let ListLocationBookingProfilesQueryBuilder {
+            limit,
+            cursor,
+        } = self;
+
+        ListLocationBookingProfilesParams {
+            limit,
+            cursor,
         }
+    }
 
-        res
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListLocationBookingProfilesParams {
+        self.build_sync()
     }
 }
 
+/// The query parameters of [Bookings::list_location_profiles](Bookings::list_location_profiles),
+/// built through the
+/// [ListLocationBookingProfilesQueryBuilder](ListLocationBookingProfilesQueryBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListLocationBookingProfilesParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+}
+
 // -------------------------------------------------------------------------------------------------
 // BookingsPost builders implementation
 // -------------------------------------------------------------------------------------------------
@@ -370,162 +517,229 @@ impl ListTeamMemberBookingsProfileBuilder {
 ///     api::bookings::BookingsPost,
 /// };
 ///
-/// async {
-///     let builder = Builder::from(BookingsPost::default())
+/// let builder = Builder::from(BookingsPost::default())
 ///     .customer_id("some_id".to_string())
 ///     .location_id("some_id".to_string())
 ///     .start_at("some_start_at_date_time".to_string())
 ///     .add_appointment_segment(AppointmentSegment::default())
-///     .build()
-///     .await;
-/// };
+///     .build_sync();
 /// ```
-#[derive(Serialize, Debug, Deserialize, Default)]
+#[derive(Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "booking_details")]
 pub struct BookingsPost {
+    #[idempotency_key]
     idempotency_key: Option<String>,
+    #[builder(flatten(
+        /// Add a customer_id
+        ///
+        /// # Arguments:
+        /// * `customer_id` - The id of your booking customer.
+        ///
+        /// # Example: Set the customer id
+        /// ```
+        ///  use square_ox::{
+        ///     api::bookings::BookingsPost,
+        ///     builder::Builder,
+        ///  };
+        ///
+        ///  let builder = Builder::from(BookingsPost::default())
+        ///  .customer_id("some_id".to_string());
+        /// ```
+        customer_id: Option<CustomerId>,
+        /// Add a location_id
+        ///
+        /// # Arguments:
+        /// * `location_id` - The id of the booking location.
+        ///
+        /// # Example: Set the customer id
+        /// ```
+        /// use square_ox::{
+        ///     builder::Builder,
+        ///     api::bookings::BookingsPost,
+        /// };
+        ///
+        /// let builder = Builder::from(BookingsPost::default())
+        /// .location_id("some_id".to_string());
+        /// ```
+        location_id: Option<LocationId>,
+        location_type: Option<BusinessAppointmentSettingsBookingLocationType>,
+        start_at: Option<Timestamp>,
+        appointment_segments: Option<Vec<AppointmentSegment>>,
+        seller_note: Option<String>,
+        customer_note: Option<String>,
+    ))]
     booking: Booking,
 }
 
-impl Validate for BookingsPost {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.booking.customer_id.is_some()
-            && self.booking.location_id.is_some()
-            && self.booking.appointment_segments.as_ref().unwrap().len() > 0
-            && self.booking.start_at.is_some() {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
-
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
+fn booking_details(body: &BookingsPost, errors: &mut ValidationError) {
+    errors.require("booking.customer_id", &body.booking.customer_id);
+    errors.require("booking.location_id", &body.booking.location_id);
+    errors.check(
+        body.booking.appointment_segments.as_ref()
+            .is_some_and(|segments| !segments.is_empty()),
+        "booking.appointment_segments",
+        ViolationReason::Empty,
+    );
+    errors.require("booking.start_at", &body.booking.start_at);
 }
 
-impl<T: ParentBuilder> Builder<BookingsPost, T> {
-    /// Add a customer_id
-    ///
-    /// # Arguments:
-    /// * `customer_id` - The id of your booking customer.
+impl BookingsPost {
+    /// A [TypedBuilder](TypedBuilder) of a booking, which only builds once `.customer_id()`,
+    /// `.location_id()`, `.start_at()` and `.add_appointment_segment()` have been called.
     ///
-    /// # Example: Set the customer id
-    /// ```
-    ///  use square_ox::{
-    ///     api::bookings::BookingsPost,
-    ///     builder::Builder,
-    ///  };
-    ///
-    ///  let builder = Builder::from(BookingsPost::default())
-    ///  .customer_id("some_id".to_string());
-    /// ```
-    pub fn customer_id(mut self, customer_id: String) -> Self {
-        self.body.booking.customer_id = Some(customer_id);
-
-        self
-    }
-
-    // Add a location_id
-    ///
-    /// # Arguments:
-    /// * `location_id` - The id of the booking location.
-    ///
-    /// # Example: Set the customer id
+    /// # Example: Build a [BookingsPost](BookingsPost) with a note for the seller
     /// ```
     /// use square_ox::{
-    ///     builder::Builder,
+    ///     objects::AppointmentSegment,
     ///     api::bookings::BookingsPost,
     /// };
     ///
-    /// let builder = Builder::from(BookingsPost::default())
-    /// .location_id("some_id".to_string());
+    /// let booking = BookingsPost::builder()
+    ///     .customer_id("some_id")
+    ///     .location_id("some_id")
+    ///     .start_at("2022-10-11T16:30:00Z")
+    ///     .add_appointment_segment(AppointmentSegment::default())
+    ///     .with(|builder| builder.seller_note("some_note".to_string()))
+    ///     .build()
+    ///     .unwrap();
     /// ```
-    pub fn location_id(mut self, location_id: String) -> Self {
-        self.body.booking.location_id = Some(location_id);
-
-        self
+    pub fn builder() -> TypedBuilder<BookingsPost, (Missing, Missing, Missing, Missing)> {
+        TypedBuilder::new()
     }
+}
 
-    pub fn location_type(mut self, location_type: BusinessAppointmentSettingsBookingLocationType) -> Self {
-        self.body.booking.location_type = Some(location_type);
-
-        self
+// the state tracks the customer_id, location_id, start_at and appointment_segments fields
+impl<C, L, S, A> TypedBuilder<BookingsPost, (C, L, S, A)> {
+    pub fn customer_id(self, customer_id: impl Into<CustomerId>)
+                       -> TypedBuilder<BookingsPost, (Present, L, S, A)> {
+        self.set(|builder| builder.customer_id(customer_id))
     }
 
-    pub fn start_at(mut self, start_at_date_time: String) -> Self {
-        self.body.booking.start_at = Some(start_at_date_time);
+    pub fn location_id(self, location_id: impl Into<LocationId>)
+                       -> TypedBuilder<BookingsPost, (C, Present, S, A)> {
+        self.set(|builder| builder.location_id(location_id))
+    }
 
-        self
+    pub fn start_at(self, start_at_date_time: impl Into<Timestamp>)
+                    -> TypedBuilder<BookingsPost, (C, L, Present, A)> {
+        self.set(|builder| builder.start_at(start_at_date_time))
     }
 
-    pub fn add_appointment_segment(mut self, appointment_segment: AppointmentSegment) -> Self {
-        if let Some(segments) = self.body.booking.appointment_segments.as_mut() {
-            segments.push(appointment_segment);
-        } else {
-            self.body.booking.appointment_segments = Some(vec![appointment_segment])
-        }
+    pub fn add_appointment_segment(self, appointment_segment: AppointmentSegment)
+                                   -> TypedBuilder<BookingsPost, (C, L, S, Present)> {
+        self.set(|builder| builder.add_appointment_segment(appointment_segment))
+    }
+}
 
-        self
+impl TypedBuilder<BookingsPost, (Present, Present, Present, Present)> {
+    pub fn build(self) -> Result<BookingsPost, BuildError> {
+        self.finish()
     }
+}
 
-    pub fn seller_note(mut self, seller_note: String) -> Self {
-        self.body.booking.seller_note = Some(seller_note);
+// -------------------------------------------------------------------------------------------------
+// BookingsUpdate builders implementation
+// -------------------------------------------------------------------------------------------------
 
-        self
-    }
+/// [BookingsUpdate](BookingsUpdate)
+///
+/// Holds a partial [Booking](Booking), only the fields that are set are updated. At least one
+/// field other than the `.version()` must be set for the BookingsUpdate to be valid.
+///
+/// # Example: Build a [BookingsUpdate](BookingsUpdate)
+/// ```
+/// use square_ox::{
+///     builder::Builder,
+///     api::bookings::BookingsUpdate,
+/// };
+///
+/// let builder = Builder::from(BookingsUpdate::default())
+///     .seller_note("some note".to_string())
+///     .version(1)
+///     .build_sync();
+/// ```
+#[derive(Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "updated_fields")]
+pub struct BookingsUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[idempotency_key]
+    idempotency_key: Option<String>,
+    #[builder(flatten(
+        /// The current version of the booking, used for optimistic concurrency.
+        version: Option<i32>,
+        customer_id: Option<CustomerId>,
+        location_id: Option<LocationId>,
+        location_type: Option<BusinessAppointmentSettingsBookingLocationType>,
+        start_at: Option<Timestamp>,
+        /// The appointment segments of the booking, the segments that are set replace all
+        /// existing segments of the booking.
+        appointment_segments: Option<Vec<AppointmentSegment>>,
+        seller_note: Option<String>,
+        customer_note: Option<String>,
+    ))]
+    booking: Booking,
+}
 
-    pub fn customer_note(mut self, customer_note: String) -> Self {
-        self.body.booking.customer_note = Some(customer_note);
+// the version alone does not update anything
+fn updated_fields(body: &BookingsUpdate, errors: &mut ValidationError) {
+    let booking = &body.booking;
+
+    errors.require_one_of("booking", &[
+        ("customer_id", booking.customer_id.is_some()),
+        ("location_id", booking.location_id.is_some()),
+        ("location_type", booking.location_type.is_some()),
+        ("appointment_segments", booking.appointment_segments.is_some()),
+        ("start_at", booking.start_at.is_some()),
+        ("seller_note", booking.seller_note.is_some()),
+        ("customer_note", booking.customer_note.is_some()),
+    ]);
+}
 
-        self
-    }
+// -------------------------------------------------------------------------------------------------
+// BulkRetrieveBookingsBody builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+pub struct BulkRetrieveBookingsBody {
+    #[required]
+    booking_ids: Vec<String>,
 }
 
 // -------------------------------------------------------------------------------------------------
-// BookingsPost builders implementation
+// BulkRetrieveTeamMemberProfilesBody builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Serialize, Debug, Deserialize, Default)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+pub struct BulkRetrieveTeamMemberProfilesBody {
+    #[required]
+    team_member_ids: Vec<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// BookingsCancel builders implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[builder(skip_validate)]
 pub struct BookingsCancel {
     #[serde(skip_serializing_if = "Option::is_none")]
-    booking_id: Option<String>,
+    booking_id: Option<BookingId>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(flatten(booking_version: Option<i32>))]
     body: Option<BookingsCancelBody>,
 }
 
 impl Validate for BookingsCancel {
     fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.booking_id.is_some() {
-            if let Some(body) = self.body.as_mut() {
-                body.idempotency_key = Some(Uuid::new_v4().to_string())
-            };
-
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
-}
+        let mut errors = ValidationError::new();
+        errors.require("booking_id", &self.booking_id);
 
-impl<T: ParentBuilder> Builder<BookingsCancel, T>  {
-    pub fn booking_id(mut self, booking_id: String) -> Self {
-        self.body.booking_id = Some(booking_id);
-
-        self
-    }
-
-    pub fn booking_version(mut self, booking_version: i32) -> Self {
-        if let Some(body) = self.body.body.as_mut() {
-            body.booking_version = Some(booking_version)
-        } else {
-            self.body.body = Some(BookingsCancelBody {
-                idempotency_key: None,
-                booking_version: Some(booking_version)
-            })
-        }
+        if let Some(body) = self.body.as_mut() {
+            body.idempotency_key = Some(Uuid::new_v4().to_string())
+        };
 
-        self
+        errors.into_result(self)
     }
 }
 
-#[derive(Serialize, Debug, Deserialize)]
+#[derive(Serialize, Debug, Deserialize, Default)]
 pub struct BookingsCancelBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     idempotency_key: Option<String>,
@@ -538,37 +752,36 @@ pub struct BookingsCancelBody {
 // -------------------------------------------------------------------------------------------------
 // holds a QueryBody struct which contains the actual query data, as this is the way it is expected
 // by the Square API
-#[derive(Serialize, Debug, Deserialize, Default)]
+#[derive(Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "start_at_range")]
 pub struct SearchAvailabilityQuery {
+    #[builder(flatten(
+        #[builder(flatten(location_id: Option<LocationId>))]
+        filter: AvailabilityQueryFilter,
+    ))]
     query: QueryBody,
 }
 
-impl Validate for SearchAvailabilityQuery {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.query.filter.start_at_range.is_some() {
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
+fn start_at_range(body: &SearchAvailabilityQuery, errors: &mut ValidationError) {
+    errors.require("query.filter.start_at_range", &body.query.filter.start_at_range);
+
+    if let Some(range) = &body.query.filter.start_at_range {
+        range.end_at.check("query.filter.start_at_range.end_at", errors);
+        range.start_at.check("query.filter.start_at_range.start_at", errors);
     }
 }
 
 impl<T: ParentBuilder> Builder<SearchAvailabilityQuery, T> {
-    pub fn start_at_range(mut self, start: String, end: String) -> Self {
+    pub fn start_at_range(mut self, start: impl Into<Timestamp>, end: impl Into<Timestamp>)
+                          -> Self {
         self.body.query.filter.start_at_range = Some(StartAtRange {
-            end_at: end.clone(),
-            start_at: start.clone(),
+            end_at: end.into(),
+            start_at: start.into(),
         });
 
         self
     }
 
-    pub fn location_id(mut self, location_id: String) -> Self {
-        self.body.query.filter.location_id = Some(location_id);
-
-        self
-    }
-
     pub fn segment_filters(mut self, service_variation_id: String) -> Self {
         let new_filter = SegmentFilter {
             service_variation_id: service_variation_id.clone(),
@@ -598,17 +811,17 @@ pub struct QueryBody {
 mod test_bookings {
     use super::*;
 
-    #[tokio::test]
-    async fn test_search_query_builder() {
+    #[test]
+    fn test_search_query_builder() {
         let expected = SearchAvailabilityQuery {
             query: QueryBody {
                 filter: AvailabilityQueryFilter {
                     start_at_range: Some(StartAtRange {
-                        end_at: "2023-10-12T07:20:50.52Z".to_string(),
-                        start_at: "2022-10-12T07:20:50.52Z".to_string(),
+                        end_at: "2023-10-12T07:20:50.52Z".into(),
+                        start_at: "2022-10-12T07:20:50.52Z".into(),
                     }),
                     booking_id: None,
-                    location_id: Some("LPNXWH14W6S47".to_string()),
+                    location_id: Some("LPNXWH14W6S47".into()),
                     segment_filters: None
                 }
             }
@@ -619,8 +832,7 @@ mod test_bookings {
                 "2022-10-12T07:20:50.52Z".to_string(),
                 "2023-10-12T07:20:50.52Z".to_string())
             .location_id("LPNXWH14W6S47".to_string())
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
@@ -641,37 +853,36 @@ mod test_bookings {
                 "2022-10-12T07:20:50.52Z".to_string())
             .location_id("L1JC53TYHS40Z".to_string())
             .segment_filters("BJHURKYAIAQIDMY267GZNYNW".to_string())
-            .build().await.unwrap();
+            .build_sync().unwrap();
 
         let result = sut.bookings().search_availability(input).await;
 
         assert!(result.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_booking_post_builder() {
+    #[test]
+    fn test_booking_post_builder() {
         let actual = Builder::from(BookingsPost::default())
             .start_at("2022-10-11T16:30:00Z".to_string())
             .location_id("L1JC53TYHS40Z".to_string())
             .customer_id("7PB8P9553RYA3F672D15369VK4".to_string())
             .add_appointment_segment(AppointmentSegment {
                 duration_minutes: 60.00,
-                team_member_id: "TMKFnToW8ByXrcm6".to_string(),
+                team_member_id: "TMKFnToW8ByXrcm6".into(),
                 any_team_member_id: None,
                 intermission_minutes: None,
                 resource_ids: None,
                 service_variation_id: "BSOL4BB6RCMX6SH4KQIFWZDP".to_string(),
                 service_variation_version:  1655427266071,
             })
-            .build()
-            .await;
+            .build_sync();
 
         let expected = Booking {
             id: None,
             all_day: None,
             appointment_segments: Some(vec![AppointmentSegment {
                 duration_minutes: 60.00,
-                team_member_id: "TMKFnToW8ByXrcm6".to_string(),
+                team_member_id: "TMKFnToW8ByXrcm6".into(),
                 any_team_member_id: None,
                 intermission_minutes: None,
                 resource_ids: None,
@@ -680,13 +891,13 @@ mod test_bookings {
             }]),
             created_at: None,
             booking_creator_details: None,
-            customer_id: Some("7PB8P9553RYA3F672D15369VK4".to_string()),
+            customer_id: Some("7PB8P9553RYA3F672D15369VK4".into()),
             customer_note: None,
-            location_id: Some("L1JC53TYHS40Z".to_string()),
+            location_id: Some("L1JC53TYHS40Z".into()),
             location_type: None,
             seller_note: None,
             source: None,
-            start_at: Some("2022-10-11T16:30:00Z".to_string()),
+            start_at: Some("2022-10-11T16:30:00Z".into()),
             status: None,
             transition_time_minutes: None,
             updated_at: None,
@@ -697,22 +908,49 @@ mod test_bookings {
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual.unwrap().booking))
     }
 
-    #[tokio::test]
-    async fn test_booking_post_builder_fail() {
+    #[test]
+    fn test_booking_post_typed_builder() {
+        let actual = BookingsPost::builder()
+            .add_appointment_segment(AppointmentSegment::default())
+            .start_at("2022-10-11T16:30:00Z")
+            .location_id("L1JC53TYHS40Z")
+            .customer_id("7PB8P9553RYA3F672D15369VK4")
+            .build()
+            .unwrap();
+
+        assert!(actual.idempotency_key.is_some());
+        assert_eq!(actual.booking.location_id, Some("L1JC53TYHS40Z".into()));
+        assert_eq!(actual.booking.appointment_segments.map(|segments| segments.len()), Some(1));
+    }
+
+    #[test]
+    fn test_booking_post_typed_builder_overwritten_fail() {
+        let res = BookingsPost::builder()
+            .add_appointment_segment(AppointmentSegment::default())
+            .start_at("2022-10-11T16:30:00Z")
+            .location_id("L1JC53TYHS40Z")
+            .customer_id("7PB8P9553RYA3F672D15369VK4")
+            .with(|_| Builder::from(BookingsPost::default()))
+            .build();
+
+        assert!(res.is_err())
+    }
+
+    #[test]
+    fn test_booking_post_builder_fail() {
         let res = Builder::from(BookingsPost::default())
             .start_at("2022-10-11T16:30:00Z".to_string())
             .customer_id("7PB8P9553RYA3F672D15369VK4".to_string())
             .add_appointment_segment(AppointmentSegment {
                 duration_minutes: 60.00,
-                team_member_id: "TMKFnToW8ByXrcm6".to_string(),
+                team_member_id: "TMKFnToW8ByXrcm6".into(),
                 any_team_member_id: None,
                 intermission_minutes: None,
                 resource_ids: None,
                 service_variation_id: "BSOL4BB6RCMX6SH4KQIFWZDP".to_string(),
                 service_variation_version:  1655427266071,
             })
-            .build()
-            .await;
+            .build_sync();
 
         assert!(res.is_err());
     }
@@ -733,7 +971,7 @@ mod test_bookings {
                 all_day: None,
                 appointment_segments: Some(vec![AppointmentSegment {
                     duration_minutes: 60.00,
-                    team_member_id: "TMKFnToW8ByXrcm6".to_string(),
+                    team_member_id: "TMKFnToW8ByXrcm6".into(),
                     any_team_member_id: None,
                     intermission_minutes: None,
                     resource_ids: None,
@@ -742,13 +980,13 @@ mod test_bookings {
                 }]),
                 created_at: None,
                 booking_creator_details: None,
-                customer_id: Some("7PB8P9553RYA3F672D15369VK4".to_string()),
+                customer_id: Some("7PB8P9553RYA3F672D15369VK4".into()),
                 customer_note: None,
-                location_id: Some("L1JC53TYHS40Z".to_string()),
+                location_id: Some("L1JC53TYHS40Z".into()),
                 location_type: None,
                 seller_note: None,
                 source: None,
-                start_at: Some("2022-10-11T16:30:00Z".to_string()),
+                start_at: Some("2022-10-11T16:30:00Z".into()),
                 status: None,
                 transition_time_minutes: None,
                 updated_at: None,
@@ -777,27 +1015,27 @@ mod test_bookings {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_bookings_cancel_builder() {
+    #[test]
+    fn test_bookings_cancel_builder() {
         let expected = BookingsCancel {
-            booking_id: Some("9uv6i3p5x5ao1p".to_string()),
+            booking_id: Some("9uv6i3p5x5ao1p".into()),
             body: Some(BookingsCancelBody {
                 idempotency_key: Some(Uuid::new_v4().to_string()),
                 booking_version: None
             })
         };
         let actual = Builder::from(BookingsCancel::default())
-            .booking_id("9uv6i3p5x5ao1p".to_string()).build().await;
+            .booking_id("9uv6i3p5x5ao1p".to_string()).build_sync();
 
         assert!(actual.is_ok());
         assert_eq!(format!("{:?}", expected.booking_id),
                    format!("{:?}", actual.unwrap().booking_id));
     }
 
-    #[tokio::test]
-    async fn test_bookings_cancel_builder_fail() {
+    #[test]
+    fn test_bookings_cancel_builder_fail() {
 
-        let res = Builder::from(BookingsCancel::default()).build().await;
+        let res = Builder::from(BookingsCancel::default()).build_sync();
 
         assert!(res.is_err());
     }
@@ -812,7 +1050,7 @@ mod test_bookings {
         let sut = SquareClient::new(&access_token);
 
         let input = BookingsCancel {
-            booking_id: Some("pi7kr2va3y4h4f".to_string()),
+            booking_id: Some("pi7kr2va3y4h4f".into()),
             body: Some(BookingsCancelBody {
                 idempotency_key: Some(Uuid::new_v4().to_string()),
                 booking_version: None
@@ -833,35 +1071,10 @@ mod test_bookings {
         let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token);
 
-        let input = BookingsPost {
-            idempotency_key: Some(Uuid::new_v4().to_string()),
-            booking: Booking {
-                id: None,
-                all_day: None,
-                appointment_segments: Some(vec![AppointmentSegment {
-                    duration_minutes: 60.00,
-                    team_member_id: "TMKFnToW8ByXrcm6".to_string(),
-                    any_team_member_id: None,
-                    intermission_minutes: None,
-                    resource_ids: None,
-                    service_variation_id: "BSOL4BB6RCMX6SH4KQIFWZDP".to_string(),
-                    service_variation_version:  1655427266071,
-                }]),
-                created_at: None,
-                booking_creator_details: None,
-                customer_id: Some("7PB8P9553RYA3F672D15369VK4".to_string()),
-                customer_note: None,
-                location_id: Some("L1JC53TYHS40Z".to_string()),
-                location_type: None,
-                seller_note: Some("be nice!".to_string()),
-                source: None,
-                start_at: Some("2022-10-11T16:30:00Z".to_string()),
-                status: None,
-                transition_time_minutes: None,
-                updated_at: None,
-                version: None
-            }
-        };
+        let input = Builder::from(BookingsUpdate::default())
+            .seller_note("be nice!".to_string())
+            .build_sync()
+            .unwrap();
 
         let res = sut.bookings()
             .update(input, "oruft3c9lh0duq".to_string())
@@ -870,8 +1083,144 @@ mod test_bookings {
         assert!(res.is_ok())
     }
 
+    #[test]
+    fn test_bookings_update_builder() {
+        let expected = r#"{"booking":{"seller_note":"be nice!","version":2}}"#;
+
+        let mut actual = Builder::from(BookingsUpdate::default())
+            .seller_note("be nice!".to_string())
+            .version(2)
+            .build_sync()
+            .unwrap();
+
+        assert!(actual.idempotency_key.is_some());
+
+        actual.idempotency_key = None;
+
+        assert_eq!(expected, serde_json::to_string(&actual).unwrap())
+    }
+
+    #[test]
+    fn test_bookings_update_builder_fail() {
+        let res = Builder::from(BookingsUpdate::default())
+            .version(2)
+            .build_sync();
+
+        assert!(res.is_err())
+    }
+
+    #[test]
+    fn test_bulk_retrieve_bookings_body_builder() {
+        let expected = BulkRetrieveBookingsBody {
+            booking_ids: vec!["oruft3c9lh0duq".to_string(), "9uv6i3p5x5ao1p".to_string()],
+        };
+
+        let actual = Builder::from(BulkRetrieveBookingsBody::default())
+            .add_booking_id("oruft3c9lh0duq".to_string())
+            .add_booking_id("9uv6i3p5x5ao1p".to_string())
+            .build_sync();
+
+        assert!(Builder::from(BulkRetrieveBookingsBody::default()).build_sync().is_err());
+        assert_eq!(format!("{:?}", expected), format!("{:?}", actual.unwrap()))
+    }
+
+    #[test]
+    fn test_deserialize_bulk_retrieve_bookings_response() {
+        use crate::objects::{BookingsEnum, Response};
+
+        let payload = r#"{
+            "bookings": {
+                "oruft3c9lh0duq": {
+                    "booking": { "id": "oruft3c9lh0duq", "version": 1 },
+                    "errors": []
+                },
+                "9uv6i3p5x5ao1p": {
+                    "errors": [{ "category": "INVALID_REQUEST_ERROR", "code": "NOT_FOUND" }]
+                }
+            }
+        }"#;
+
+        let res: SquareResponse = serde_json::from_str(payload).unwrap();
+
+        match res.response {
+            Some(Response::Bookings(BookingsEnum::Map(bookings))) => {
+                assert_eq!(bookings.len(), 2);
+                assert!(bookings["oruft3c9lh0duq"].booking.is_some());
+                assert!(bookings["9uv6i3p5x5ao1p"].booking.is_none());
+            },
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+
     #[tokio::test]
-    async fn test_list_bookings_query_builder() {
+    async fn test_bulk_retrieve_bookings() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let input = Builder::from(BulkRetrieveBookingsBody::default())
+            .add_booking_id("oruft3c9lh0duq".to_string())
+            .build_sync()
+            .unwrap();
+
+        let res = sut.bookings().bulk_retrieve(input).await;
+
+        assert!(res.is_ok())
+    }
+
+    #[test]
+    fn test_list_location_booking_profiles_query_builder() {
+        let expected = vec![
+            ("limit".to_string(), "10".to_string()),
+            ("cursor".to_string(), "some_cursor".to_string()),
+        ];
+
+        let actual = ListLocationBookingProfilesQueryBuilder::new()
+            .cursor("some_cursor".to_string())
+            .limit(10)
+            .build_sync();
+
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_list_location_booking_profiles() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let res = sut.bookings().list_location_profiles(None).await;
+
+        assert!(res.is_ok())
+    }
+
+    #[tokio::test]
+    async fn test_bulk_retrieve_team_member_booking_profiles() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let input = Builder::from(BulkRetrieveTeamMemberProfilesBody::default())
+            .add_team_member_id("TMKFnToW8ByXrcm6".to_string())
+            .build_sync()
+            .unwrap();
+
+        let res = sut.bookings().bulk_retrieve_team_member_profiles(input).await;
+
+        assert!(res.is_ok())
+    }
+
+    #[test]
+    fn test_list_bookings_query_builder() {
         let expected = vec![
             ("location_id".to_string(), "L1JC53TYHS40Z".to_string()),
             ("start_at_min".to_string(), "2022-09-12T07:20:50.52Z".to_string()),
@@ -880,10 +1229,9 @@ mod test_bookings {
         let actual = ListBookingsQueryBuilder::new()
             .location_id("L1JC53TYHS40Z".to_string())
             .start_at_min("2022-09-12T07:20:50.52Z".to_string())
-            .build()
-            .await;
+            .build_sync();
 
-        assert_eq!(expected, actual)
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap())
 
 
     }
@@ -897,9 +1245,9 @@ mod test_bookings {
         let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token);
 
-        let input = vec![
-            ("start_at_min".to_string(), "2022-09-12T07:20:50.52Z".to_string())
-        ];
+        let input = ListBookingsQueryBuilder::new()
+            .start_at_min("2022-09-12T07:20:50.52Z")
+            .build_sync();
 
         let res = sut.bookings().list(Some(input)).await;
 
@@ -920,8 +1268,8 @@ mod test_bookings {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_list_team_member_booking_profile_query_builder() {
+    #[test]
+    fn test_list_team_member_booking_profile_query_builder() {
         let expected = vec![
             ("limit".to_string(), "10".to_string()),
             ("bookable_only".to_string(), "true".to_string()),
@@ -932,10 +1280,9 @@ mod test_bookings {
             .bookable_only()
             .limit(10)
             .location_id("L1JC53TYHS40Z".to_string())
-            .build()
-            .await;
+            .build_sync();
 
-        assert_eq!(expected, actual)
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap())
 
 
     }
@@ -949,11 +1296,11 @@ mod test_bookings {
         let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token);
 
-        let input = vec![
-            ("limit".to_string(), "10".to_string()),
-            ("bookable_only".to_string(), "true".to_string()),
-            ("location_id".to_string(), "L1JC53TYHS40Z".to_string()),
-        ];
+        let input = ListTeamMemberBookingsProfileBuilder::new()
+            .limit(10)
+            .bookable_only()
+            .location_id("L1JC53TYHS40Z")
+            .build_sync();
 
         let res = sut.bookings()
             .list_team_member_profiles(Some(input))
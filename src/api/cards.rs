@@ -3,20 +3,20 @@ Cards functionality of the [Square API](https://developer.squareup.com).
  */
 
 use crate::client::SquareClient;
-use crate::api::{Verb, SquareAPI};
-use crate::errors::{CardBuildError, SquareError, ValidationError};
+use crate::api::{query_pairs, Verb, SquareAPI};
+use crate::errors::SquareError;
 use crate::response::SquareResponse;
-use crate::objects::{Address, Card};
+use crate::objects::{Address, Card, Response};
 
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
-use crate::builder::{Builder, ParentBuilder, Validate};
-use crate::objects::enums::SortOrder;
+use crate::builder::{Builder, ParentBuilder, SquareBuilder};
+use crate::objects::enums::{CardBrand, CardType, SortOrder};
+use crate::objects::ids::{CardId, CustomerId, PaymentId};
 
 impl SquareClient {
-    pub fn cards(&self) -> Cards {
+    pub fn cards(&self) -> Cards<'_> {
         Cards {
-            client: &self,
+            client: self,
         }
     }
 }
@@ -44,11 +44,11 @@ impl<'a> Cards<'a> {
     ///         .retrieve("some_id".to_string())
     ///         .await;
     /// };
-    pub async fn retrieve(self, card_id: String)
+    pub async fn retrieve(self, card_id: impl Into<CardId>)
                                -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
-            SquareAPI::Cards(format!("/{}", card_id)),
+            SquareAPI::Cards(format!("/{}", card_id.into())),
             None::<&Card>,
             None,
         ).await
@@ -57,6 +57,11 @@ impl<'a> Cards<'a> {
     /// See which [Card](Card)s are on file by requesting the information from the
     /// [Square API](https://developer.squareup.com) and receiving them formatted as a
     /// list of [Card](Card)s.
+    ///
+    /// The [Square API](https://developer.squareup.com) does not support filtering by card brand
+    /// or type, so these filters of the [ListCardsQueryBuilder](ListCardsQueryBuilder) are
+    /// applied to the returned [Card](Card)s instead. Further pages are requested until the
+    /// filtered page holds as many cards as the first unfiltered page or no pages are left.
     /// # Example
     /// ```rust
     ///use square_ox::{
@@ -71,14 +76,48 @@ impl<'a> Cards<'a> {
     ///         .await;
     /// };
     /// ```
-    pub async fn list(self, search_query: Option<Vec<(String, String)>>)
+    pub async fn list(self, search_query: Option<ListCardsParams>)
                             -> Result<SquareResponse, SquareError> {
-        self.client.request(
+        let mut search_query = search_query.unwrap_or_default();
+
+        let mut res = self.client.request(
             Verb::GET,
             SquareAPI::Cards("".to_string()),
             None::<&Card>,
-            search_query,
-        ).await
+            query_pairs(Some(&search_query))?,
+        ).await?;
+
+        if search_query.card_brand.is_none() && search_query.card_type.is_none() {
+            return Ok(res);
+        }
+
+        // an unfiltered page holds as many cards as the first page returned by Square, keep
+        // following the cursor until the filtered page holds as many or no pages are left
+        let page_size = page_len(&mut res);
+        cards_mut(&mut res).for_each(|cards| cards.retain(|card| search_query.matches(card)));
+
+        while page_len(&mut res) < page_size && res.cursor.is_some() {
+            search_query.cursor = res.cursor.take();
+
+            let mut next = self.client.request(
+                Verb::GET,
+                SquareAPI::Cards("".to_string()),
+                None::<&Card>,
+                query_pairs(Some(&search_query))?,
+            ).await?;
+
+            let matching: Vec<Card> = cards_mut(&mut next)
+                .next()
+                .map(std::mem::take)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|card| search_query.matches(card))
+                .collect();
+            cards_mut(&mut res).for_each(|cards| cards.extend(matching.iter().cloned()));
+            res.cursor = next.cursor;
+        }
+
+        Ok(res)
     }
 
     /// Create a new [Card](Card) registered at the [Square API](https://developer.squareup.com).
@@ -98,8 +137,7 @@ impl<'a> Cards<'a> {
     ///     let card = Builder::from(CardWrapper::default())
     ///     .source_id("some_id".to_string())
     ///     .customer_id("some_id".to_string())
-    ///     .build()
-    ///     .await
+    ///     .build_sync()
     ///     .unwrap();
     ///
     ///     let locations = SquareClient::new("some_token")
@@ -136,17 +174,20 @@ impl<'a> Cards<'a> {
     ///         .await;
     /// };
     /// ```
-    pub async fn disable(self, card_id: String)
+    pub async fn disable(self, card_id: impl Into<CardId>)
                               -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::POST,
-            SquareAPI::Cards(format!("/{}/disable", card_id)),
+            SquareAPI::Cards(format!("/{}/disable", card_id.into())),
             None::<&Card>,
             None,
         ).await
     }
 }
 
+// -------------------------------------------------------------------------------------------------
+// ListCardsQueryBuilder implementation
+// -------------------------------------------------------------------------------------------------
 #[derive(Default)]
 pub struct ListCardsQueryBuilder {
     cursor: Option<String>,
@@ -154,6 +195,8 @@ pub struct ListCardsQueryBuilder {
     include_disabled: Option<bool>,
     reference_id: Option<String>,
     sort_order: Option<SortOrder>,
+    card_brand: Option<CardBrand>,
+    card_type: Option<CardType>,
 }
 
 impl ListCardsQueryBuilder {
@@ -167,8 +210,8 @@ impl ListCardsQueryBuilder {
         self
     }
 
-    pub fn customer_id(mut self, customer_id: String) -> Self {
-        self.customer_id = Some(customer_id);
+    pub fn customer_id(mut self, customer_id: impl Into<CustomerId>) -> Self {
+        self.customer_id = Some(customer_id.into().into());
 
         self
     }
@@ -203,72 +246,127 @@ impl ListCardsQueryBuilder {
         self
     }
 
-    pub async fn build(self) -> Vec<(String, String)> {
+    /// Only return cards of the given [CardBrand](CardBrand). This filter is applied by
+    /// [Cards::list](Cards::list) to the returned cards.
+    pub fn card_brand(mut self, card_brand: CardBrand) -> Self {
+        self.card_brand = Some(card_brand);
+
+        self
+    }
+
+    /// Only return cards of the given [CardType](CardType). This filter is applied by
+    /// [Cards::list](Cards::list) to the returned cards.
+    pub fn card_type(mut self, card_type: CardType) -> Self {
+        self.card_type = Some(card_type);
+
+        self
+    }
+
+    pub fn build_sync(self) -> ListCardsParams {
         let ListCardsQueryBuilder {
             cursor,
             customer_id,
             include_disabled,
             reference_id,
             sort_order,
+            card_brand,
+            card_type,
         } = self;
 
-        let mut res = vec![];
-
-        if let Some(cursor) = cursor {
-            res.push(("cursor".to_string(), cursor))
-        }
-        if let Some(customer_id) = customer_id {
-            res.push(("customer_id".to_string(), customer_id))
-        }
-        if let Some(include_disabled) = include_disabled {
-            res.push(("include_disabled".to_string(), include_disabled.to_string()))
-        }
-        if let Some(reference_id) = reference_id {
-            res.push(("reference_id".to_string(), reference_id))
-        }
-        if let Some(sort_order) = sort_order {
-            res.push(("sort_order".to_string(), sort_order.to_string()))
+        ListCardsParams {
+            cursor,
+            customer_id,
+            include_disabled,
+            reference_id,
+            sort_order,
+            card_brand,
+            card_type,
         }
+    }
 
-        res
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListCardsParams {
+        self.build_sync()
     }
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize, Default)]
-pub struct CardWrapper {
-    pub(crate) card: Card,
-    pub(crate) idempotency_key: Option<String>,
-    pub(crate) source_id: Option<String>,
-    pub(crate) verification_token: Option<String>,
+/// The query parameters of [Cards::list](Cards::list), built through the
+/// [ListCardsQueryBuilder](ListCardsQueryBuilder). The card brand and type are not sent to the
+/// Square API but filter the returned cards.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListCardsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_disabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_order: Option<SortOrder>,
+    #[serde(skip)]
+    card_brand: Option<CardBrand>,
+    #[serde(skip)]
+    card_type: Option<CardType>,
 }
 
-impl Validate for CardWrapper {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.source_id.is_some() {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
+impl ListCardsParams {
+    // checks a card against the card brand and card type filters
+    fn matches(&self, card: &Card) -> bool {
+        let brand_matches = self.card_brand.is_none() || card.card_brand == self.card_brand;
+        let type_matches = self.card_type.is_none() || card.card_type == self.card_type;
 
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
+        brand_matches && type_matches
     }
 }
 
-impl<T: ParentBuilder> Builder<CardWrapper, T> {
-    pub fn customer_id(mut self, customer_id: String) -> Self {
-        self.body.card.customer_id = Some(customer_id);
+// the lists of cards held by a list response
+fn cards_mut(res: &mut SquareResponse) -> impl Iterator<Item = &mut Vec<Card>> {
+    IntoIterator::into_iter([
+        &mut res.response,
+        &mut res.opt_response01,
+        &mut res.opt_response02,
+        &mut res.opt_response03,
+    ])
+        .filter_map(|response| match response {
+            Some(Response::Cards(cards)) => Some(cards),
+            _ => None,
+        })
+}
 
-        self
-    }
+// the number of cards held by a list response
+fn page_len(res: &mut SquareResponse) -> usize {
+    cards_mut(res).next().map_or(0, |cards| cards.len())
+}
 
-    pub fn billing_address(mut self, address: Address) -> Self {
-        self.body.card.billing_address = Some(address);
+// -------------------------------------------------------------------------------------------------
+// CardWrapper builder implementation
+// -------------------------------------------------------------------------------------------------
 
-        self
-    }
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+pub struct CardWrapper {
+    #[builder(flatten(
+        customer_id: Option<CustomerId>,
+        billing_address: Option<Address>,
+        cardholder_name: Option<String>,
+        reference_id: Option<String>,
+    ))]
+    pub(crate) card: Card,
+    #[idempotency_key]
+    pub(crate) idempotency_key: Option<String>,
+    #[required]
+    pub(crate) source_id: Option<String>,
+    /// An identifying token generated by the Web Payments SDK `verifyBuyer()` function, used for
+    /// Strong Customer Authentication.
+    pub(crate) verification_token: Option<String>,
+}
 
-    pub fn source_id(mut self, source_id: String) -> Self {
-        self.body.source_id = Some(source_id);
+impl<T: ParentBuilder> Builder<CardWrapper, T> {
+    /// Create the card on file from the card used in an existing
+    /// [Payment](crate::objects::Payment), instead of a card nonce.
+    pub fn payment_id(mut self, payment_id: impl Into<PaymentId>) -> Self {
+        self.body.source_id = Some(payment_id.into().into());
 
         self
     }
@@ -277,9 +375,10 @@ impl<T: ParentBuilder> Builder<CardWrapper, T> {
 #[cfg(test)]
 mod test_cards {
     use super::*;
+    use uuid::Uuid;
 
-    #[tokio::test]
-    async fn test_list_cards_query_builder() {
+    #[test]
+    fn test_list_cards_query_builder() {
         let expected = vec![
             ("cursor".to_string(), "dwcsdaw2390rec92".to_string()),
             ("include_disabled".to_string(), "false".to_string()),
@@ -289,10 +388,47 @@ mod test_cards {
             .exclude_disabled()
             .sort_ascending()
             .cursor("dwcsdaw2390rec92".to_string())
-            .build()
-            .await;
+            .build_sync();
+
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap())
+    }
+
+    #[test]
+    fn test_list_cards_query_builder_brand_and_type() {
+        let expected = vec![
+            ("customer_id".to_string(), "EDH2RWZCFCRGZCZ99GMG8ZF59R".to_string()),
+        ];
+        let actual = ListCardsQueryBuilder::new()
+            .card_type(CardType::Credit)
+            .card_brand(CardBrand::AmericanExpress)
+            .customer_id("EDH2RWZCFCRGZCZ99GMG8ZF59R".to_string())
+            .build_sync();
+
+        // the brand and type only filter the returned cards and are not sent
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap());
+        assert_eq!(actual.card_brand, Some(CardBrand::AmericanExpress));
+        assert_eq!(actual.card_type, Some(CardType::Credit));
+    }
+
+    #[test]
+    fn test_matches_filters() {
+        let card = Card {
+            card_brand: Some(CardBrand::Visa),
+            card_type: Some(CardType::Debit),
+            ..Default::default()
+        };
 
-        assert_eq!(expected, actual)
+        assert!(ListCardsQueryBuilder::new().build_sync().matches(&card));
+        assert!(ListCardsQueryBuilder::new().card_brand(CardBrand::Visa).build_sync().matches(&card));
+        assert!(!ListCardsQueryBuilder::new()
+            .card_brand(CardBrand::Visa)
+            .card_type(CardType::Credit)
+            .build_sync()
+            .matches(&card));
+        assert!(!ListCardsQueryBuilder::new()
+            .card_type(CardType::Debit)
+            .build_sync()
+            .matches(&Card::default()));
     }
 
     #[tokio::test]
@@ -304,10 +440,10 @@ mod test_cards {
         let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token);
 
-        let input = vec![
-            ("include_disabled".to_string(), "false".to_string()),
-            ("sort_order".to_string(), "ASC".to_string()),
-        ];
+        let input = ListCardsQueryBuilder::new()
+            .exclude_disabled()
+            .sort_ascending()
+            .build_sync();
 
         let res = sut.cards()
             .list(Some(input))
@@ -334,8 +470,8 @@ mod test_cards {
 
     }
 
-    #[tokio::test]
-    async fn test_card_builder() {
+    #[test]
+    fn test_card_builder() {
         let expected = CardWrapper {
             card: Card {
                 id: None,
@@ -345,7 +481,7 @@ mod test_cards {
                 card_co_brand: None,
                 card_type: None,
                 cardholder_name: None,
-                customer_id: Some("EDH2RWZCFCRGZCZ99GMG8ZF59R".to_string()),
+                customer_id: Some("EDH2RWZCFCRGZCZ99GMG8ZF59R".into()),
                 enabled: None,
                 exp_month: None,
                 exp_year: None,
@@ -364,8 +500,7 @@ mod test_cards {
         let mut actual = Builder::from(CardWrapper::default())
             .customer_id("EDH2RWZCFCRGZCZ99GMG8ZF59R".to_string())
             .source_id("cnon:card-nonce-ok".to_string())
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert!(actual.idempotency_key.is_some());
@@ -375,6 +510,19 @@ mod test_cards {
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual));
     }
 
+    #[test]
+    fn test_card_builder_from_payment() {
+        let actual = Builder::from(CardWrapper::default())
+            .customer_id("EDH2RWZCFCRGZCZ99GMG8ZF59R".to_string())
+            .payment_id("KkAkhdMsgzn59SM8A89WgKwekxLZY".to_string())
+            .verification_token("verf:some-token".to_string())
+            .build_sync()
+            .unwrap();
+
+        assert_eq!(actual.source_id, Some("KkAkhdMsgzn59SM8A89WgKwekxLZY".to_string()));
+        assert_eq!(actual.verification_token, Some("verf:some-token".to_string()));
+    }
+
     // #[tokio::test]
     async fn test_create_card() {
         use dotenv::dotenv;
@@ -393,7 +541,7 @@ mod test_cards {
                 card_co_brand: None,
                 card_type: None,
                 cardholder_name: None,
-                customer_id: Some("EDH2RWZCFCRGZCZ99GMG8ZF59R".to_string()),
+                customer_id: Some("EDH2RWZCFCRGZCZ99GMG8ZF59R".into()),
                 enabled: None,
                 exp_month: None,
                 exp_year: None,
@@ -0,0 +1,344 @@
+/*!
+Cash Drawer Shifts functionality of the [Square API](https://developer.squareup.com).
+ */
+
+use crate::api::{query_pairs, SquareAPI, Verb};
+use crate::client::SquareClient;
+use crate::datetime::Timestamp;
+use crate::errors::SquareError;
+use crate::objects::CashDrawerShift;
+use crate::objects::enums::SortOrder;
+use crate::response::SquareResponse;
+use crate::objects::ids::LocationId;
+
+use serde::Serialize;
+
+impl SquareClient {
+    /// Returns a [CashDrawers](CashDrawers) object through which you can make calls specifically
+    /// to the Cash Drawer Shifts endpoint of the [Square API](https://developer.squareup.com).
+    /// # Example: Retrieve a cash drawer shift.
+    /// ```rust
+    /// use square_ox::{
+    ///         response::{SquareResponse, ResponseError},
+    ///         client::SquareClient,
+    ///     };
+    ///
+    /// async {
+    ///     let shift = SquareClient::new("some_token")
+    ///         .cash_drawers()
+    ///         .retrieve_shift(
+    ///             "some_shift_id".to_string(),
+    ///             "some_location_id".to_string(),
+    ///         )
+    ///         .await;
+    ///     };
+    /// ```
+    pub fn cash_drawers(&self) -> CashDrawers<'_> {
+        CashDrawers {
+            client: self,
+        }
+    }
+}
+
+/// Allows you to make calls to the [Square API](https://developer.squareup.com) at the Cash
+/// Drawer Shifts endpoint with all currently implemented methods.
+pub struct CashDrawers<'a> {
+    client: &'a SquareClient,
+}
+
+impl<'a> CashDrawers<'a> {
+    /// Provides the details for all of the [CashDrawerShift](CashDrawerShift)s for a
+    /// [Location](crate::objects::Location) in a date range.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/cash-drawers/list-cash-drawer-shifts)
+    ///
+    /// # Arguments
+    /// * `location_id` - The ID of the location to query for a list of cash drawer shifts.
+    /// * `parameters` - The [ListCashDrawerShiftsParams](ListCashDrawerShiftsParams) created
+    ///   through the [ListCashDrawerShiftsParametersBuilder](ListCashDrawerShiftsParametersBuilder)
+    pub async fn list_shifts(
+        self,
+        location_id: impl Into<LocationId>,
+        parameters: Option<ListCashDrawerShiftsParams>
+    )
+        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::CashDrawers("/shifts".to_string()),
+            None::<&CashDrawerShift>,
+            query_pairs(Some(&LocationParams {
+                location_id: location_id.into(),
+                parameters: parameters.as_ref(),
+            }))?,
+        ).await
+    }
+
+    /// Provides the summary details for a single [CashDrawerShift](CashDrawerShift).
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/cash-drawers/retrieve-cash-drawer-shift)
+    ///
+    /// # Arguments
+    /// * `shift_id` - The shift ID.
+    /// * `location_id` - The ID of the location to retrieve cash drawer shifts from.
+    pub async fn retrieve_shift(self, shift_id: String, location_id: impl Into<LocationId>)
+                                -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::CashDrawers(format!("/shifts/{}", shift_id)),
+            None::<&CashDrawerShift>,
+            query_pairs(Some(&LocationParams {
+                location_id: location_id.into(),
+                parameters: None::<&()>,
+            }))?,
+        ).await
+    }
+
+    /// Provides a paginated list of events for a single [CashDrawerShift](CashDrawerShift).
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/cash-drawers/list-cash-drawer-shift-events)
+    ///
+    /// # Arguments
+    /// * `shift_id` - The shift ID.
+    /// * `location_id` - The ID of the location to list cash drawer shifts for.
+    /// * `parameters` - The [ListCashDrawerShiftEventsParams](ListCashDrawerShiftEventsParams)
+    ///   created through the
+    ///   [ListCashDrawerShiftEventsParametersBuilder](ListCashDrawerShiftEventsParametersBuilder)
+    pub async fn list_shift_events(
+        self,
+        shift_id: String,
+        location_id: impl Into<LocationId>,
+        parameters: Option<ListCashDrawerShiftEventsParams>
+    )
+        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::CashDrawers(format!("/shifts/{}/events", shift_id)),
+            None::<&CashDrawerShift>,
+            query_pairs(Some(&LocationParams {
+                location_id: location_id.into(),
+                parameters: parameters.as_ref(),
+            }))?,
+        ).await
+    }
+}
+
+// every endpoint of the Cash Drawer Shifts API requires the location_id as a query parameter,
+// followed by the parameters of the endpoint
+#[derive(Serialize)]
+struct LocationParams<'a, P: Serialize> {
+    location_id: LocationId,
+    #[serde(flatten)]
+    parameters: Option<&'a P>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// ListCashDrawerShiftsParametersBuilder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Default)]
+pub struct ListCashDrawerShiftsParametersBuilder {
+    sort_order: Option<SortOrder>,
+    begin_time: Option<String>,
+    end_time: Option<String>,
+    limit: Option<i32>,
+    cursor: Option<String>,
+}
+
+impl ListCashDrawerShiftsParametersBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The order in which cash drawer shifts are listed in the response, based on their
+    /// opened_at field.
+    pub fn sort_ascending(mut self) -> Self {
+        self.sort_order = Some(SortOrder::Asc);
+
+        self
+    }
+
+    /// The order in which cash drawer shifts are listed in the response, based on their
+    /// opened_at field.
+    pub fn sort_descending(mut self) -> Self {
+        self.sort_order = Some(SortOrder::Desc);
+
+        self
+    }
+
+    /// The inclusive start time of the query on opened_at, in ISO 8601 format.
+    pub fn begin_time(mut self, begin_time: impl Into<Timestamp>) -> Self {
+        self.begin_time = Some(begin_time.into().into());
+
+        self
+    }
+
+    /// The exclusive end date of the query on opened_at, in ISO 8601 format.
+    pub fn end_time(mut self, end_time: impl Into<Timestamp>) -> Self {
+        self.end_time = Some(end_time.into().into());
+
+        self
+    }
+
+    /// Number of cash drawer shift events in a page of results (200 by default, 1000 max).
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Opaque cursor for fetching the next page of results.
+    pub fn cursor(mut self, cursor: String) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+
+    pub fn build_sync(self) -> ListCashDrawerShiftsParams {
+        let ListCashDrawerShiftsParametersBuilder {
+            sort_order,
+            begin_time,
+            end_time,
+            limit,
+            cursor,
+        } = self;
+
+        ListCashDrawerShiftsParams {
+            sort_order,
+            begin_time,
+            end_time,
+            limit,
+            cursor,
+        }
+    }
+
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListCashDrawerShiftsParams {
+        self.build_sync()
+    }
+}
+
+/// The query parameters of [CashDrawers::list_shifts](CashDrawers::list_shifts), built through
+/// the [ListCashDrawerShiftsParametersBuilder](ListCashDrawerShiftsParametersBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListCashDrawerShiftsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_order: Option<SortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    begin_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// ListCashDrawerShiftEventsParametersBuilder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Default)]
+pub struct ListCashDrawerShiftEventsParametersBuilder {
+    limit: Option<i32>,
+    cursor: Option<String>,
+}
+
+impl ListCashDrawerShiftEventsParametersBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Number of resources to be returned in a page of results (200 by default, 1000 max).
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Opaque cursor for fetching the next page of results.
+    pub fn cursor(mut self, cursor: String) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+
+    pub fn build_sync(self) -> ListCashDrawerShiftEventsParams {
+        let ListCashDrawerShiftEventsParametersBuilder {
+            limit,
+            cursor,
+        } = self;
+
+        ListCashDrawerShiftEventsParams {
+            limit,
+            cursor,
+        }
+    }
+
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListCashDrawerShiftEventsParams {
+        self.build_sync()
+    }
+}
+
+/// The query parameters of [CashDrawers::list_shift_events](CashDrawers::list_shift_events),
+/// built through the
+/// [ListCashDrawerShiftEventsParametersBuilder](ListCashDrawerShiftEventsParametersBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListCashDrawerShiftEventsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+}
+
+#[cfg(test)]
+mod test_cash_drawers {
+    use super::*;
+
+    #[test]
+    fn test_list_cash_drawer_shifts_parameters_builder() {
+        let expected = vec![
+            ("sort_order".to_string(), "DESC".to_string()),
+            ("begin_time".to_string(), "2022-09-12T07:20:50.52Z".to_string()),
+            ("limit".to_string(), "20".to_string()),
+        ];
+
+        let actual = ListCashDrawerShiftsParametersBuilder::new()
+            .limit(20)
+            .begin_time("2022-09-12T07:20:50.52Z".to_string())
+            .sort_descending()
+            .build_sync();
+
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap())
+    }
+
+    #[test]
+    fn test_location_params() {
+        let expected = vec![
+            ("location_id".to_string(), "L1JC53TYHS40Z".to_string()),
+            ("limit".to_string(), "5".to_string()),
+        ];
+
+        let parameters = ListCashDrawerShiftEventsParametersBuilder::new()
+            .limit(5)
+            .build_sync();
+
+        let actual = LocationParams {
+            location_id: "L1JC53TYHS40Z".into(),
+            parameters: Some(&parameters),
+        };
+
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_list_cash_drawer_shifts() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let res = sut.cash_drawers()
+            .list_shifts("L1JC53TYHS40Z".to_string(), None)
+            .await;
+
+        assert!(res.is_ok())
+    }
+}
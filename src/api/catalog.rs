@@ -1,21 +1,23 @@
 /*!
 Catalog functionality of the [Square API](https://developer.squareup.com).
  */
-use crate::client::SquareClient;
-use crate::api::{Verb, SquareAPI};
-use crate::errors::{ObjectUpsertRequestBuildError, SquareError, ValidationError};
-use crate::response::SquareResponse;
-use crate::objects::{CatalogItem, CatalogObject, CatalogObjectVariation, CatalogQuery, CustomAttributeFilter, enums::CatalogObjectTypeEnum};
+use crate::client::{FormPart, SquareClient};
+use crate::datetime::Timestamp;
+use crate::api::{comma_separated, query_pairs, request_chunked, Verb, SquareAPI};
+use crate::errors::{FieldPath, SquareError, ValidationError, ViolationReason};
+use crate::response::{ChunkedResponse, SquareResponse};
+use crate::objects::{CatalogItem, CatalogObject, CatalogObjectBatch, CatalogObjectVariation, CatalogQuery, CustomAttributeFilter, enums::CatalogObjectTypeEnum};
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use crate::builder::{Builder, Nil, ParentBuilder, Validate};
+use crate::builder::{Builder, Nil, ParentBuilder, SquareBuilder, Validate};
 use crate::objects::enums::{CatalogItemProductType, CatalogObjectType, SearchCatalogItemsRequestStockLevel, SortOrder};
+use crate::objects::ids::CatalogObjectId;
 
 impl SquareClient {
-    pub fn catalog(&self) -> Catalog {
+    pub fn catalog(&self) -> Catalog<'_> {
         Catalog {
-            client: &self,
+            client: self,
         }
     }
 }
@@ -26,13 +28,13 @@ pub struct Catalog<'a> {
 
 impl<'a> Catalog<'a> {
     /// Returns a list of all [CatalogObjects](crate::objects::CatalogObject)s of the specified types in the catalog.
-    pub async fn list(self, list_parameters: Option<Vec<(String, String)>>)
+    pub async fn list(self, list_parameters: Option<ListCatalogParams>)
                               -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
             SquareAPI::Catalog("/list".to_string()),
             None::<&CatalogObject>,
-            list_parameters
+            query_pairs(list_parameters.as_ref())?
         ).await
     }
 
@@ -49,11 +51,11 @@ impl<'a> Catalog<'a> {
 
     /// Deletes a single CatalogObject based on the provided ID and returns the set of successfully
     /// deleted IDs in the response.
-    pub async fn delete_object(self, object_id: String)
+    pub async fn delete_object(self, object_id: impl Into<CatalogObjectId>)
                                        -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::DELETE,
-            SquareAPI::Catalog(format!("/object/{}", object_id)),
+            SquareAPI::Catalog(format!("/object/{}", object_id.into())),
             None::<&ObjectUpsertRequest>,
             None,
         ).await
@@ -63,15 +65,15 @@ impl<'a> Catalog<'a> {
     /// [CatalogObject](crate::objects::CatalogObject) based on the provided ID.
     pub async fn retrieve_object(
         self,
-        object_id: String,
-        parameters: Option<Vec<(String, String)>>
+        object_id: impl Into<CatalogObjectId>,
+        parameters: Option<RetrieveObjectParams>
     )
         -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
-            SquareAPI::Catalog(format!("/object/{}", object_id)),
+            SquareAPI::Catalog(format!("/object/{}", object_id.into())),
             None::<&ObjectUpsertRequest>,
-            parameters,
+            query_pairs(parameters.as_ref())?,
         ).await
     }
 
@@ -90,6 +92,24 @@ impl<'a> Catalog<'a> {
         ).await
     }
 
+    /// Chunked variant of [batch_retrieve_object](Catalog::batch_retrieve_object) that splits the
+    /// object ids into chunks of 1,000, sends at most `concurrency` requests at once and merges
+    /// the returned objects into one [ChunkedResponse](ChunkedResponse).
+    ///
+    /// # Arguments
+    /// * `body` - A [BatchRetrieveObjects](BatchRetrieveObjects) holding any number of object ids.
+    /// * `concurrency` - The maximum number of chunks that are requested at the same time.
+    pub async fn batch_retrieve_object_chunked(self, body: BatchRetrieveObjects, concurrency: usize)
+                                               -> ChunkedResponse<CatalogObjectId> {
+        let client = self.client;
+
+        request_chunked(body.object_ids.clone(), MAX_RETRIEVE_IDS, concurrency, |_, object_ids, _| {
+            let body = BatchRetrieveObjects { object_ids, ..body.clone() };
+
+            async move { Catalog { client }.batch_retrieve_object(body).await }
+        }).await
+    }
+
     /// Searches for [CatalogObject](crate::objects::CatalogObject) of any type by matching
     /// supported search attribute values, excluding custom attribute values on items or item
     /// variations, against one or more of the specified query filters.
@@ -127,8 +147,146 @@ impl<'a> Catalog<'a> {
             None,
         ).await
     }
+
+    /// Creates or updates up to 10,000 target [CatalogObject](crate::objects::CatalogObject)s,
+    /// grouped into batches of up to 1,000 objects each.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/catalog/batch-upsert-catalog-objects)
+    ///
+    /// New objects are given a temporary client id starting with `#` (e.g. `#new_item`) which
+    /// can be referenced by other objects in the same request. The permanent ids assigned by
+    /// Square are returned as [CatalogIdMapping](crate::objects::CatalogIdMapping)s in the
+    /// `id_mappings` field of the response.
+    pub async fn batch_upsert(self, body: BatchUpsertObjects)
+                              -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Catalog("/batch-upsert".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Deletes a set of [CatalogItem](crate::objects::CatalogItem)s based on the provided list of
+    /// target IDs and returns a set of successfully deleted IDs in the response.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/catalog/batch-delete-catalog-objects)
+    pub async fn batch_delete(self, body: BatchDeleteObjects)
+                              -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Catalog("/batch-delete".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Chunked variant of [batch_delete](Catalog::batch_delete) that splits the object ids into
+    /// chunks of 200, sends at most `concurrency` requests at once and merges the deleted ids into
+    /// one [ChunkedResponse](ChunkedResponse).
+    ///
+    /// # Arguments
+    /// * `body` - A [BatchDeleteObjects](BatchDeleteObjects) holding any number of object ids.
+    /// * `concurrency` - The maximum number of chunks that are requested at the same time.
+    pub async fn batch_delete_chunked(self, body: BatchDeleteObjects, concurrency: usize)
+                                      -> ChunkedResponse<CatalogObjectId> {
+        let client = self.client;
+
+        request_chunked(body.object_ids, MAX_DELETE_IDS, concurrency, |_, object_ids, _| {
+            let body = BatchDeleteObjects { object_ids };
+
+            async move { Catalog { client }.batch_delete(body).await }
+        }).await
+    }
+
+    /// Uploads an image file to be represented by a [CatalogImage](crate::objects::CatalogImage)
+    /// object that can be linked to an existing [CatalogObject](crate::objects::CatalogObject)
+    /// instance. Only JPEG and PNG files are accepted.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/catalog/create-catalog-image)
+    ///
+    /// # Example: Upload an image and attach it to an item
+    /// ```rust
+    /// use square_ox::{
+    ///     client::SquareClient,
+    ///     api::catalog::CreateCatalogImage,
+    ///     builder::Builder
+    /// };
+    ///
+    /// async {
+    ///     let image = Builder::from(CreateCatalogImage::default())
+    ///         .image_file(std::fs::read("coffee.png").unwrap())
+    ///         .name("Coffee".to_string())
+    ///         .object_id("some_item_id".to_string())
+    ///         .build_sync()
+    ///         .unwrap();
+    ///
+    ///     let res = SquareClient::new("some_token")
+    ///         .catalog()
+    ///         .create_image(image)
+    ///         .await;
+    /// };
+    /// ```
+    pub async fn create_image(self, body: CreateCatalogImage)
+                              -> Result<SquareResponse, SquareError> {
+        let content_type = image_content_type(&body.image_file).unwrap_or("image/jpeg");
+        let file_name = match content_type {
+            "image/png" => "image.png",
+            _ => "image.jpeg",
+        };
+
+        let parts = vec![
+            FormPart {
+                name: "request",
+                file_name: None,
+                content_type: "application/json",
+                data: serde_json::to_vec(&body)?,
+            },
+            FormPart {
+                name: "image_file",
+                file_name: Some(file_name.to_string()),
+                content_type,
+                data: body.image_file,
+            },
+        ];
+
+        self.client.multipart_request(
+            Verb::POST,
+            SquareAPI::Catalog("/images".to_string()),
+            parts,
+        ).await
+    }
+
+    /// Updates the [CatalogTax](crate::objects::CatalogTax) objects that apply to the targeted
+    /// [CatalogItem](crate::objects::CatalogItem)s without having to perform an upsert on the
+    /// entire item.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/catalog/update-item-taxes)
+    pub async fn update_item_taxes(self, body: UpdateItemTaxes)
+                                   -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Catalog("/update-item-taxes".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Updates the [CatalogModifierList](crate::objects::CatalogModifierList) objects that apply
+    /// to the targeted [CatalogItem](crate::objects::CatalogItem)s without having to perform an
+    /// upsert on the entire item.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/catalog/update-item-modifier-lists)
+    pub async fn update_item_modifier_lists(self, body: UpdateItemModifierLists)
+                                            -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Catalog("/update-item-modifier-lists".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
 }
 
+// the maximum number of object ids the Square API accepts in a single batch request
+const MAX_RETRIEVE_IDS: usize = 1000;
+const MAX_DELETE_IDS: usize = 200;
+
 // -------------------------------------------------------------------------------------------------
 // CatalogListParameterBuilder implementation
 // -------------------------------------------------------------------------------------------------
@@ -166,77 +324,63 @@ impl CatalogListParameterBuilder {
         self
     }
 
-    pub async fn build(self) -> Vec<(String, String)> {
-        let mut res = vec![];
-
-        if let Some(cursor) = self.cursor {
-            res.push(("cursor".to_string(), cursor))
-        }
-
-        if let Some(types) = self.types {
-            let mut combined = "".to_string();
-            for type_name in types {
-                combined = format!("{}{}%2C", combined, type_name);
-            }
-            if combined.len() > 3 {
-                for _ in 0..3 {
-                    combined.pop();
-                }
-            }
-            res.push(("types".to_string(), combined))
-        }
+    pub fn build_sync(self) -> ListCatalogParams {
+        let CatalogListParameterBuilder {
+            cursor,
+            types,
+            catalog_version,
+        } = self;
 
-        if let Some(catalog_version) = self.catalog_version {
-            res.push(("catalog_version".to_string(), catalog_version.to_string()))
+        ListCatalogParams {
+            cursor,
+            types,
+            catalog_version,
         }
+    }
 
-        res
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListCatalogParams {
+        self.build_sync()
     }
 }
 
+/// The query parameters of [Catalog::list](Catalog::list), built through the
+/// [CatalogListParameterBuilder](CatalogListParameterBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListCatalogParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "comma_separated")]
+    types: Option<Vec<CatalogObjectTypeEnum>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catalog_version: Option<i64>,
+}
+
 // -------------------------------------------------------------------------------------------------
 // ObjectUpsertRequest builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
+#[validate(with = "object_identity")]
 pub struct ObjectUpsertRequest {
+    #[idempotency_key]
     idempotency_key: Option<String>,
+    #[builder(flatten(
+        id: Option<CatalogObjectId>,
+        #[builder(setter = "object_type")]
+        type_name: Option<CatalogObjectType>,
+        item_data: Option<CatalogItem>,
+    ))]
     object: CatalogObject,
 }
 
-impl Validate for ObjectUpsertRequest {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.object.id.is_some() &&
-            self.object.type_name.is_some() {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
-
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
+fn object_identity(body: &ObjectUpsertRequest, errors: &mut ValidationError) {
+    errors.require("object.id", &body.object.id);
+    errors.require("object.type", &body.object.type_name);
 }
 
 impl<T: ParentBuilder> Builder<ObjectUpsertRequest, T> {
-    pub fn id(mut self, id: String) -> Self {
-        self.body.object.id = Some(id);
-
-        self
-    }
-
-    pub fn object_type(mut self, object_type: CatalogObjectType) -> Self {
-        self.body.object.type_name = Some(object_type);
-
-        self
-    }
-
-    pub fn item_data(mut self, item_data: CatalogItem) -> Self {
-        self.body.object.item_data = Some(item_data);
-
-        self
-    }
-
     pub fn add_variations(mut self, variation: CatalogObjectVariation) -> Self {
-        if let Some(mut item_data) = self.body.object.item_data.as_mut() {
+        if let Some(item_data) = self.body.object.item_data.as_mut() {
             if let Some(variations) = item_data.variations.as_mut() {
                 variations.push(variation)
             } else {
@@ -299,115 +443,62 @@ impl ObjectRetrieveParameterBuilder {
         self
     }
 
-    pub async fn build(self) -> Vec<(String, String)> {
+    pub fn build_sync(self) -> RetrieveObjectParams {
         let ObjectRetrieveParameterBuilder {
             include_related_objects,
             catalog_version,
         } = self;
 
-        let mut res = vec![];
-
-        if let Some(include_related_objects) = include_related_objects {
-            res.push(("include_related_objects".to_string(), include_related_objects.to_string()))
-        }
-        if let Some(catalog_version) = catalog_version {
-            res.push(("catalog_version".to_string(), catalog_version.to_string()))
+        RetrieveObjectParams {
+            include_related_objects,
+            catalog_version,
         }
+    }
 
-        res
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> RetrieveObjectParams {
+        self.build_sync()
     }
 }
 
+/// The query parameters of [Catalog::retrieve_object](Catalog::retrieve_object), built through
+/// the [ObjectRetrieveParameterBuilder](ObjectRetrieveParameterBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RetrieveObjectParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_related_objects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catalog_version: Option<i64>,
+}
+
 // -------------------------------------------------------------------------------------------------
 // SearchCatalogObjectsBody builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
 pub struct SearchCatalogObjectsBody {
-    begin_time: Option<String>,
+    begin_time: Option<Timestamp>,
     cursor: Option<String>,
+    #[builder(skip, preset(include_deleted_objects = "true", exclude_deleted_objects = "false"))]
     include_deleted_objects: Option<bool>,
+    #[builder(skip, preset(include_related_objects = "true", exclude_related_objects = "false"))]
     include_related_objects: Option<bool>,
     limit: Option<i64>,
     object_types: Option<Vec<CatalogObjectType>>,
     query: Option<CatalogQuery>,
 }
 
-impl Validate for SearchCatalogObjectsBody {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        Ok(self)
-    }
-}
-
 impl<T: ParentBuilder> Builder<SearchCatalogObjectsBody, T> {
-    pub fn begin_time(mut self, begin_time: String) -> Self {
-        self.body.begin_time = Some(begin_time);
-
-        self
-    }
-
-    pub fn cursor(mut self, cursor: String) -> Self {
-        self.body.cursor = Some(cursor);
-
-        self
-    }
-
-    pub fn include_deleted_objects(mut self) -> Self {
-        self.body.include_deleted_objects = Some(true);
-
-        self
-    }
-
-    pub fn exclude_deleted_objects(mut self) -> Self {
-        self.body.include_deleted_objects = Some(false);
-
-        self
-    }
-
-    pub fn include_related_objects(mut self) -> Self {
-        self.body.include_related_objects = Some(true);
-
-        self
-    }
-
-    pub fn exclude_related_objects(mut self) -> Self {
-        self.body.include_related_objects = Some(false);
-
-        self
-    }
-
-    pub fn limit(mut self, limit: i64) -> Self {
-        self.body.limit = Some(limit);
-
-        self
-    }
-
     pub fn set_object_types(mut self, object_types: Vec<CatalogObjectType>) -> Self {
         self.body.object_types = Some(object_types);
 
         self
     }
-
-    pub fn add_object_type(mut self, object_type: CatalogObjectType) -> Self {
-        if let Some(object_types) = self.body.object_types.as_mut() {
-            object_types.push(object_type)
-        } else {
-            self.body.object_types = Some(vec![object_type])
-        }
-
-        self
-    }
-
-    pub fn query(mut self, query: CatalogQuery) -> Self {
-        self.body.query = Some(query);
-
-        self
-    }
 }
 
 // -------------------------------------------------------------------------------------------------
 // SearchCatalogItemsBody builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
 pub struct SearchCatalogItemsBody {
     category_ids: Option<Vec<String>>,
     cursor: Option<String>,
@@ -420,12 +511,6 @@ pub struct SearchCatalogItemsBody {
     text_filter: Option<String>,
 }
 
-impl Validate for SearchCatalogItemsBody {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        Ok(self)
-    }
-}
-
 impl<T: ParentBuilder> Builder<SearchCatalogItemsBody, T> {
     pub fn low_stock_level(mut self) -> Self {
         if let Some(vec) = self.body.stock_levels.as_mut() {
@@ -441,54 +526,215 @@ impl<T: ParentBuilder> Builder<SearchCatalogItemsBody, T> {
 // -------------------------------------------------------------------------------------------------
 // BatchRetrieveObjects builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Default)]
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
 pub struct BatchRetrieveObjects {
-    pub object_ids: Vec<String>,
+    #[required]
+    pub object_ids: Vec<CatalogObjectId>,
     pub catalog_version: Option<i32>,
+    #[builder(skip, preset(include_deleted_objects = "true"))]
     pub include_deleted_objects: Option<bool>,
+    #[builder(skip, preset(include_related_objects = "true"))]
     pub include_related_objects: Option<bool>,
 }
 
-impl Validate for BatchRetrieveObjects {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.object_ids.len() > 0 {
-            Ok(self)
-        } else {
-            Err(ValidationError)
+// -------------------------------------------------------------------------------------------------
+// BatchUpsertObjects builder implementation
+// -------------------------------------------------------------------------------------------------
+const MAX_OBJECTS_PER_BATCH: usize = 1000;
+const MAX_OBJECTS_PER_UPSERT: usize = 10000;
+
+/// Build a request to upsert many [CatalogObject](CatalogObject)s at once.
+///
+/// Every object must have an id and a type. Objects that are to be created must use a temporary
+/// id starting with `#`, which other objects in the same request can use to reference them.
+/// Objects added through `.add_object()` are placed into the last batch, and a new batch is
+/// started automatically once a batch holds 1,000 objects.
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
+#[validate(with = "batch_limits")]
+pub struct BatchUpsertObjects {
+    #[idempotency_key]
+    idempotency_key: Option<String>,
+    #[builder(skip)]
+    batches: Vec<CatalogObjectBatch>,
+}
+
+fn batch_limits(body: &BatchUpsertObjects, errors: &mut ValidationError) {
+    let total: usize = body.batches.iter().map(|batch| batch.objects.len()).sum();
+    errors.check(total > 0, "batches", ViolationReason::Empty);
+    if total > MAX_OBJECTS_PER_UPSERT {
+        errors.invalid("batches", format!("must hold at most {} objects in total",
+                                          MAX_OBJECTS_PER_UPSERT));
+    }
+
+    for (i, batch) in body.batches.iter().enumerate() {
+        let path = FieldPath::from("batches").index(i);
+
+        errors.check(!batch.objects.is_empty(), path.clone().field("objects"),
+                     ViolationReason::Empty);
+        if batch.objects.len() > MAX_OBJECTS_PER_BATCH {
+            errors.invalid(path.clone().field("objects"),
+                           format!("must hold at most {} objects", MAX_OBJECTS_PER_BATCH));
+        }
+
+        for (j, object) in batch.objects.iter().enumerate() {
+            let path = path.clone().field("objects").index(j);
+
+            errors.check(object.id.as_ref().is_some_and(|id| !id.is_empty()),
+                         path.clone().field("id"), ViolationReason::Required);
+            errors.require(path.field("type"), &object.type_name);
         }
     }
 }
 
-impl<T: ParentBuilder> Builder<BatchRetrieveObjects, T> {
-    pub fn object_ids(mut self, ids: Vec<String>) -> Self {
-        self.body.object_ids = ids;
-        
+impl<T: ParentBuilder> Builder<BatchUpsertObjects, T> {
+    /// Add a complete batch of objects. Objects within a batch are committed together, and a
+    /// failure in one batch does not affect the other batches.
+    pub fn add_batch(mut self, objects: Vec<CatalogObject>) -> Self {
+        self.body.batches.push(CatalogObjectBatch { objects });
+
         self
     }
-    
-    pub fn add_object_id(mut self, id: String) -> Self {
-        self.body.object_ids.push(id);
-        
+
+    /// Add a single object to the last batch.
+    pub fn add_object(mut self, object: CatalogObject) -> Self {
+        match self.body.batches.last_mut() {
+            Some(batch) if batch.objects.len() < MAX_OBJECTS_PER_BATCH => {
+                batch.objects.push(object)
+            }
+            _ => self.body.batches.push(CatalogObjectBatch { objects: vec![object] }),
+        }
+
         self
     }
-    
-    pub fn catalog_version(mut self, version: i32) -> Self {
-        self.body.catalog_version = Some(version);
-        
+
+    /// Start a new batch that following calls of `.add_object()` will add to.
+    pub fn new_batch(mut self) -> Self {
+        self.body.batches.push(CatalogObjectBatch::default());
+
         self
     }
+}
+
+// -------------------------------------------------------------------------------------------------
+// BatchDeleteObjects builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
+pub struct BatchDeleteObjects {
+    #[required]
+    object_ids: Vec<CatalogObjectId>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// CreateCatalogImage builder implementation
+// -------------------------------------------------------------------------------------------------
+/// Build a request to upload a [CatalogImage](crate::objects::CatalogImage).
+///
+/// The image file is required and must be a JPEG or PNG.
+/// * `.image_file()`
+///
+/// The image is attached to an existing [CatalogObject](CatalogObject) if one is set through
+/// `.object_id()`, otherwise it is created as an unattached image.
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
+#[builder(skip_validate)]
+pub struct CreateCatalogImage {
+    #[builder(skip)]
+    idempotency_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(skip)]
+    object_id: Option<String>,
+    #[builder(flatten(
+        #[builder(flatten(name: Option<String>, caption: Option<String>))]
+        image_data: Option<CatalogImage>,
+    ))]
+    image: CatalogObject,
+    /// Make the image the primary image of the object set through `.object_id()`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(skip, preset(is_primary = "true"))]
+    is_primary: Option<bool>,
+    #[serde(skip)]
+    #[builder(skip)]
+    image_file: Vec<u8>,
+}
+
+impl Validate for CreateCatalogImage {
+    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
+        let mut errors = ValidationError::new();
+        if self.image_file.is_empty() {
+            errors.check(false, "image_file", ViolationReason::Required);
+        } else if image_content_type(&self.image_file).is_none() {
+            errors.invalid("image_file", "must be a JPEG or PNG image");
+        }
+
+        self.idempotency_key = Some(Uuid::new_v4().to_string());
+        self.image.type_name = Some(CatalogObjectType::Image);
+        if self.image.id.is_none() {
+            self.image.id = Some("#image".into());
+        }
+
+        errors.into_result(self)
+    }
+}
 
-    pub fn include_deleted_objects(mut self) -> Self {
-        self.body.include_deleted_objects = Some(true);
+impl<T: ParentBuilder> Builder<CreateCatalogImage, T> {
+    /// The raw bytes of the JPEG or PNG file to upload.
+    pub fn image_file(mut self, image_file: Vec<u8>) -> Self {
+        self.body.image_file = image_file;
 
         self
     }
 
-    pub fn include_related_objects(mut self) -> Self {
-        self.body.include_related_objects = Some(true);
+    /// The id of the [CatalogObject](CatalogObject) the image is to be attached to.
+    pub fn object_id(mut self, object_id: impl Into<CatalogObjectId>) -> Self {
+        self.body.object_id = Some(object_id.into().into());
 
         self
     }
+
+}
+
+// detects the content type of an image file by its magic number
+fn image_content_type(image_file: &[u8]) -> Option<&'static str> {
+    if image_file.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if image_file.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        Some("image/png")
+    } else {
+        None
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// UpdateItemTaxes builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
+pub struct UpdateItemTaxes {
+    #[required]
+    item_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "taxes")]
+    #[builder(each = "enable_tax")]
+    taxes_to_enable: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "taxes")]
+    #[builder(each = "disable_tax")]
+    taxes_to_disable: Option<Vec<String>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// UpdateItemModifierLists builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
+pub struct UpdateItemModifierLists {
+    #[required]
+    item_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "modifier_lists")]
+    #[builder(each = "enable_modifier_list")]
+    modifier_lists_to_enable: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "modifier_lists")]
+    #[builder(each = "disable_modifier_list")]
+    modifier_lists_to_disable: Option<Vec<String>>,
 }
 
 #[cfg(test)]
@@ -497,16 +743,17 @@ mod test_catalog {
     use crate::objects::enums::{CatalogItemProductType, CatalogObjectType, CatalogPricingType, Currency};
     use super::*;
 
-    #[tokio::test]
-    async fn test_list_parameter_builder() {
-        let expected = vec![("types".to_string(), "ITEM%2CCATEGORY".to_string())];
+    #[test]
+    fn test_list_parameter_builder() {
+        let expected = vec![("types".to_string(), "ITEM,CATEGORY".to_string())];
         let actual = CatalogListParameterBuilder::new()
             .add_type(CatalogObjectTypeEnum::Item)
             .add_type(CatalogObjectTypeEnum::Category)
             .add_type(CatalogObjectTypeEnum::Item)
-            .build().await;
+            .build_sync();
 
-        assert_eq!(expected, actual)
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap());
+        assert_eq!(serde_urlencoded::to_string(&actual).unwrap(), "types=ITEM%2CCATEGORY");
     }
 
     #[tokio::test]
@@ -518,7 +765,10 @@ mod test_catalog {
         let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token);
 
-        let input = vec![("types".to_string(), "ITEM,CATEGORY".to_string())];
+        let input = CatalogListParameterBuilder::new()
+            .add_type(CatalogObjectTypeEnum::Item)
+            .add_type(CatalogObjectTypeEnum::Category)
+            .build_sync();
 
         let res = sut.catalog()
             .list(Some(input))
@@ -527,12 +777,12 @@ mod test_catalog {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_upsert_object_request_builder() {
+    #[test]
+    fn test_upsert_object_request_builder() {
         let expected = ObjectUpsertRequest {
             idempotency_key: None,
             object: CatalogObject {
-                id: Some("#91039132".to_string()),
+                id: Some("#91039132".into()),
                 type_name: Some(CatalogObjectType::Item),
                 absent_at_location_ids: None,
                 catalog_v1_ids: None,
@@ -707,8 +957,7 @@ mod test_catalog {
                 created_at: None,
                 version: None
             })
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert!(actual.idempotency_key.is_some());
@@ -730,7 +979,7 @@ mod test_catalog {
         let input = ObjectUpsertRequest {
             idempotency_key: Some(Uuid::new_v4().to_string()),
             object: CatalogObject {
-                id: Some("#91039132".to_string()),
+                id: Some("#91039132".into()),
                 type_name: Some(CatalogObjectType::Item),
                 absent_at_location_ids: None,
                 catalog_v1_ids: None,
@@ -856,8 +1105,8 @@ mod test_catalog {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_object_retrieve_parameter_builder() {
+    #[test]
+    fn test_object_retrieve_parameter_builder() {
         let expected = vec![
             ("include_related_objects".to_string(), false.to_string()),
             ("catalog_version".to_string(), 1655427266071_i64.to_string()),
@@ -866,10 +1115,9 @@ mod test_catalog {
         let actual = ObjectRetrieveParameterBuilder::new()
             .exclude_related_objects()
             .catalog_version(1655427266071)
-            .build()
-            .await;
+            .build_sync();
 
-        assert_eq!(expected, actual);
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap());
     }
 
     #[tokio::test]
@@ -881,10 +1129,10 @@ mod test_catalog {
         let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token);
 
-        let input = vec![
-            ("include_related_objects".to_string(), false.to_string()),
-            ("catalog_version".to_string(), 1655427266071_i64.to_string()),
-        ];
+        let input = ObjectRetrieveParameterBuilder::new()
+            .exclude_related_objects()
+            .catalog_version(1655427266071)
+            .build_sync();
 
         let res = sut.catalog()
             .retrieve_object(
@@ -896,10 +1144,10 @@ mod test_catalog {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_search_catalog_object_body_builder() {
+    #[test]
+    fn test_search_catalog_object_body_builder() {
         let expected = SearchCatalogObjectsBody {
-            begin_time: Some("some time".to_string()),
+            begin_time: Some("2022-09-12T07:20:50.52Z".into()),
             cursor: None,
             include_deleted_objects: Some(false),
             include_related_objects: Some(true),
@@ -912,16 +1160,26 @@ mod test_catalog {
             .limit(100)
             .exclude_deleted_objects()
             .include_related_objects()
-            .begin_time("some time".to_string())
+            .begin_time("2022-09-12T07:20:50.52Z")
             .add_object_type(CatalogObjectType::Item)
             .add_object_type(CatalogObjectType::ItemVariation)
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert_eq!(format!("{:?}",expected), format!("{:?}",actual));
     }
 
+    #[test]
+    fn test_search_catalog_object_body_builder_invalid_begin_time() {
+        let res = Builder::from(SearchCatalogObjectsBody::default())
+            .begin_time("some time")
+            .build_sync();
+
+        let errors = res.unwrap_err();
+        assert_eq!(errors.violations().len(), 1);
+        assert_eq!(errors.violations()[0].path.to_string(), "begin_time");
+    }
+
     #[tokio::test]
     async fn test_search_objects() {
         use dotenv::dotenv;
@@ -964,8 +1222,8 @@ mod test_catalog {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_search_catalog_items_body_builder() {
+    #[test]
+    fn test_search_catalog_items_body_builder() {
         let expected = SearchCatalogItemsBody {
             category_ids: None,
             cursor: None,
@@ -979,8 +1237,7 @@ mod test_catalog {
         };
 
         let actual = Builder::from(SearchCatalogItemsBody::default())
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert_eq!(format!("{:?}",expected), format!("{:?}",actual));
@@ -1024,7 +1281,7 @@ mod test_catalog {
         let sut = SquareClient::new(&access_token);
         
         let input = BatchRetrieveObjects {
-            object_ids: vec!["6362XBFOY6N6F2J42ZE3JC5R".to_string(), "H4JPRP3IFQZKCW4R3CTKYOTO".to_string()],
+            object_ids: vec!["6362XBFOY6N6F2J42ZE3JC5R".into(), "H4JPRP3IFQZKCW4R3CTKYOTO".into()],
             catalog_version: None,
             include_deleted_objects: None,
             include_related_objects: None
@@ -1034,4 +1291,75 @@ mod test_catalog {
 
         assert!(res.is_ok())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_batch_upsert_objects_builder() {
+        let mut builder = Builder::from(BatchUpsertObjects::default());
+        for i in 0..1001 {
+            builder = builder.add_object(CatalogObject {
+                id: Some(format!("#item_{}", i).into()),
+                type_name: Some(CatalogObjectType::Item),
+                ..Default::default()
+            });
+        }
+
+        let actual = builder.build_sync().unwrap();
+
+        assert!(actual.idempotency_key.is_some());
+        assert_eq!(actual.batches.len(), 2);
+        assert_eq!(actual.batches[0].objects.len(), 1000);
+        assert_eq!(actual.batches[1].objects.len(), 1);
+    }
+
+    #[test]
+    fn test_batch_upsert_objects_builder_fail() {
+        let res = Builder::from(BatchUpsertObjects::default())
+            .add_object(CatalogObject {
+                id: None,
+                type_name: Some(CatalogObjectType::Item),
+                ..Default::default()
+            })
+            .build_sync();
+
+        assert!(res.is_err())
+    }
+
+    #[test]
+    fn test_create_catalog_image_builder() {
+        let png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00];
+
+        let actual = Builder::from(CreateCatalogImage::default())
+            .image_file(png)
+            .name("Coffee".to_string())
+            .object_id("W62UWFY35CWMYGVWK6TWJDNI".to_string())
+            .build_sync()
+            .unwrap();
+
+        assert_eq!(image_content_type(&actual.image_file), Some("image/png"));
+        assert_eq!(actual.image.id, Some("#image".into()));
+        assert!(matches!(actual.image.type_name, Some(CatalogObjectType::Image)));
+    }
+
+    #[test]
+    fn test_create_catalog_image_builder_fail() {
+        let res = Builder::from(CreateCatalogImage::default())
+            .image_file(b"GIF89a".to_vec())
+            .build_sync();
+
+        assert!(res.is_err())
+    }
+
+    #[test]
+    fn test_update_item_taxes_builder() {
+        let actual = Builder::from(UpdateItemTaxes::default())
+            .add_item_id("H42BRLUJ5KTZTTMPVSLFAACQ".to_string())
+            .enable_tax("4WRCNHCJZDVLSNDQ35PP2YAD".to_string())
+            .build_sync()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&actual).unwrap(),
+            r#"{"item_ids":["H42BRLUJ5KTZTTMPVSLFAACQ"],"taxes_to_enable":["4WRCNHCJZDVLSNDQ35PP2YAD"]}"#
+        );
+    }
+}
@@ -3,21 +3,21 @@ Checkout functionality of the [Square API](https://developer.squareup.com).
  */
 
 use crate::client::SquareClient;
-use crate::api::{Verb, SquareAPI};
-use crate::errors::{SquareError, ValidationError};
+use crate::api::{query_pairs, Verb, SquareAPI};
+use crate::errors::{FieldPath, SquareError, ValidationError};
 use crate::response::SquareResponse;
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use crate::builder::{AddField, Builder, ParentBuilder, Validate};
+use crate::builder::{AddField, Builder, ParentBuilder, SquareBuilder};
 use crate::objects::{self, Address, ChargeRequestAdditionalRecipient, CheckoutOptions,
                      CreateOrderRequest, Order, PaymentLink, PrePopulatedData,
                      QuickPay};
 
 impl SquareClient {
-    pub fn checkout(&self) -> Checkout {
+    pub fn checkout(&self) -> Checkout<'_> {
         Checkout {
-            client: &self
+            client: self
         }
     }
 }
@@ -32,7 +32,7 @@ impl<'a> Checkout<'a> {
     /// # Arguments:
     /// * `location_id` - The id of the location you would like to link to the checkout page.
     /// * `create_order_request`- The request body of the create_checkout call wrapped in a
-    /// [CreateOrderRequestWrapper](CreateOrderRequestWrapper).
+    ///   [CreateOrderRequestWrapper](CreateOrderRequestWrapper).
     pub async fn create_checkout(
         self, location_id: String,
         create_order_request: CreateOrderRequestWrapper
@@ -49,18 +49,17 @@ impl<'a> Checkout<'a> {
     /// Lists all payment links registered at the [Square API](https://developer.squareup.com).
     ///
     /// # Arguments:
-    /// * `search_query` - The parameters restricting the listing of payment links. They are build
-    /// through the [ListPaymentLinksSearchQueryBuilder](ListPaymentLinksSearchQueryBuilder) and in
-    /// vector form.
+    /// * `search_query` - The parameters restricting the listing of payment links. They are built
+    ///   through the [ListPaymentLinksSearchQueryBuilder](ListPaymentLinksSearchQueryBuilder).
     pub async fn list(
-        self, search_query: Option<Vec<(String, String)>>
+        self, search_query: Option<ListPaymentLinksParams>
     )
         -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
             SquareAPI::Checkout("/payment-links".to_string()),
             None::<&CreateOrderRequestWrapper>,
-            search_query,
+            query_pairs(search_query.as_ref())?,
         ).await
     }
 
@@ -69,8 +68,8 @@ impl<'a> Checkout<'a> {
     ///
     /// # Arguments:
     /// * `payment_link` - The body of the quest, holding the details of the payment link that is
-    /// being added. This body is wrapped by a [CreatePaymentLinkWrapper](CreatePaymentLinkWrapper).
-    /// The payment link must contain at least one Order or QuickPay object.
+    ///   being added. This body is wrapped by a [CreatePaymentLinkWrapper](CreatePaymentLinkWrapper).
+    ///   The payment link must contain at least one Order or QuickPay object.
     pub async fn create(
         self, payment_link: CreatePaymentLinkWrapper
     )
@@ -133,12 +132,17 @@ impl<'a> Checkout<'a> {
     }
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize)]
+#[derive(Clone, Serialize, Debug, Deserialize, SquareBuilder)]
+#[validate(with = "order_location")]
 pub struct CreateOrderRequestWrapper {
+    #[idempotency_key]
     idempotency_key: Option<String>,
+    #[builder(flatten(order: Order))]
     order: CreateOrderRequest,
+    #[builder(skip, preset(ask_for_shipping_address = "true"))]
     ask_for_shipping_address: Option<bool>,
     merchant_support_email: Option<String>,
+    #[builder(skip, preset(pre_populate_buyer_email = "true"))]
     pre_populate_buyer_email: Option<bool>,
     pre_populate_shipping_address: Option<Address>,
     redirect_url: Option<String>,
@@ -162,54 +166,18 @@ impl Default for CreateOrderRequestWrapper {
     }
 }
 
-impl Validate for CreateOrderRequestWrapper {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.order.order.location_id.is_some() {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
-
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
-}
-
-impl<T: ParentBuilder> Builder<CreateOrderRequestWrapper, T> {
-    pub fn order(mut self, order: Order) -> Self {
-        self.body.order.order = order;
-
-        self
-    }
-
-    pub fn ask_for_shipping_address(mut self) -> Self {
-        self.body.ask_for_shipping_address = Some(true);
-
-        self
-    }
-
-    pub fn merchant_support_email(mut self, merchant_support_email: String) -> Self {
-        self.body.merchant_support_email = Some(merchant_support_email);
-
-        self
-    }
-
-    pub fn redirect_url(mut self, redirect_url: String) -> Self {
-        self.body.redirect_url = Some(redirect_url);
-
-        self
-    }
-
-    pub fn pre_populate_buyer_email(mut self) -> Self {
-        self.body.pre_populate_buyer_email = Some(true);
-
-        self
-    }
+fn order_location(body: &CreateOrderRequestWrapper, errors: &mut ValidationError) {
+    errors.require("order.order.location_id", &body.order.order.location_id);
 }
 
 impl AddField<Order> for CreateOrderRequestWrapper {
     fn add_field(&mut self, field: Order) {
         self.order.order = field;
     }
+
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("order.order")
+    }
 }
 
 #[derive(Default)]
@@ -235,34 +203,46 @@ impl ListPaymentLinksSearchQueryBuilder {
         self
     }
 
-    pub async fn build(self) -> Vec<(String, String)> {
+    pub fn build_sync(self) -> ListPaymentLinksParams {
         let ListPaymentLinksSearchQueryBuilder {
             cursor,
             limit,
         } = self;
 
-        let mut res = vec![];
-
-        if let Some(cursor) = cursor {
-            res.push(("cursor".to_string() , cursor));
-        }
-
-        if let Some(limit) = limit {
-            res.push(("limit".to_string() , limit.to_string()));
+        ListPaymentLinksParams {
+            cursor,
+            limit,
         }
+    }
 
-        res
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListPaymentLinksParams {
+        self.build_sync()
     }
 }
 
-#[derive(Clone, Serialize, Debug, Default)]
+/// The query parameters of [Checkout::list](Checkout::list), built through the
+/// [ListPaymentLinksSearchQueryBuilder](ListPaymentLinksSearchQueryBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListPaymentLinksParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+}
+
+#[derive(Clone, Serialize, Debug, Default, SquareBuilder)]
 pub struct CreatePaymentLinkWrapper {
+    #[idempotency_key]
     idempotency_key: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "content")]
     quick_pay: Option<QuickPay>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "content")]
+    #[sub_builder]
     order: Option<Order>,
     #[serde(skip_serializing_if = "Option::is_none")]
     checkout_options: Option<CheckoutOptions>,
@@ -274,70 +254,10 @@ pub struct CreatePaymentLinkWrapper {
     payment_note: Option<String>,
 }
 
-impl Validate for CreatePaymentLinkWrapper {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.order.is_some() || self.quick_pay.is_some() {
-            self.idempotency_key = Uuid::new_v4().to_string();
-
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
-}
-
-impl<T: ParentBuilder> Builder<CreatePaymentLinkWrapper, T> {
-    pub fn checkout_options(mut self, checkout_options: CheckoutOptions) -> Self {
-        self.body.checkout_options = Some(checkout_options);
-
-        self
-    }
-
-    pub fn description(mut self, description: String) -> Self {
-        self.body.description = Some(description);
-
-        self
-    }
-
-    pub fn order(mut self, order: Order) -> Self {
-        self.body.order = Some(order);
-
-        self
-    }
-
-    pub fn payment_note(mut self, payment_note: String) -> Self {
-        self.body.payment_note = Some(payment_note);
-
-        self
-    }
-
-    pub fn pre_populated_data(mut self, pre_populated_data: PrePopulatedData) -> Self {
-        self.body.pre_populated_data = Some(pre_populated_data);
-
-        self
-    }
-
-    pub fn quick_pay(mut self, quick_pay: QuickPay) -> Self {
-        self.body.quick_pay = Some(quick_pay);
-
-        self
-    }
-
-    pub fn source(mut self, source: String) -> Self {
-        self.body.source = Some(source);
-
-        self
-    }
-}
-
-impl AddField<Order> for CreatePaymentLinkWrapper {
-    fn add_field(&mut self, field: Order) {
-        self.order = Some(field);
-    }
-}
-
-#[derive(Clone, Serialize, Debug)]
+#[derive(Clone, Serialize, Debug, SquareBuilder)]
+#[validate(with = "current_version")]
 pub struct UpdatePaymentLinkWrapper {
+    #[builder(flatten(version: i32))]
     payment_link: PaymentLink,
 }
 
@@ -360,14 +280,9 @@ impl Default for UpdatePaymentLinkWrapper {
     }
 }
 
-impl Validate for UpdatePaymentLinkWrapper {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.payment_link.version > 1 {
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-
+fn current_version(body: &UpdatePaymentLinkWrapper, errors: &mut ValidationError) {
+    if body.payment_link.version <= 1 {
+        errors.invalid("payment_link.version", "must be the current version of the payment link");
     }
 }
 
@@ -377,12 +292,6 @@ impl<T: ParentBuilder> Builder<UpdatePaymentLinkWrapper, T> {
 
         self
     }
-
-    pub fn version(mut self, version: i32) -> Self {
-        self.body.payment_link.version = version;
-
-        self
-    }
 }
 
 #[cfg(test)]
@@ -391,13 +300,13 @@ mod test_checkout {
     use crate::objects::{enums::{OrderLineItemItemType, Currency}, Money, OrderLineItem};
     use super::*;
 
-    #[tokio::test]
-    async fn test_create_order_request_builder() {
+    #[test]
+    fn test_create_order_request_builder() {
         let expected = CreateOrderRequestWrapper {
             idempotency_key: None,
             order: CreateOrderRequest { idempotency_key: "".to_string(), order: Order {
                 id: None,
-                location_id: Some("L1JC53TYHS40Z".to_string()),
+                location_id: Some("L1JC53TYHS40Z".into()),
                 close_at: None,
                 created_at: None,
                 customer_id: None,
@@ -412,7 +321,7 @@ mod test_checkout {
                             amount: Some(5),
                             currency: Currency::USD
                         }),
-                        catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".to_string()),
+                        catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".into()),
                         catalog_version: Some(1655427266071),
                         gross_sales_money: None,
                         item_type: Some(OrderLineItemItemType::Item),
@@ -438,7 +347,7 @@ mod test_checkout {
                           amount: Some(5),
                           currency: Currency::USD
                         }),
-                        catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".to_string()),
+                        catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".into()),
                         catalog_version: Some(1655427266071),
                         gross_sales_money: None,
                         item_type: Some(OrderLineItemItemType::Item),
@@ -498,7 +407,7 @@ mod test_checkout {
                     amount: Some(5),
                     currency: Currency::USD
                 }),
-                catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".to_string()),
+                catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".into()),
                 catalog_version: Some(1655427266071),
                 gross_sales_money: None,
                 item_type: Some(OrderLineItemItemType::Item),
@@ -524,7 +433,7 @@ mod test_checkout {
                     amount: Some(5),
                     currency: Currency::USD
                 }),
-                catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".to_string()),
+                catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".into()),
                 catalog_version: Some(1655427266071),
                 gross_sales_money: None,
                 item_type: Some(OrderLineItemItemType::Item),
@@ -545,8 +454,7 @@ mod test_checkout {
             .location_id("L1JC53TYHS40Z".to_string())
             .into_parent_builder()
             .unwrap()
-            .build()
-            .await
+            .build_sync()
             .unwrap();
         actual.idempotency_key = None;
         actual.order.idempotency_key = "".to_string();
@@ -567,7 +475,7 @@ mod test_checkout {
             idempotency_key: Some(Uuid::new_v4().to_string()),
             order: CreateOrderRequest { idempotency_key: Uuid::new_v4().to_string(), order: Order {
                 id: None,
-                location_id: Some("L1JC53TYHS40Z".to_string()),
+                location_id: Some("L1JC53TYHS40Z".into()),
                 close_at: None,
                 created_at: None,
                 customer_id: None,
@@ -582,7 +490,7 @@ mod test_checkout {
                             amount: Some(5),
                             currency: Currency::USD
                         }),
-                        catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".to_string()),
+                        catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".into()),
                         catalog_version: Some(1655427266071),
                         gross_sales_money: None,
                         item_type: Some(OrderLineItemItemType::Item),
@@ -608,7 +516,7 @@ mod test_checkout {
                             amount: Some(5),
                             currency: Currency::USD
                         }),
-                        catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".to_string()),
+                        catalog_object_id: Some("BSOL4BB6RCMX6SH4KQIFWZDP".into()),
                         catalog_version: Some(1655427266071),
                         gross_sales_money: None,
                         item_type: Some(OrderLineItemItemType::Item),
@@ -665,8 +573,8 @@ mod test_checkout {
         assert!(res.is_ok());
     }
 
-    #[tokio::test]
-    async fn test_list_payment_search_query_builder() {
+    #[test]
+    fn test_list_payment_search_query_builder() {
         let expected = vec![
             ("cursor".to_string(), "dwasd".to_string()),
             ("limit".to_string(), "10".to_string()),
@@ -675,10 +583,9 @@ mod test_checkout {
         let actual = ListPaymentLinksSearchQueryBuilder::new()
             .limit(10)
             .cursor("dwasd".to_string())
-            .build()
-            .await;
+            .build_sync();
 
-        assert_eq!(expected, actual)
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap())
     }
 
     #[tokio::test]
@@ -690,7 +597,9 @@ mod test_checkout {
         let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token);
 
-        let input = vec![("limit".to_string(), "10".to_string())];
+        let input = ListPaymentLinksSearchQueryBuilder::new()
+            .limit(10)
+            .build_sync();
 
         let res = sut.checkout()
             .list(Some(input))
@@ -699,13 +608,13 @@ mod test_checkout {
         assert!(res.is_ok());
     }
 
-    #[tokio::test]
-    async fn test_create_payment_link_builder() {
+    #[test]
+    fn test_create_payment_link_builder() {
         let expected = CreatePaymentLinkWrapper {
             idempotency_key: "".to_string(),
             description: None,
             quick_pay: Some( QuickPay {
-                location_id: "L1JC53TYHS40Z".to_string(),
+                location_id: "L1JC53TYHS40Z".into(),
                 name: "Another Thing".to_string(),
                 price_money: Money { amount: Some(10), currency: Currency::USD }
             }),
@@ -718,12 +627,11 @@ mod test_checkout {
 
         let mut actual = Builder::from(CreatePaymentLinkWrapper::default())
             .quick_pay(QuickPay {
-                location_id: "L1JC53TYHS40Z".to_string(),
+                location_id: "L1JC53TYHS40Z".into(),
                 name: "Another Thing".to_string(),
                 price_money: Money { amount: Some(10), currency: Currency::USD }
             })
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         actual.idempotency_key = "".to_string();
@@ -745,7 +653,7 @@ mod test_checkout {
             idempotency_key: "".to_string(),
             description: None,
             quick_pay: Some( QuickPay {
-                location_id: "L1JC53TYHS40Z".to_string(),
+                location_id: "L1JC53TYHS40Z".into(),
                 name: "Another Thing".to_string(),
                 price_money: Money { amount: Some(10), currency: Currency::USD }
             }),
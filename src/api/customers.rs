@@ -3,20 +3,22 @@ Customers functionality of the [Square API](https://developer.squareup.com).
  */
 
 use crate::client::SquareClient;
-use crate::api::{Verb, SquareAPI};
-use crate::errors::{SquareError, ListParametersBuilderError, ValidationError};
+use crate::datetime::Timestamp;
+use crate::api::{query_pairs, Verb, SquareAPI, cards::ListCardsQueryBuilder};
+use crate::errors::{ListParametersBuilderError, SquareError, ValidationError};
 use crate::response::SquareResponse;
-use crate::objects::{Address, Customer, enums::CustomerCreationSource, SearchQueryAttribute,
-                     TimeRange, CustomerFilter, CustomerTextFilter, CreationSource};
+use crate::objects::{Address, Customer, SearchQueryAttribute, TimeRange, CustomerFilter,
+                     CustomerTextFilter, CreationSource};
+use crate::objects::enums::{CustomerCreationSource, CustomerSortField, SortOrder};
 
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
-use crate::builder::{Builder, ParentBuilder, Validate};
+use crate::builder::{Builder, ParentBuilder, SquareBuilder};
+use crate::objects::ids::CustomerId;
 
 impl SquareClient {
-    pub fn customers(&self) -> Customers {
+    pub fn customers(&self) -> Customers<'_> {
         Customers {
-            client: &self
+            client: self
         }
     }
 }
@@ -28,16 +30,31 @@ pub struct Customers<'a> {
 impl<'a> Customers<'a> {
     /// Lists customer profiles associated with a Square account.
     /// [Open in API Reference](https://developer.squareup.com/reference/square/customers/list-customers)
-    pub async fn list(self, list_parameters: Vec<(String, String)>)
+    pub async fn list(self, list_parameters: ListCustomersParams)
                       -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
             SquareAPI::Customers("".to_string()),
             None::<&Customer>,
-            Some(list_parameters),
+            query_pairs(Some(&list_parameters))?,
         ).await
     }
 
+    /// Lists the enabled [Card](crate::objects::Card)s on file of a customer.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/cards-api/list-cards)
+    ///
+    /// # Arguments
+    /// * `customer_id` - The id of the customer whose cards are listed.
+    pub async fn cards(self, customer_id: impl Into<CustomerId>)
+                       -> Result<SquareResponse, SquareError> {
+        let parameters = ListCardsQueryBuilder::new()
+            .customer_id(customer_id.into().to_string())
+            .exclude_disabled()
+            .build_sync();
+
+        self.client.cards().list(Some(parameters)).await
+    }
+
     /// Creates a new customer for a business.
     /// [Open in API Reference](https://developer.squareup.com/reference/square/customers/create-customer)
     pub async fn create(self, customer: Customer)
@@ -70,20 +87,27 @@ impl<'a> Customers<'a> {
             Verb::DELETE,
             SquareAPI::Customers(format!("/{}", customer_to_delete.customer_id.unwrap())),
             None::<&CustomerSearchQuery>,
-            customer_to_delete.version,
+            query_pairs(Some(&DeleteCustomerParams { version: customer_to_delete.version }))?,
         ).await
     }
 }
 
+// the query parameters of Customers::delete
+#[derive(Serialize)]
+struct DeleteCustomerParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<i64>,
+}
+
 // -------------------------------------------------------------------------------------------------
 // CustomerListParametersBuilder implementation
 // -------------------------------------------------------------------------------------------------
 #[derive(Default)]
 pub struct CustomerListParametersBuilder {
     cursor: Option<String>,
-    limit: Option<String>,
-    sort_field: Option<String>,
-    sort_order: Option<String>,
+    limit: Option<i32>,
+    sort_field: Option<CustomerSortField>,
+    sort_order: Option<SortOrder>,
 }
 
 impl CustomerListParametersBuilder {
@@ -99,183 +123,120 @@ impl CustomerListParametersBuilder {
 
     pub fn limit(mut self, limit: i32) -> Self {
         if limit < 1 || limit > 100 { return self }
-        let limit = limit.to_string();
         self.limit = Some(limit);
 
         self
     }
 
     pub fn sort_field_default(mut self) -> Self {
-        self.sort_field = Some("DEFAULT".to_string());
+        self.sort_field = Some(CustomerSortField::Default);
 
         self
     }
 
     pub fn sort_field_created_at(mut self) -> Self {
-        self.sort_field = Some("CREATED_AT".to_string());
+        self.sort_field = Some(CustomerSortField::CreatedAt);
 
         self
     }
 
     pub fn sort_order_desc(mut self) -> Self {
-        self.sort_order = Some("DESC".to_string());
+        self.sort_order = Some(SortOrder::Desc);
 
         self
     }
 
     pub fn sort_order_asc(mut self) -> Self {
-        self.sort_order = Some("ASC".to_string());
+        self.sort_order = Some(SortOrder::Asc);
 
         self
     }
 
-    pub async fn build(mut self) -> Result<Vec<(String, String)>, ListParametersBuilderError> {
-        let mut res = Vec::new();
-        if let Some(cursor) = self.cursor.take() {
-            res.push(("cursor".to_string(), cursor))
-        }
-        if let Some(limit) = self.limit.take() {
-            res.push(("limit".to_string(), limit))
-        }
-        if let Some(sort_field) = self.sort_field.take() {
-            res.push(("sort_field".to_string(), sort_field))
-        }
-        if let Some(sort_order) = self.sort_order.take() {
-            res.push(("sort_order".to_string(), sort_order))
-        }
+    pub fn build_sync(self) -> Result<ListCustomersParams, ListParametersBuilderError> {
+        let CustomerListParametersBuilder {
+            cursor,
+            limit,
+            sort_field,
+            sort_order,
+        } = self;
+
+        Ok(ListCustomersParams {
+            cursor,
+            limit,
+            sort_field,
+            sort_order,
+        })
+    }
 
-        Ok(res)
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> Result<ListCustomersParams, ListParametersBuilderError> {
+        self.build_sync()
     }
 }
 
+/// The query parameters of [Customers::list](Customers::list), built through the
+/// [CustomerListParametersBuilder](CustomerListParametersBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListCustomersParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_field: Option<CustomerSortField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_order: Option<SortOrder>,
+}
+
 // -------------------------------------------------------------------------------------------------
 // Customer builder implementation
 // -------------------------------------------------------------------------------------------------
-impl Validate for Customer {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.given_name.is_some() ||
-            self.family_name.is_some() ||
-            self.company_name.is_some() ||
-            self.email_address.is_some() ||
-            self.phone_number.is_some() {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
-
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
-}
-
 impl<T: ParentBuilder> Builder<Customer, T> {
-    pub fn given_name(mut self, given_name: String) -> Self {
-        self.body.given_name = Some(given_name);
-
-        self
-    }
-
-    pub fn family_name(mut self, family_name: String) -> Self {
-        self.body.family_name = Some(family_name);
-
-        self
-    }
-
-    pub fn nickname(mut self, nickname: String) -> Self {
-        self.body.nickname = Some(nickname);
-
-        self
-    }
-
-    pub fn email_address(mut self, email_address: String) -> Self {
-        self.body.email_address = Some(email_address);
-
-        self
-    }
-
     pub fn address_from_address(mut self, address: Address) -> Self {
         self.body.address = Some(address);
 
         self
     }
-
-    pub fn birthday(mut self, birthday: String) -> Self {
-        self.body.birthday = Some(birthday);
-
-        self
-    }
-
-    pub fn phone_number(mut self, phone_number: String) -> Self {
-        self.body.phone_number = Some(phone_number);
-
-        self
-    }
-
-    pub fn note(mut self, note: String) -> Self {
-        self.body.birthday = Some(note);
-
-        self
-    }
 }
 
 // -------------------------------------------------------------------------------------------------
 // CustomerDelete builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Debug, Default)]
+#[derive(Debug, Default, SquareBuilder)]
 pub struct CustomerDelete {
-    customer_id: Option<String>,
-    version: Option<Vec<(String, String)>>,
-}
-
-impl Validate for CustomerDelete {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.customer_id.is_some() {
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
-}
-
-impl<T: ParentBuilder> Builder<CustomerDelete, T> {
-    pub fn customer_id(mut self, customer_id: String) -> Self {
-        self.body.customer_id = Some(customer_id);
-
-        self
-    }
-
-    pub fn version(mut self, version: i64) -> Self {
-        self.body.version = Some(vec![("version".to_string(), version.to_string())]);
-
-        self
-    }
+    #[required]
+    customer_id: Option<CustomerId>,
+    /// The current version of the customer, to only delete it if it has not been changed since.
+    version: Option<i64>,
 }
 
 // -------------------------------------------------------------------------------------------------
 // CustomerSearchQuery builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
+#[validate(with = "filter_time_ranges")]
 pub struct CustomerSearchQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(skip)]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<SearchQueryAttribute>
 }
 
-impl Validate for CustomerSearchQuery {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        Ok(self)
+fn filter_time_ranges(body: &CustomerSearchQuery, errors: &mut ValidationError) {
+    if let Some(filter) = body.query.as_ref().and_then(|query| query.filter.as_ref()) {
+        if let Some(created_at) = &filter.created_at {
+            created_at.check("query.filter.created_at", errors);
+        }
+        if let Some(updated_at) = &filter.updated_at {
+            updated_at.check("query.filter.updated_at", errors);
+        }
     }
 }
 
 impl<T: ParentBuilder> Builder<CustomerSearchQuery, T> {
-    pub fn cursor(mut self, cursor: String) -> Self {
-        self.body.cursor = Some(cursor);
-
-        self
-    }
-
     pub fn limit(mut self, limit: i64) -> Self {
         if limit < 1 || limit > 100 { return self };
         self.body.limit = Some(limit);
@@ -283,10 +244,10 @@ impl<T: ParentBuilder> Builder<CustomerSearchQuery, T> {
         self
     }
 
-    pub fn created_at(mut self, start: String, end: String) -> Self {
+    pub fn created_at(mut self, start: impl Into<Timestamp>, end: impl Into<Timestamp>) -> Self {
         let time_range = TimeRange {
-            start_at: Some(start),
-            end_at: Some(end),
+            start_at: Some(start.into()),
+            end_at: Some(end.into()),
         };
         let filter = CustomerFilter {
             created_at:  Some(time_range.clone()),
@@ -315,10 +276,10 @@ impl<T: ParentBuilder> Builder<CustomerSearchQuery, T> {
         self
     }
 
-    pub fn updated_at(mut self, start: String, end: String) -> Self {
+    pub fn updated_at(mut self, start: impl Into<Timestamp>, end: impl Into<Timestamp>) -> Self {
         let time_range = TimeRange {
-            start_at: Some(start),
-            end_at: Some(end),
+            start_at: Some(start.into()),
+            end_at: Some(end.into()),
         };
         let filter = CustomerFilter {
             created_at:  None,
@@ -684,18 +645,18 @@ impl<T: ParentBuilder> Builder<CustomerSearchQuery, T> {
 mod test_customers {
     use super::*;
 
-    #[tokio::test]
-    async fn test_list_parameter_builder() {
+    #[test]
+    fn test_list_parameter_builder() {
         let sut = CustomerListParametersBuilder::new();
         let expected =  vec![
             ("limit".to_string(), "4".to_string()),
             ("sort_field".to_string(), "DEFAULT".to_string())
         ];
         let actual =
-            sut.limit(4).limit(101).sort_field_default().build().await;
+            sut.limit(4).limit(101).sort_field_default().build_sync();
 
         assert!(actual.is_ok());
-        assert_eq!(expected, actual.unwrap())
+        assert_eq!(Some(expected), query_pairs(Some(&actual.unwrap())).unwrap())
     }
 
     #[tokio::test]
@@ -707,8 +668,11 @@ mod test_customers {
         let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token);
 
-        let input = vec![("limit".to_string(), "23".to_string()),
-             ("sort_field".to_string(), "DEFAULT".to_string())];
+        let input = CustomerListParametersBuilder::new()
+            .limit(23)
+            .sort_field_default()
+            .build_sync()
+            .unwrap();
 
         let result = sut.customers().list(input).await;
 
@@ -717,7 +681,23 @@ mod test_customers {
     }
 
     #[tokio::test]
-    async fn test_customer_builder() {
+    async fn test_list_customer_cards() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let result = sut.customers()
+            .cards("EDH2RWZCFCRGZCZ99GMG8ZF59R".to_string())
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_customer_builder() {
         let expected = Customer {
             id: None,
             birthday: Some("1996-11-02".to_string()),
@@ -769,8 +749,7 @@ mod test_customers {
             .phone_number("123-456-7890".to_string())
             .phone_number("123-456-7890".to_string())
             .birthday("1996-11-02".to_string())
-            .build()
-            .await;
+            .build_sync();
 
         assert!(actual.is_ok());
 
@@ -779,6 +758,30 @@ mod test_customers {
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual.unwrap()))
     }
 
+    #[test]
+    fn test_customer_builder_violations() {
+        let error = Builder::from(Customer::default())
+            .note("no name yet")
+            .build_sync()
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "could not build Customer: one of company_name, email_address, family_name, \
+             given_name, phone_number required"
+        );
+
+        let customer = Builder::from(Customer::default())
+            .email_address("pierre@example.com")
+            .note("no name yet")
+            .build_sync()
+            .unwrap();
+
+        assert!(customer.idempotency_key.is_some());
+        assert_eq!(customer.note, Some("no name yet".to_string()));
+        assert_eq!(customer.birthday, None);
+    }
+
     // #[tokio::test]
     async fn test_create_customer() {
         use dotenv::dotenv;
@@ -791,7 +794,7 @@ mod test_customers {
         let input = Builder::from(Customer::default())
             .given_name("Boyd".to_string())
             .nickname("the coolest".to_string())
-            .build().await.unwrap();
+            .build_sync().unwrap();
 
         let result = sut.customers().create(input).await;
 
@@ -799,17 +802,16 @@ mod test_customers {
         println!("{:?}", result.unwrap())
     }
 
-    #[tokio::test]
-    async fn test_customer_delete_builder() {
+    #[test]
+    fn test_customer_delete_builder() {
         let expected = CustomerDelete {
-            customer_id: Some("dew212ewfd32123ca".to_string()),
+            customer_id: Some("dew212ewfd32123ca".into()),
             version: None
         };
 
         let actual = Builder::from(CustomerDelete::default())
             .customer_id("dew212ewfd32123ca".to_string())
-            .build()
-            .await;
+            .build_sync();
 
         assert!(actual.is_ok());
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual.unwrap()))
@@ -825,7 +827,7 @@ mod test_customers {
         let sut = SquareClient::new(&access_token);
 
         let input = CustomerDelete {
-            customer_id: Some("WPGEDT7V38Y318JVGZ1G1C39W4".to_string()),
+            customer_id: Some("WPGEDT7V38Y318JVGZ1G1C39W4".into()),
             version: None
         };
 
@@ -834,16 +836,16 @@ mod test_customers {
         assert!(res.is_ok());
     }
 
-    #[tokio::test]
-    async fn test_customer_search_query_builder() {
+    #[test]
+    fn test_customer_search_query_builder() {
         let expected = CustomerSearchQuery {
             cursor: None,
             limit: Some(5),
             query: Some(SearchQueryAttribute {
                 filter: Some(CustomerFilter {
                     created_at: Some(TimeRange {
-                        end_at: Some("2022-01-23T20:21:54.859Z".to_string()),
-                        start_at: Some("2018-01-23T20:21:54.859Z".to_string())
+                        end_at: Some("2022-01-23T20:21:54.859Z".into()),
+                        start_at: Some("2018-01-23T20:21:54.859Z".into())
                     }),
                     creation_source: Some(CreationSource {
                         rule: Some("EXCLUDE".to_string()),
@@ -883,7 +885,7 @@ mod test_customers {
             .creation_source_value(CustomerCreationSource::Coupon)
             .creation_source_value(CustomerCreationSource::Appointments)
             .set_creation_source_exclude()
-            .build().await;
+            .build_sync();
 
         assert!(actual.is_ok());
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual.unwrap()));
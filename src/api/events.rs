@@ -0,0 +1,192 @@
+/*!
+Events functionality of the [Square API](https://developer.squareup.com).
+
+The [Event](crate::objects::Event)s returned by this endpoint have the same shape as the
+notifications delivered to a webhook subscription, which makes it possible to backfill missed
+webhook notifications with the same handling code.
+ */
+
+use crate::api::{SquareAPI, Verb};
+use crate::builder::SquareBuilder;
+use crate::client::SquareClient;
+use crate::errors::SquareError;
+use crate::objects::{SearchEventsQuery, SearchEventsSort, TimeRange};
+use crate::objects::enums::{SearchEventsSortField, SortOrder};
+use crate::response::SquareResponse;
+use crate::objects::ids::LocationId;
+
+use serde::{Deserialize, Serialize};
+
+impl SquareClient {
+    /// Returns an [Events](Events) object through which you can make calls specifically to the
+    /// Events endpoint of the [Square API](https://developer.squareup.com).
+    /// # Example: Search for all payment.created events.
+    /// ```rust
+    /// use square_ox::{
+    ///         client::SquareClient,
+    ///         builder::Builder,
+    ///         api::events::SearchEventsBody,
+    ///     };
+    ///
+    /// async {
+    ///     let body = Builder::from(SearchEventsBody::default())
+    ///         .add_event_type("payment.created".to_string())
+    ///         .build_sync()
+    ///         .unwrap();
+    ///
+    ///     let events = SquareClient::new("some_token")
+    ///         .events()
+    ///         .search_events(body)
+    ///         .await;
+    ///     };
+    /// ```
+    pub fn events(&self) -> Events<'_> {
+        Events {
+            client: self,
+        }
+    }
+}
+
+/// Allows you to make calls to the [Square API](https://developer.squareup.com) at the Events
+/// endpoint with all currently implemented methods.
+pub struct Events<'a> {
+    client: &'a SquareClient,
+}
+
+impl<'a> Events<'a> {
+    /// Search for [Event](crate::objects::Event)s within the past 28 days.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/events/search-events)
+    ///
+    /// # Arguments
+    /// * `body` - A [SearchEventsBody](SearchEventsBody).
+    pub async fn search_events(self, body: SearchEventsBody)
+                               -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Events("".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// SearchEventsBody builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
+pub struct SearchEventsBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    /// The maximum number of events to return in a single page (100 max).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(flatten(
+        #[builder(flatten(
+            /// The event types, e.g. `payment.created`, to filter the events by.
+            event_types: Option<Vec<String>>,
+            merchant_ids: Option<Vec<String>>,
+            location_ids: Option<Vec<LocationId>>,
+            /// Only return events created within the given [TimeRange](TimeRange).
+            created_at: Option<TimeRange>,
+        ))]
+        filter: Option<SearchEventsFilter>,
+        #[builder(skip, preset(
+            sort_ascending = "SearchEventsSort {
+                field: Some(SearchEventsSortField::Default),
+                order: Some(SortOrder::Asc),
+            }",
+            sort_descending = "SearchEventsSort {
+                field: Some(SearchEventsSortField::Default),
+                order: Some(SortOrder::Desc),
+            }",
+        ))]
+        sort: Option<SearchEventsSort>,
+    ))]
+    query: Option<SearchEventsQuery>,
+}
+
+#[cfg(test)]
+mod test_events {
+    use super::*;
+    use crate::builder::Builder;
+    use crate::objects::{Event, EventObject, Response, SearchEventsFilter};
+
+    #[test]
+    fn test_search_events_body_builder() {
+        let expected = SearchEventsBody {
+            cursor: None,
+            limit: Some(10),
+            query: Some(SearchEventsQuery {
+                filter: Some(SearchEventsFilter {
+                    created_at: None,
+                    event_types: Some(vec!["payment.created".to_string()]),
+                    location_ids: Some(vec!["L1JC53TYHS40Z".into()]),
+                    merchant_ids: None
+                }),
+                sort: Some(SearchEventsSort {
+                    field: Some(SearchEventsSortField::Default),
+                    order: Some(SortOrder::Desc)
+                })
+            })
+        };
+
+        let actual = Builder::from(SearchEventsBody::default())
+            .limit(10)
+            .add_event_type("payment.created".to_string())
+            .add_location_id("L1JC53TYHS40Z".to_string())
+            .sort_descending()
+            .build_sync()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
+    }
+
+    #[test]
+    fn test_deserialize_webhook_event() {
+        let payload = r#"{
+            "merchant_id": "6SSW7HV8K2ST5",
+            "type": "payment.created",
+            "event_id": "13b867cf-db3d-4b1c-90b6-2f32a9d78124",
+            "created_at": "2020-02-06T21:27:30.792Z",
+            "data": {
+                "type": "payment",
+                "id": "KkAkhdMsgzn59SM8A89WgKwekxLZY",
+                "object": {
+                    "payment": {
+                        "id": "KkAkhdMsgzn59SM8A89WgKwekxLZY",
+                        "status": "APPROVED"
+                    }
+                }
+            }
+        }"#;
+
+        let event: Event = serde_json::from_str(payload).unwrap();
+
+        assert!(matches!(
+            event.data.unwrap().object,
+            Some(EventObject::Typed(Response::Payment(_)))
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_search_events() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let input = Builder::from(SearchEventsBody::default())
+            .limit(10)
+            .build_sync()
+            .unwrap();
+
+        let res = sut.events()
+            .search_events(input)
+            .await;
+
+        assert!(res.is_ok())
+    }
+}
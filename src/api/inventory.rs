@@ -3,16 +3,20 @@ Inventory functionality of the [Square API](https://developer.squareup.com).
  */
 
 use crate::client::SquareClient;
-use crate::api::{Verb, SquareAPI};
-use crate::errors::{InventoryChangeBodyBuildError, SquareError, ValidationError};
-use crate::response::SquareResponse;
-use crate::objects::{CatalogObject, InventoryChange, InventoryPhysicalCount,
+use crate::datetime::{now, Timestamp};
+use crate::api::{query_pairs, request_chunked, Verb, SquareAPI};
+use crate::errors::{FieldPath, SquareError};
+use crate::response::{ChunkedResponse, SquareResponse};
+use crate::objects::{CatalogObject, InventoryAdjustment, InventoryChange, InventoryPhysicalCount,
                      InventoryTransfer};
 use crate::objects::enums::{InventoryChangeType, InventoryState};
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use crate::builder::{AddField, Builder, ParentBuilder, Validate};
+use crate::builder::{AddField, Builder, ParentBuilder, SquareBuilder};
+use crate::objects::ids::{
+    CatalogObjectId, InventoryAdjustmentId, InventoryPhysicalCountId, InventoryTransferId, LocationId
+};
 
 
 impl SquareClient {
@@ -31,14 +35,14 @@ impl SquareClient {
     ///         .inventory()
     ///         .retrieve_count(
     ///             "some_obj_id".to_string(),
-    ///             Some("some_loc_id".to_string())
+    ///             Some("some_loc_id".into())
     ///         )
     ///         .await;
     ///     };
     /// ```
-    pub fn inventory(&self) -> Inventory {
+    pub fn inventory(&self) -> Inventory<'_> {
         Inventory {
-            client: &self,
+            client: self,
         }
     }
 }
@@ -63,31 +67,90 @@ impl<'a> Inventory<'a> {
         ).await
     }
 
+    /// Chunked variant of [batch_change](Inventory::batch_change) that splits the changes into
+    /// chunks of 100, sends at most `concurrency` requests at once and merges the resulting counts
+    /// and changes into one [ChunkedResponse](ChunkedResponse). Every chunk is sent with its own
+    /// idempotency key derived from the key of the body, the n-th chunk, counting from 0, is sent
+    /// with the key `<key>-<n>`, so retrying the whole call with the same body does not apply the
+    /// changes twice. The same holds for a key overridden through
+    /// [RequestOptions](crate::client::RequestOptions).
+    ///
+    /// # Arguments
+    /// * `body` - An [InventoryChangeBody](InventoryChangeBody) holding any number of changes.
+    /// * `concurrency` - The maximum number of chunks that are requested at the same time.
+    pub async fn batch_change_chunked(self, body: InventoryChangeBody, concurrency: usize)
+                                      -> ChunkedResponse<InventoryChange> {
+        let client = self.client;
+        let InventoryChangeBody { idempotency_key, changes, ignore_unchanged_counts } = body;
+
+        request_chunked(changes, MAX_CHANGES, concurrency, |index, changes, _| {
+            let body = InventoryChangeBody::chunk(
+                idempotency_key.as_deref(),
+                ignore_unchanged_counts,
+                index,
+                changes,
+            );
+            let client = client.for_chunk(index);
+
+            async move { Inventory { client: &client }.batch_change(body).await }
+        }).await
+    }
+
+    /// Returns historical physical counts and adjustments based on the provided filter criteria.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/inventory/batch-retrieve-inventory-changes)
+    ///
+    /// # Arguments
+    /// * `body` - A [BatchRetrieveChanges](BatchRetrieveChanges) holding the filters.
+    pub async fn batch_retrieve_changes(self, body: BatchRetrieveChanges)
+                                        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Inventory("/changes/batch-retrieve".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Returns a set of physical counts and inventory adjustments for the provided
+    /// [CatalogObject](crate::objects::CatalogObject) at all of the requested
+    /// [Location](crate::objects::Location)s.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/inventory/retrieve-inventory-changes)
+    ///
+    /// # Arguments
+    /// * `object_id` - The ID of the catalog object to retrieve the changes history for.
+    pub async fn retrieve_changes(self, object_id: impl Into<CatalogObjectId>)
+                                  -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::Inventory(format!("/{}/changes", object_id.into())),
+            None::<&CatalogObject>,
+            None,
+        ).await
+    }
+
     /// Retrieves the current calculated stock count for a given [CatalogObject](crate::objects::CatalogObject) at
     /// a given set of [Location](crate::objects::Location)s.
     /// [Open in API Reference](https://developer.squareup.com/reference/square/inventory/retrieve-inventory-count)
-    pub async fn retrieve_count(self, object_id: String, location_id: Option<String>)
+    pub async fn retrieve_count(self, object_id: impl Into<CatalogObjectId>,
+                                location_id: impl Into<Option<LocationId>>)
         -> Result<SquareResponse, SquareError>{
-        let parameters = match location_id {
-            Some(location_id) => Some(vec![("location_id".to_string(), location_id)]),
-            None => None
-        };
+        let parameters = RetrieveCountParams { location_ids: location_id.into() };
 
         self.client.request(
             Verb::GET,
-            SquareAPI::Inventory(format!("/{}", object_id)),
+            SquareAPI::Inventory(format!("/{}", object_id.into())),
             None::<&CatalogObject>,
-            parameters,
+            query_pairs(Some(&parameters))?,
         ).await
     }
 
     /// Returns the [InventoryAdjustment](InventoryAdjustment) object with the provided adjustment_id.
     /// [Open in API Reference](https://developer.squareup.com/reference/square/inventory/retrieve-inventory-adjustment)
-    pub async fn retrieve_adjustment(self, adjustment_id: String)
+    pub async fn retrieve_adjustment(self, adjustment_id: impl Into<InventoryAdjustmentId>)
                                 -> Result<SquareResponse, SquareError>{
         self.client.request(
             Verb::GET,
-            SquareAPI::Inventory(format!("/adjustments/{}", adjustment_id)),
+            SquareAPI::Inventory(format!("/adjustments/{}", adjustment_id.into())),
             None::<&CatalogObject>,
             None,
         ).await
@@ -95,11 +158,11 @@ impl<'a> Inventory<'a> {
 
     /// Returns the [InventoryTransfer](InventoryTransfer) object with the provided `transfer_id`.
     /// [Open in API Reference](https://developer.squareup.com/reference/square/inventory/retrieve-inventory-transfer)
-    pub async fn retrieve_transfer(self, transfer_id: String)
+    pub async fn retrieve_transfer(self, transfer_id: impl Into<InventoryTransferId>)
                                 -> Result<SquareResponse, SquareError>{
         self.client.request(
             Verb::GET,
-            SquareAPI::Inventory(format!("/transfer/{}", transfer_id)),
+            SquareAPI::Inventory(format!("/transfer/{}", transfer_id.into())),
             None::<&CatalogObject>,
             None,
         ).await
@@ -107,11 +170,14 @@ impl<'a> Inventory<'a> {
 
     /// Returns the [InventoryPhysicalCount](InventoryPhysicalCount) object with the provided `physical_count_id`.
     /// [Open in API Reference](https://developer.squareup.com/reference/square/inventory/retrieve-inventory-physical-count)
-    pub async fn retrieve_physical_count(self, physical_count_id: String)
-                                -> Result<SquareResponse, SquareError>{
+    pub async fn retrieve_physical_count(
+        self,
+        physical_count_id: impl Into<InventoryPhysicalCountId>
+    )
+        -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
-            SquareAPI::Inventory(format!("/physical-counts/{}", physical_count_id)),
+            SquareAPI::Inventory(format!("/physical-counts/{}", physical_count_id.into())),
             None::<&CatalogObject>,
             None,
         ).await
@@ -129,33 +195,179 @@ impl<'a> Inventory<'a> {
             None,
         ).await
     }
+
+    /// Chunked variant of [batch_retrieve_counts](Inventory::batch_retrieve_counts) that splits
+    /// the catalog object ids into chunks of 1,000, sends at most `concurrency` requests at once
+    /// and merges the resulting counts into one [ChunkedResponse](ChunkedResponse). Every page of
+    /// a chunk is requested by following its cursor, so the cursor of the `body` is ignored.
+    ///
+    /// # Arguments
+    /// * `body` - A [BatchRetrieveCounts](BatchRetrieveCounts) holding any number of object ids.
+    /// * `concurrency` - The maximum number of chunks that are requested at the same time.
+    pub async fn batch_retrieve_counts_chunked(self, body: BatchRetrieveCounts, concurrency: usize)
+                                               -> ChunkedResponse<CatalogObjectId> {
+        let client = self.client;
+
+        let ids = body.catalog_object_ids.clone();
+
+        request_chunked(ids, MAX_COUNT_IDS, concurrency, |_, catalog_object_ids, cursor| {
+            let body = BatchRetrieveCounts { catalog_object_ids, cursor, ..body.clone() };
+
+            async move { Inventory { client }.batch_retrieve_counts(body).await }
+        }).await
+    }
 }
 
+// the query parameters of Inventory::retrieve_count
+#[derive(Serialize)]
+struct RetrieveCountParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_ids: Option<LocationId>,
+}
+
+// the maximum number of changes and object ids the Square API accepts in a single batch request
+const MAX_CHANGES: usize = 100;
+const MAX_COUNT_IDS: usize = 1000;
+
 // -------------------------------------------------------------------------------------------------
 // InventoryChangeBody builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
 pub struct InventoryChangeBody {
+    #[idempotency_key]
     idempotency_key: Option<String>,
+    #[required]
+    #[builder(each = "change")]
     changes: Vec<InventoryChange>,
     ignore_unchanged_counts: Option<bool>,
 }
 
-impl Validate for InventoryChangeBody {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.changes.len() > 0 {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
+impl InventoryChangeBody {
+    // the body of the chunk with the given index of a chunked batch change, whose idempotency key
+    // is derived from the key of the whole batch, a key is only generated if the batch has none
+    fn chunk(
+        idempotency_key: Option<&str>,
+        ignore_unchanged_counts: Option<bool>,
+        index: usize,
+        changes: Vec<InventoryChange>,
+    ) -> Self {
+        let idempotency_key = match idempotency_key {
+            Some(idempotency_key) => format!("{}-{}", idempotency_key, index),
+            None => Uuid::new_v4().to_string(),
+        };
 
-            Ok(self)
-        } else {
-            Err(ValidationError)
+        InventoryChangeBody {
+            idempotency_key: Some(idempotency_key),
+            changes,
+            ignore_unchanged_counts,
         }
     }
 }
 
 impl<T: ParentBuilder> Builder<InventoryChangeBody, T> {
-    pub fn change(mut self, change: InventoryChange) -> Self {
-        self.body.changes.push(change);
+    /// Add a [InventoryTransfer](InventoryTransfer) of `quantity` units of an item variation that
+    /// are in stock from one [Location](crate::objects::Location) to another, occurring now.
+    ///
+    /// # Arguments
+    /// * `catalog_object_id` - The ID of the item variation being transferred.
+    /// * `from_location_id` - The ID of the location the stock is moved away from.
+    /// * `to_location_id` - The ID of the location the stock is moved to.
+    /// * `quantity` - The number of units, as a decimal with up to 5 digits after the decimal point.
+    pub fn transfer(
+        mut self,
+        catalog_object_id: impl Into<CatalogObjectId>,
+        from_location_id: impl Into<LocationId>,
+        to_location_id: impl Into<LocationId>,
+        quantity: String
+    ) -> Self {
+        self.body.changes.push(InventoryChange {
+            transfer: Some(InventoryTransfer {
+                id: None,
+                catalog_object_id: catalog_object_id.into(),
+                catalog_object_type: None,
+                created_at: None,
+                employee_id: None,
+                from_location_id: from_location_id.into(),
+                occurred_at: now(),
+                quantity,
+                reference_id: None,
+                source: None,
+                state: InventoryState::InStock,
+                team_member_id: None,
+                to_location_id: to_location_id.into(),
+            }),
+            inventory_change_type: InventoryChangeType::Transfer,
+            ..Default::default()
+        });
+
+        self
+    }
+
+    /// Add a [InventoryAdjustment](InventoryAdjustment) moving `quantity` units of an item
+    /// variation at a [Location](crate::objects::Location) from one
+    /// [InventoryState](InventoryState) to another, occurring now.
+    ///
+    /// # Arguments
+    /// * `catalog_object_id` - The ID of the item variation being adjusted.
+    /// * `location_id` - The ID of the location the adjustment applies to.
+    /// * `from_state` - The state the units are in before the adjustment.
+    /// * `to_state` - The state the units are in after the adjustment.
+    /// * `quantity` - The number of units, as a decimal with up to 5 digits after the decimal point.
+    pub fn adjust(
+        mut self,
+        catalog_object_id: impl Into<CatalogObjectId>,
+        location_id: impl Into<LocationId>,
+        from_state: InventoryState,
+        to_state: InventoryState,
+        quantity: String
+    ) -> Self {
+        self.body.changes.push(InventoryChange {
+            adjustment: Some(InventoryAdjustment {
+                catalog_object_id: catalog_object_id.into(),
+                location_id: location_id.into(),
+                from_state: Some(from_state),
+                to_state: Some(to_state),
+                quantity: Some(quantity),
+                occurred_at: Some(now()),
+                ..Default::default()
+            }),
+            inventory_change_type: InventoryChangeType::Adjustment,
+            ..Default::default()
+        });
+
+        self
+    }
+
+    /// Add a [InventoryPhysicalCount](InventoryPhysicalCount) that sets the in stock quantity of
+    /// an item variation at a [Location](crate::objects::Location), occurring now.
+    ///
+    /// # Arguments
+    /// * `catalog_object_id` - The ID of the item variation being counted.
+    /// * `location_id` - The ID of the location the count was taken at.
+    /// * `quantity` - The number of units, as a decimal with up to 5 digits after the decimal point.
+    pub fn physical_count(
+        mut self,
+        catalog_object_id: impl Into<CatalogObjectId>,
+        location_id: impl Into<LocationId>,
+        quantity: String
+    ) -> Self {
+        self.body.changes.push(InventoryChange {
+            physical_count: Some(InventoryPhysicalCount {
+                id: None,
+                catalog_object_id: catalog_object_id.into(),
+                catalog_object_type: None,
+                created_at: None,
+                location_id: location_id.into(),
+                occurred_at: now(),
+                quantity,
+                reference_id: None,
+                source: None,
+                state: InventoryState::InStock,
+                team_member_id: None
+            }),
+            inventory_change_type: InventoryChangeType::PhysicalCount,
+            ..Default::default()
+        });
 
         self
     }
@@ -165,58 +377,78 @@ impl AddField<InventoryChange> for InventoryChangeBody {
     fn add_field(&mut self, field: InventoryChange) {
         self.changes.push(field);
     }
+
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("changes").index(self.changes.len())
+    }
 }
 
 
 // -------------------------------------------------------------------------------------------------
 // BatchRetrieveCounts builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
 pub struct BatchRetrieveCounts {
-    catalog_object_ids: Vec<String>,
+    #[required]
+    #[builder(setter = "object_ids", each = "add_object_id")]
+    catalog_object_ids: Vec<CatalogObjectId>,
     cursor: Option<String>,
     limit: Option<i32>,
-    location_ids: Vec<String>,
+    #[required]
+    location_ids: Vec<LocationId>,
     states: Option<Vec<InventoryState>>,
-    updated_after: Option<String>,
-}
-
-impl Validate for BatchRetrieveCounts {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.location_ids.len() > 0 && self.catalog_object_ids.len() > 0 {
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
+    updated_after: Option<Timestamp>,
 }
 
-impl<T: ParentBuilder> Builder<BatchRetrieveCounts, T> {
-    pub fn object_ids(mut self, ids: Vec<String>) -> Self {
-        self.body.catalog_object_ids = ids;
-
-        self
-    }
-
-    pub fn location_ids(mut self, ids: Vec<String>) -> Self {
-        self.body.location_ids = ids;
-
-        self
-    }
-
-    pub fn add_location_id(mut self, id: String) -> Self {
-        self.body.location_ids.push(id);
-
-        self
-    }
+// -------------------------------------------------------------------------------------------------
+// BatchRetrieveChanges builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
+pub struct BatchRetrieveChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter = "object_ids", each = "add_object_id")]
+    catalog_object_ids: Option<Vec<CatalogObjectId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    /// The number of records to return (100 max).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_ids: Option<Vec<LocationId>>,
+    /// Only return adjustments with a `to_state` or physical counts with a `state` in the given
+    /// [InventoryState](InventoryState)s.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    states: Option<Vec<InventoryState>>,
+    /// Only return changes of the given [InventoryChangeType](InventoryChangeType)s.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    types: Option<Vec<InventoryChangeType>>,
+    /// Only return changes updated after the given RFC 3339 timestamp (inclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated_after: Option<Timestamp>,
+    /// Only return changes updated before the given RFC 3339 timestamp (exclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated_before: Option<Timestamp>,
 }
 
 #[cfg(test)]
 mod test_inventory {
     use crate::builder::BackIntoBuilder;
-    use crate::objects::enums::InventoryState;
     use super::*;
 
+    #[test]
+    fn test_inventory_change_body_chunk() {
+        let first = InventoryChangeBody::chunk(Some("batch-key"), Some(true), 0, vec![]);
+        let second = InventoryChangeBody::chunk(Some("batch-key"), Some(true), 1, vec![]);
+
+        assert_eq!(first.idempotency_key, Some("batch-key-0".to_string()));
+        assert_eq!(second.idempotency_key, Some("batch-key-1".to_string()));
+        assert_eq!(second.ignore_unchanged_counts, Some(true));
+
+        let generated = InventoryChangeBody::chunk(None, None, 0, vec![]);
+        assert!(generated.idempotency_key.is_some());
+        assert_ne!(generated.idempotency_key, InventoryChangeBody::chunk(None, None, 0, vec![]).idempotency_key);
+    }
+
     #[tokio::test]
     async fn test_retrieve_count() {
         use dotenv::dotenv;
@@ -232,14 +464,14 @@ mod test_inventory {
         );
 
         let res = sut.inventory()
-            .retrieve_count(input.0, Some(input.1))
+            .retrieve_count(input.0, Some(input.1.into()))
             .await;
 
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_change_body_builder() {
+    #[test]
+    fn test_change_body_builder() {
         let expected = InventoryChangeBody {
             idempotency_key: None,
             changes: vec![
@@ -249,11 +481,11 @@ mod test_inventory {
                     measurement_unit_id: None,
                     physical_count: Some(InventoryPhysicalCount {
                         id: None,
-                        catalog_object_id: "".to_string(),
+                        catalog_object_id: "".into(),
                         catalog_object_type: None,
                         created_at: None,
-                        location_id: "L1JC53TYHS40Z".to_string(),
-                        occurred_at: "2022-07-09T12:25:34Z".to_string(),
+                        location_id: "L1JC53TYHS40Z".into(),
+                        occurred_at: "2022-07-09T12:25:34Z".into(),
                         quantity: "30".to_string(),
                         reference_id: None,
                         source: None,
@@ -272,11 +504,11 @@ mod test_inventory {
             .change_type(InventoryChangeType::PhysicalCount)
             .physical_count(InventoryPhysicalCount {
                 id: None,
-                catalog_object_id: "".to_string(),
+                catalog_object_id: "".into(),
                 catalog_object_type: None,
                 created_at: None,
-                location_id: "L1JC53TYHS40Z".to_string(),
-                occurred_at: "2022-07-09T12:25:34Z".to_string(),
+                location_id: "L1JC53TYHS40Z".into(),
+                occurred_at: "2022-07-09T12:25:34Z".into(),
                 quantity: "30".to_string(),
                 reference_id: None,
                 source: None,
@@ -285,8 +517,7 @@ mod test_inventory {
             })
             .into_parent_builder()
             .unwrap()
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert!(actual.idempotency_key.is_some());
@@ -296,6 +527,131 @@ mod test_inventory {
         assert_eq!(format!("{:?}",expected), format!("{:?}",actual));
     }
 
+    #[test]
+    fn test_adjustment_with_vendor_builder() {
+        let actual = Builder::from(InventoryChangeBody::default())
+            .sub_builder_from(InventoryChange::default())
+            .sub_builder_from(InventoryAdjustment::default())
+            .catalog_object_id("DUHTRFG3OEYAXG5I22XLFN23".to_string())
+            .location_id("L1JC53TYHS40Z".to_string())
+            .quantity("12".to_string())
+            .from_state(InventoryState::None)
+            .to_state(InventoryState::InStock)
+            .vendor_id("INV_V_JDKYHBWT1D4F8MFH63DBMEN8Y4".to_string())
+            .into_parent_builder()
+            .unwrap()
+            .into_parent_builder()
+            .unwrap()
+            .build_sync()
+            .unwrap();
+
+        let adjustment = actual.changes[0].adjustment.as_ref().unwrap();
+
+        assert_eq!(
+            adjustment.vendor_id,
+            Some("INV_V_JDKYHBWT1D4F8MFH63DBMEN8Y4".to_string())
+        );
+        assert!(matches!(
+            actual.changes[0].inventory_change_type,
+            InventoryChangeType::Adjustment
+        ));
+    }
+
+    #[test]
+    fn test_adjustment_with_vendor_builder_fail() {
+        let res = Builder::from(InventoryAdjustment::default())
+            .catalog_object_id("DUHTRFG3OEYAXG5I22XLFN23".to_string())
+            .location_id("L1JC53TYHS40Z".to_string())
+            .quantity("12".to_string())
+            .from_state(InventoryState::InStock)
+            .to_state(InventoryState::Sold)
+            .vendor_id("INV_V_JDKYHBWT1D4F8MFH63DBMEN8Y4".to_string())
+            .build_sync();
+
+        assert!(res.is_err())
+    }
+
+    #[test]
+    fn test_change_body_builder_helpers() {
+        let actual = Builder::from(InventoryChangeBody::default())
+            .transfer(
+                "DUHTRFG3OEYAXG5I22XLFN23".to_string(),
+                "L1JC53TYHS40Z".to_string(),
+                "LBQ9DAD7NN0H2".to_string(),
+                "5".to_string(),
+            )
+            .adjust(
+                "DUHTRFG3OEYAXG5I22XLFN23".to_string(),
+                "L1JC53TYHS40Z".to_string(),
+                InventoryState::InStock,
+                InventoryState::Waste,
+                "1".to_string(),
+            )
+            .physical_count(
+                "DUHTRFG3OEYAXG5I22XLFN23".to_string(),
+                "L1JC53TYHS40Z".to_string(),
+                "30".to_string(),
+            )
+            .build_sync()
+            .unwrap();
+
+        assert!(matches!(actual.changes[0].inventory_change_type, InventoryChangeType::Transfer));
+        assert_eq!(actual.changes[0].transfer.as_ref().unwrap().to_location_id, "LBQ9DAD7NN0H2");
+        assert!(matches!(actual.changes[1].inventory_change_type, InventoryChangeType::Adjustment));
+        assert!(matches!(
+            actual.changes[1].adjustment.as_ref().unwrap().to_state,
+            Some(InventoryState::Waste)
+        ));
+        assert!(matches!(
+            actual.changes[2].inventory_change_type,
+            InventoryChangeType::PhysicalCount
+        ));
+        assert_eq!(actual.changes[2].physical_count.as_ref().unwrap().quantity, "30");
+    }
+
+    #[test]
+    fn test_now() {
+        let now = now();
+        let now = now.as_str();
+
+        assert_eq!(now.len(), 20);
+        assert_eq!(&now[4..5], "-");
+        assert_eq!(&now[10..11], "T");
+        assert!(now.ends_with('Z'));
+    }
+
+    #[test]
+    fn test_batch_retrieve_changes_body_builder() {
+        let expected = r#"{"catalog_object_ids":["DUHTRFG3OEYAXG5I22XLFN23"],"location_ids":["L1JC53TYHS40Z"],"states":["IN_STOCK"],"types":["ADJUSTMENT"],"updated_after":"2022-07-09T12:25:34Z"}"#;
+
+        let actual = Builder::from(BatchRetrieveChanges::default())
+            .add_object_id("DUHTRFG3OEYAXG5I22XLFN23".to_string())
+            .add_location_id("L1JC53TYHS40Z".to_string())
+            .add_type(InventoryChangeType::Adjustment)
+            .add_state(InventoryState::InStock)
+            .updated_after("2022-07-09T12:25:34Z".to_string())
+            .build_sync()
+            .unwrap();
+
+        assert_eq!(expected, serde_json::to_string(&actual).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_changes() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let res = sut.inventory()
+            .retrieve_changes("DUHTRFG3OEYAXG5I22XLFN23".to_string())
+            .await;
+
+        assert!(res.is_ok())
+    }
+
     // #[tokio::test]
     async fn test_batch_change() {
         use dotenv::dotenv;
@@ -314,11 +670,11 @@ mod test_inventory {
                     measurement_unit_id: None,
                     physical_count: Some(InventoryPhysicalCount {
                         id: None,
-                        catalog_object_id: "DUHTRFG3OEYAXG5I22XLFN23".to_string(),
+                        catalog_object_id: "DUHTRFG3OEYAXG5I22XLFN23".into(),
                         catalog_object_type: None,
                         created_at: None,
-                        location_id: "L1JC53TYHS40Z".to_string(),
-                        occurred_at: "2022-07-09T12:25:34Z".to_string(),
+                        location_id: "L1JC53TYHS40Z".into(),
+                        occurred_at: "2022-07-09T12:25:34Z".into(),
                         quantity: "30".to_string(),
                         reference_id: None,
                         source: None,
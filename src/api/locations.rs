@@ -4,22 +4,23 @@ Customers functionality of the [Square API](https://developer.squareup.com).
 
 use crate::client::SquareClient;
 use crate::api::{Verb, SquareAPI};
-use crate::errors::{SquareError, LocationBuildError, ValidationError};
+use crate::errors::{SquareError, ValidationError};
 use crate::response::SquareResponse;
 use crate::objects::{
     Address, BusinessHours, BusinessHoursPeriod, Coordinates, Location, TaxIds,
     enums::{
-        Currency, LocationStatus, LocationType
+        Currency, LocationCapability, LocationStatus, LocationType, MerchantCategoryCode
     }
 };
 
 use serde::{Deserialize, Serialize};
-use crate::builder::{Builder, ParentBuilder, Validate};
+use crate::builder::{Builder, ParentBuilder, SquareBuilder};
+use crate::objects::ids::LocationId;
 
 impl SquareClient {
-    pub fn locations(&self) -> Locations {
+    pub fn locations(&self) -> Locations<'_> {
         Locations {
-            client: &self,
+            client: self,
         }
     }
 }
@@ -70,8 +71,7 @@ impl<'a> Locations<'a> {
     ///  async {
     ///     let location = Builder::from(LocationCreationWrapper::default())
     ///         .name("The Foo Bar".to_string())
-    ///         .build()
-    ///         .await
+    ///         .build_sync()
     ///         .unwrap();
     ///     let res = SquareClient::new("some_token")
     ///         .locations()
@@ -105,8 +105,7 @@ impl<'a> Locations<'a> {
     ///  async {
     ///     let location = Builder::from(LocationCreationWrapper::default())
     ///         .name("The New Foo Bar".to_string())
-    ///         .build()
-    ///         .await
+    ///         .build_sync()
     ///         .unwrap();
     ///     let res = SquareClient::new("some_token")
     ///         .locations()
@@ -114,11 +113,11 @@ impl<'a> Locations<'a> {
     ///         .await;
     /// };
     /// ```
-    pub async fn update(self, updated_location: LocationCreationWrapper, location_id: String)
+    pub async fn update(self, updated_location: LocationCreationWrapper, location_id: impl Into<LocationId>)
                                  -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::PUT,
-            SquareAPI::Locations(format!("/{}", location_id)),
+            SquareAPI::Locations(format!("/{}", location_id.into())),
             Some(&updated_location),
             None,
         ).await
@@ -142,11 +141,11 @@ impl<'a> Locations<'a> {
     ///         .await;
     /// };
     /// ```
-    pub async fn retrieve(self, location_id: String)
+    pub async fn retrieve(self, location_id: impl Into<LocationId>)
                                    -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
-            SquareAPI::Locations(format!("/{}", location_id)),
+            SquareAPI::Locations(format!("/{}", location_id.into())),
             None::<&LocationCreationWrapper>,
             None,
         ).await
@@ -173,207 +172,56 @@ impl<'a> Locations<'a> {
 ///     api::locations::LocationCreationWrapper,
 /// };
 ///
-/// async {
-///     let builder = Builder::from(LocationCreationWrapper::default())
+/// let builder = Builder::from(LocationCreationWrapper::default())
 ///     .name("The Foo Bar".to_string())
-///     .build()
-///     .await;
-/// };
+///     .build_sync();
 /// ```
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
+#[validate(with = "location_name")]
 pub struct LocationCreationWrapper {
+    #[builder(flatten(
+        name: Option<String>,
+        address: Option<Address>,
+        business_email: Option<String>,
+        /// Add a complete [BusinessHours](BusinessHours) object by using this method.
+        business_hours: Option<BusinessHours>,
+        business_name: Option<String>,
+        /// Add multiple *capabilities* at once through this method. This method will overwrite all
+        /// other *capabilities* that are already held by the [Location](Location) object.
+        capabilities: Option<Vec<LocationCapability>>,
+        coordinates: Option<Coordinates>,
+        country: Option<String>,
+        currency: Option<Currency>,
+        description: Option<String>,
+        facebook_url: Option<String>,
+        full_format_logo_url: Option<String>,
+        instagram_username: Option<String>,
+        language_code: Option<String>,
+        logo_url: Option<String>,
+        mcc: Option<MerchantCategoryCode>,
+        merchant_id: Option<String>,
+        phone_number: Option<String>,
+        pos_background_url: Option<String>,
+        status: Option<LocationStatus>,
+        tax_ids: Option<TaxIds>,
+        timezone: Option<String>,
+        twitter_username: Option<String>,
+        #[builder(setter = "location_type")]
+        type_name: Option<LocationType>,
+        website_url: Option<String>,
+    ))]
     location: Location
 }
 
-impl Validate for LocationCreationWrapper {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.location.name.is_some() {
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-
-    }
+fn location_name(body: &LocationCreationWrapper, errors: &mut ValidationError) {
+    errors.require("location.name", &body.location.name);
 }
 
 impl<T: ParentBuilder> Builder<LocationCreationWrapper, T> {
-    pub fn name(mut self, name: String) -> Self {
-        self.body.location.name = Some(name);
-
-        self
-    }
-
-    pub fn address(mut self, address: Address) -> Self {
-        self.body.location.address = Some(address);
-
-        self
-    }
-
-    pub fn business_email(mut self, business_email: String) -> Self {
-        self.body.location.business_email = Some(business_email);
-
-        self
-    }
-
     /// Add individual [BusinessHoursPeriod](BusinessHoursPeriod)'s by the use of this method.
     pub fn add_business_hours_period(mut self, business_hours_period: BusinessHoursPeriod) -> Self {
-        match self.body.location.business_hours.take() {
-            Some(mut business_hours) => {
-                business_hours.periods.push(business_hours_period);
-                self.body.location.business_hours = Some(business_hours);
-            }
-            None => self.body.location.business_hours = Some(BusinessHours {
-                periods: vec![business_hours_period]
-            })
-        }
-
-        self
-    }
-
-    /// Add a complete [BusinessHours](BusinessHours) object by using this method.
-    pub fn business_hours(mut self, business_hours: BusinessHours) -> Self {
-        self.body.location.business_hours = Some(business_hours);
-
-        self
-    }
-
-    pub fn business_name(mut self, business_name: String) -> Self {
-        self.body.location.business_name = Some(business_name);
-
-        self
-    }
-
-    /// Add an individual *capability* by the use of this method.
-    pub fn add_capability(mut self, capability: String) -> Self {
-        match self.body.location.capabilities.take() {
-            Some(mut capabilities) => {
-                capabilities.push(capability);
-                self.body.location.capabilities = Some(capabilities)
-            }
-            None => self.body.location.capabilities = Some(vec![capability]),
-        }
-
-        self
-    }
-
-    /// Add multiple *capabilities* at once through this method. This method will overwrite all
-    /// other *capabilities* that are already held by the [Location](Location) object.
-    pub fn capabilities(mut self, capabilities: Vec<String>) -> Self {
-        self.body.location.capabilities = Some(capabilities);
-
-        self
-    }
-
-    pub fn coordinates(mut self, coordinates: Coordinates) -> Self {
-        self.body.location.coordinates = Some(coordinates);
-
-        self
-    }
-
-    pub fn country(mut self, country: String) -> Self {
-        self.body.location.country = Some(country);
-
-        self
-    }
-
-    pub fn currency(mut self, currency: Currency) -> Self {
-        self.body.location.currency = Some(currency);
-
-        self
-    }
-
-    pub fn description(mut self, description: String) -> Self {
-        self.body.location.description = Some(description);
-
-        self
-    }
-
-    pub fn facebook_url(mut self, facebook_url: String) -> Self {
-        self.body.location.facebook_url = Some(facebook_url);
-
-        self
-    }
-
-    pub fn full_format_logo_url(mut self, full_format_logo_url: String) -> Self {
-        self.body.location.full_format_logo_url = Some(full_format_logo_url);
-
-        self
-    }
-
-    pub fn instagram_username(mut self, instagram_username: String) -> Self {
-        self.body.location.instagram_username = Some(instagram_username);
-
-        self
-    }
-
-    pub fn language_code(mut self, language_code: String) -> Self {
-        self.body.location.language_code = Some(language_code);
-
-        self
-    }
-
-    pub fn logo_url(mut self, logo_url: String) -> Self {
-        self.body.location.logo_url = Some(logo_url);
-
-        self
-    }
-
-    pub fn mcc(mut self, mcc: String) -> Self {
-        self.body.location.mcc = Some(mcc);
-
-        self
-    }
-
-    pub fn merchant_id(mut self, merchant_id: String) -> Self {
-        self.body.location.merchant_id = Some(merchant_id);
-
-        self
-    }
-
-    pub fn phone_number(mut self, phone_number: String) -> Self {
-        self.body.location.phone_number = Some(phone_number);
-
-        self
-    }
-
-    pub fn pos_background_url(mut self, pos_background_url: String) -> Self {
-        self.body.location.pos_background_url = Some(pos_background_url);
-
-        self
-    }
-
-    pub fn status(mut self, status: LocationStatus) -> Self {
-        self.body.location.status = Some(status);
-
-        self
-    }
-
-    pub fn tax_ids(mut self, tax_ids: TaxIds) -> Self {
-        self.body.location.tax_ids = Some(tax_ids);
-
-        self
-    }
-
-    pub fn timezone(mut self, timezone: String) -> Self {
-        self.body.location.timezone = Some(timezone);
-
-        self
-    }
-
-    pub fn twitter_username(mut self, twitter_username: String) -> Self {
-        self.body.location.twitter_username = Some(twitter_username);
-
-        self
-    }
-
-    pub fn location_type(mut self, location_type: LocationType) -> Self {
-        self.body.location.type_name = Some(location_type);
-
-        self
-    }
-
-    pub fn website_url(mut self, website_url: String) -> Self {
-        self.body.location.website_url = Some(website_url);
+        self.body.location.business_hours.get_or_insert_with(Default::default)
+            .periods.push(business_hours_period);
 
         self
     }
@@ -398,8 +246,8 @@ mod test_locations {
         assert!(result.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_location_builder() {
+    #[test]
+    fn test_location_builder() {
         let expected = Location {
             id: None,
             name: Some("New Test Location".to_string()),
@@ -434,21 +282,19 @@ mod test_locations {
             .name("New Test Location".to_string())
             .facebook_url("some_url".to_string())
             .location_type(LocationType::Physical)
-            .build()
-            .await;
+            .build_sync();
 
         assert!(actual.is_ok());
 
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual.unwrap().location))
     }
 
-    #[tokio::test]
-    async fn test_location_builder_fail() {
+    #[test]
+    fn test_location_builder_fail() {
         let res = Builder::from(LocationCreationWrapper::default())
             .facebook_url("some_url".to_string())
             .location_type(LocationType::Physical)
-            .build()
-            .await;
+            .build_sync();
 
         assert!(res.is_err());
     }
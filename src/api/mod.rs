@@ -16,10 +16,22 @@ pub mod inventory;
 pub mod sites;
 pub mod terminal;
 pub mod orders;
+pub mod bank_accounts;
+pub mod cash_drawers;
+pub mod vendors;
+pub mod webhook_subscriptions;
+pub mod events;
 
 use crate::client::ClientMode;
 use crate::client::SquareClient;
+use crate::errors::{ChunkError, SquareError};
+use crate::response::{ChunkFailure, ChunkedResponse, SquareResponse};
+
+use futures_util::stream::{self, StreamExt};
+use serde::{ser::Error, Serialize, Serializer};
+use serde_json::Value;
 use std::fmt;
+use std::future::Future;
 
 /// All of the endpoints of the [Square API](https://developer.squareup.com)
 /// for which we have implemented some of the functionality.
@@ -36,6 +48,12 @@ pub enum SquareAPI {
     Sites(String),
     Terminals(String),
     Orders(String),
+    BankAccounts(String),
+    CashDrawers(String),
+    Vendors(String),
+    Webhooks(String),
+    Events(String),
+    ApplePay(String),
 }
 
 /// All of the HTTP verbs that have been implemented and are accepted by the different
@@ -65,6 +83,12 @@ impl fmt::Display for SquareAPI {
             SquareAPI::Sites(path) => write!(f, "sites{}", path),
             SquareAPI::Terminals(path) => write!(f, "terminals{}", path),
             SquareAPI::Orders(path) => write!(f, "orders{}", path),
+            SquareAPI::BankAccounts(path) => write!(f, "bank-accounts{}", path),
+            SquareAPI::CashDrawers(path) => write!(f, "cash-drawers{}", path),
+            SquareAPI::Vendors(path) => write!(f, "vendors{}", path),
+            SquareAPI::Webhooks(path) => write!(f, "webhooks{}", path),
+            SquareAPI::Events(path) => write!(f, "events{}", path),
+            SquareAPI::ApplePay(path) => write!(f, "apple-pay{}", path),
         }
     }
 }
@@ -75,9 +99,243 @@ impl SquareClient {
         const SQUARE_PRODUCTION_BASE: &str = "https://connect.squareup.com/v2/";
         const SQUARE_SANDBOX_BASE: &str = "https://connect.squareupsandbox.com/v2/";
 
-        match self.client_mode {
+        match &self.client_mode {
             ClientMode::Production => format!("{}{}", SQUARE_PRODUCTION_BASE, end_point),
             ClientMode::Sandboxed => format!("{}{}", SQUARE_SANDBOX_BASE, end_point),
+            ClientMode::Custom(base_url) => {
+                let base_url = base_url.as_str().trim_end_matches('/');
+
+                format!("{}/{}", base_url, end_point)
+            },
+        }
+    }
+}
+
+// Splits the inputs into chunks of at most chunk_size, sends a request for every chunk with at
+// most concurrency requests in flight at once and merges the results in the order of the chunks.
+// The request is called with the index of the chunk, the chunk and the cursor of the page to
+// request, every page of a chunk is requested before the chunk counts as successful.
+pub(crate) async fn request_chunked<T, F, Fut>(
+    inputs: Vec<T>,
+    chunk_size: usize,
+    concurrency: usize,
+    request: F,
+) -> ChunkedResponse<T>
+    where T: Clone,
+          F: Fn(usize, Vec<T>, Option<String>) -> Fut,
+          Fut: Future<Output = Result<SquareResponse, SquareError>>,
+{
+    let request = &request;
+    let requests = inputs.chunks(chunk_size.max(1)).enumerate().map(|(index, chunk)| {
+        let chunk = chunk.to_vec();
+
+        async move {
+            let response = request_pages(index, &chunk, request).await;
+
+            (chunk, response)
+        }
+    });
+
+    let results: Vec<_> = stream::iter(requests)
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut res = ChunkedResponse {
+        response: SquareResponse::default(),
+        failures: vec![],
+    };
+    for (inputs, result) in results {
+        match result.and_then(|response| res.response.merge(response)) {
+            Ok(()) => {},
+            Err(error) => res.failures.push(ChunkFailure { inputs, error }),
         }
     }
+
+    res
+}
+
+// Requests every page of a chunk by following the cursors of the responses.
+async fn request_pages<T, F, Fut>(index: usize, chunk: &[T], request: &F)
+    -> Result<SquareResponse, ChunkError>
+    where T: Clone,
+          F: Fn(usize, Vec<T>, Option<String>) -> Fut,
+          Fut: Future<Output = Result<SquareResponse, SquareError>>,
+{
+    let mut response = request(index, chunk.to_vec(), None).await?;
+    let mut cursor = response.cursor.take();
+
+    while let Some(page_cursor) = cursor {
+        let mut page = request(index, chunk.to_vec(), Some(page_cursor)).await?;
+        cursor = page.cursor.take();
+        response.merge(page)?;
+    }
+
+    Ok(response)
+}
+
+// Serializes the typed parameters of a request into the pairs of its query string.
+pub(crate) fn query_pairs<P: Serialize>(parameters: Option<&P>)
+    -> Result<Option<Vec<(String, String)>>, SquareError> {
+    parameters
+        .map(|parameters| {
+            let query = serde_urlencoded::to_string(parameters)?;
+
+            Ok(serde_urlencoded::from_str(&query)?)
+        })
+        .transpose()
+}
+
+// Query strings cannot hold sequences, so the Square API expects lists as a single comma
+// separated value, e.g. types=ITEM,CATEGORY.
+pub(crate) fn comma_separated<T, S>(values: &Option<Vec<T>>, serializer: S)
+    -> Result<S::Ok, S::Error>
+    where T: Serialize,
+          S: Serializer,
+{
+    let values = values.iter()
+        .flatten()
+        .map(|value| match serde_json::to_value(value).map_err(S::Error::custom)? {
+            Value::String(value) => Ok(value),
+            value => Ok(value.to_string()),
+        })
+        .collect::<Result<Vec<_>, S::Error>>()?;
+
+    serializer.serialize_str(&values.join(","))
+}
+
+#[cfg(test)]
+mod test_api {
+    use super::*;
+    use crate::client::Url;
+    use crate::objects::{CatalogIdMapping, CatalogObject, Response};
+
+    #[test]
+    fn test_custom_endpoint() {
+        let with_slash = SquareClient::new("some_token")
+            .custom(Url::parse("http://localhost:8080/v2/").unwrap());
+        let without_slash = SquareClient::new("some_token")
+            .custom(Url::parse("http://localhost:8080/v2").unwrap());
+
+        assert_eq!(with_slash.endpoint(SquareAPI::Payments("/abc".to_string())),
+                   "http://localhost:8080/v2/payments/abc");
+        assert_eq!(without_slash.endpoint(SquareAPI::Locations("".to_string())),
+                   "http://localhost:8080/v2/locations");
+    }
+
+    #[tokio::test]
+    async fn test_request_chunked() {
+        let inputs: Vec<i32> = (0..25).collect();
+
+        let res = request_chunked(inputs, 10, 2, |_, chunk, _| async move {
+            if chunk.contains(&15) {
+                Err(SquareError::from(None))
+            } else {
+                Ok(SquareResponse {
+                    response: Some(Response::Objects(chunk.iter().map(|i| CatalogObject {
+                        id: Some(i.to_string().into()),
+                        ..Default::default()
+                    }).collect())),
+                    ..Default::default()
+                })
+            }
+        }).await;
+
+        assert_eq!(res.failures.len(), 1);
+        assert_eq!(res.failures[0].inputs, (10..20).collect::<Vec<i32>>());
+
+        match res.response.response {
+            Some(Response::Objects(objects)) => {
+                let ids: Vec<String> = objects.into_iter().filter_map(|object| object.id).map(String::from).collect();
+                let expected: Vec<String> = (0..10).chain(20..25).map(|i| i.to_string()).collect();
+
+                assert_eq!(ids, expected)
+            },
+            _ => panic!("expected merged objects"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_chunked_pages() {
+        let inputs: Vec<i32> = (0..4).collect();
+
+        let res = request_chunked(inputs, 2, 2, |_, chunk, cursor| async move {
+            let page = if cursor.is_some() { 1 } else { 0 };
+
+            Ok(SquareResponse {
+                response: Some(Response::Objects(chunk.iter().map(|i| CatalogObject {
+                    id: Some(format!("{}-{}", i, page).into()),
+                    ..Default::default()
+                }).collect())),
+                cursor: if page == 0 { Some("next".to_string()) } else { None },
+                ..Default::default()
+            })
+        }).await;
+
+        assert!(res.is_ok());
+        assert_eq!(res.response.cursor, None);
+
+        match res.response.response {
+            Some(Response::Objects(objects)) => {
+                let ids: Vec<String> = objects.into_iter().filter_map(|object| object.id).map(String::from).collect();
+
+                assert_eq!(ids, vec!["0-0", "1-0", "0-1", "1-1", "2-0", "3-0", "2-1", "3-1"])
+            },
+            _ => panic!("expected merged objects"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_chunked_id_mappings() {
+        let inputs: Vec<i32> = (0..4).collect();
+
+        let res = request_chunked(inputs, 2, 2, |_, chunk, _| async move {
+            Ok(SquareResponse {
+                response: Some(Response::IdMappings(chunk.iter().map(|i| CatalogIdMapping {
+                    client_object_id: Some(format!("#{}", i)),
+                    object_id: Some(i.to_string()),
+                }).collect())),
+                id_mapping: Some(chunk.iter().map(|i| (format!("#{}", i), i.to_string())).collect()),
+                ..Default::default()
+            })
+        }).await;
+
+        assert!(res.is_ok());
+        assert_eq!(res.response.id_mapping.map(|id_mapping| id_mapping.len()), Some(4));
+
+        match res.response.response {
+            Some(Response::IdMappings(id_mappings)) => {
+                let ids: Vec<String> = id_mappings.into_iter().filter_map(|mapping| mapping.object_id).collect();
+
+                assert_eq!(ids, vec!["0", "1", "2", "3"])
+            },
+            _ => panic!("expected merged id mappings"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_chunked_too_many_response_kinds() {
+        let inputs: Vec<i32> = (0..2).collect();
+
+        let res = request_chunked(inputs, 1, 1, |index, _, _| async move {
+            let responses = if index == 0 {
+                [Response::Cards(vec![]), Response::Sites(vec![]), Response::Counts(vec![])]
+            } else {
+                [Response::Changes(vec![]), Response::Events(vec![]), Response::Vendors(vec![])]
+            };
+            let [response, opt_response01, opt_response02] = responses;
+
+            Ok(SquareResponse {
+                response: Some(response),
+                opt_response01: Some(opt_response01),
+                opt_response02: Some(opt_response02),
+                ..Default::default()
+            })
+        }).await;
+
+        assert_eq!(res.failures.len(), 1);
+        assert_eq!(res.failures[0].inputs, vec![1]);
+        assert!(matches!(res.failures[0].error, ChunkError::TooManyResponseKinds));
+        assert!(matches!(res.response.opt_response02, Some(Response::Counts(_))));
+    }
 }
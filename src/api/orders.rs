@@ -4,18 +4,20 @@ Orders functionality of the [Square API](https://developer.squareup.com).
 
 use crate::api::{SquareAPI, Verb};
 use crate::client::SquareClient;
-use crate::errors::{SquareError, ValidationError};
-use crate::objects::{Customer, Order, OrderReward, OrderServiceCharge, SearchOrdersQuery};
+use crate::errors::{FieldPath, SquareError, ValidationError};
+use crate::objects::{Customer, Order, OrderFulfillment, OrderLineItem, OrderLineItemDiscount,
+                     OrderLineItemTax, OrderReward, OrderServiceCharge, SearchOrdersQuery};
 use crate::response::SquareResponse;
-use crate::builder::{Builder, ParentBuilder, Validate, BackIntoBuilder, AddField};
+use crate::builder::{AddField, BackIntoBuilder, SquareBuilder, Validate};
+use crate::objects::ids::{CustomerId, LocationId, OrderId};
 
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 
 impl SquareClient {
-    pub fn orders(&self) -> Orders {
+    pub fn orders(&self) -> Orders<'_> {
         Orders {
-            client: &self,
+            client: self,
         }
     }
 }
@@ -52,11 +54,11 @@ impl<'a> Orders<'a> {
 
     /// Retrieves an [Order](Order) by ID.
     /// [Open in API Reference](https://developer.squareup.com/reference/square/orders/retrieve-order).
-    pub async fn retrieve(self, id: String)
+    pub async fn retrieve(self, id: impl Into<OrderId>)
                       -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
-            SquareAPI::Orders(format!("/{}", id)),
+            SquareAPI::Orders(format!("/{}", id.into())),
             None::<&SearchOrderBody>,
             None,
         ).await
@@ -64,11 +66,11 @@ impl<'a> Orders<'a> {
 
     /// Retrieves an [Order](Order) by ID.
     /// [Open in API Reference](https://developer.squareup.com/reference/square/orders/retrieve-order).
-    pub async fn update(self, id: String, body: OrderUpdateBody)
+    pub async fn update(self, id: impl Into<OrderId>, body: OrderUpdateBody)
                       -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::PUT,
-            SquareAPI::Orders(format!("/{}", id)),
+            SquareAPI::Orders(format!("/{}", id.into())),
             Some(&body),
             None,
         ).await
@@ -77,11 +79,48 @@ impl<'a> Orders<'a> {
     /// Pay for an [Order](Order) using one or more approved payments or settle an order with a
     /// total of 0.
     /// [Open in API Reference](https://developer.squareup.com/reference/square/orders/pay-order).
-    pub async fn pay(self, id: String, body: PayOrderBody)
+    pub async fn pay(self, id: impl Into<OrderId>, body: PayOrderBody)
                       -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::POST,
-            SquareAPI::Orders(format!("/{}/pay", id)),
+            SquareAPI::Orders(format!("/{}/pay", id.into())),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Retrieves a set of [Order](Order)s by their IDs.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/orders/batch-retrieve-orders).
+    pub async fn batch_retrieve(self, body: BatchRetrieveOrdersBody)
+                      -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Orders("/batch-retrieve".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Creates a new [Order](Order), in the `DRAFT` state, by duplicating an existing order. The
+    /// newly created order has only the core fields (such as line items, taxes, and discounts)
+    /// copied from the original order.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/orders/clone-order).
+    ///
+    /// # Arguments
+    /// * `order_id` - The ID of the order to clone.
+    /// * `version` - An optional order version for concurrency protection. If a version is
+    ///   provided, it must match the latest stored version of the order to clone.
+    pub async fn clone_order(self, order_id: impl Into<OrderId>, version: Option<i64>)
+                      -> Result<SquareResponse, SquareError> {
+        let body = CloneOrderBody {
+            idempotency_key: Uuid::new_v4().to_string(),
+            order_id: order_id.into(),
+            version,
+        };
+
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Orders("/clone".to_string()),
             Some(&body),
             None,
         ).await
@@ -100,71 +139,110 @@ impl<'a> Orders<'a> {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
+#[validate(with = "order_location")]
 pub struct CreateOrderBody {
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[idempotency_key]
     idempotency_key: Option<String>,
+    #[builder(flatten(
+        location_id: Option<LocationId>,
+        customer_id: Option<CustomerId>,
+        service_charges: Option<Vec<OrderServiceCharge>>,
+        line_items: Option<Vec<OrderLineItem>>,
+        taxes: Option<Vec<OrderLineItemTax>>,
+        discounts: Option<Vec<OrderLineItemDiscount>>,
+        fulfillments: Option<Vec<OrderFulfillment>>,
+        /// A client-specified ID to associate an entity in another system with this order.
+        reference_id: Option<String>,
+        /// Set all of the metadata of the order at once. This overwrites any metadata that was
+        /// already added.
+        metadata: Option<HashMap<String, String>>,
+    ))]
     order: Order,
 }
 
-impl Validate for CreateOrderBody {
-    fn validate(mut self) -> Result<Self, ValidationError> {
-        if self.order.location_id.is_some(){
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
-            Ok(self)
+fn order_location(body: &CreateOrderBody, errors: &mut ValidationError) {
+    errors.require("order.location_id", &body.order.location_id);
+}
+
+// implements the necessary traits to release an OrderServiceCharge builder from a CreateOrderBody
+// builder
+impl AddField<OrderServiceCharge> for CreateOrderBody {
+    fn add_field(&mut self, field: OrderServiceCharge) {
+        if let Some(service_charges) = self.order.service_charges.as_mut() {
+            service_charges.push(field);
         } else {
-            Err(ValidationError)
+            self.order.service_charges = Some(vec![field]);
         }
     }
+
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("order")
+            .join(&AddField::<OrderServiceCharge>::field_path(&self.order))
+    }
 }
 
-impl<T: ParentBuilder> Builder<CreateOrderBody, T> {
-    pub fn location_id(mut self, location_id: String) -> Self {
-        self.body.order.location_id = Some(location_id);
+// implements the necessary traits to release OrderLineItem, OrderLineItemTax,
+// OrderLineItemDiscount and OrderFulfillment builders from a CreateOrderBody builder
+impl AddField<OrderLineItem> for CreateOrderBody {
+    fn add_field(&mut self, field: OrderLineItem) {
+        self.order.add_field(field);
+    }
 
-        self
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("order")
+            .join(&AddField::<OrderLineItem>::field_path(&self.order))
     }
+}
 
-    pub fn customer_id(mut self, customer_id: String) -> Self {
-        self.body.order.customer_id = Some(customer_id);
+impl AddField<OrderLineItemTax> for CreateOrderBody {
+    fn add_field(&mut self, field: OrderLineItemTax) {
+        self.order.add_field(field);
+    }
 
-        self
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("order")
+            .join(&AddField::<OrderLineItemTax>::field_path(&self.order))
     }
+}
 
-    pub fn add_service_charge(mut self, service_charge: OrderServiceCharge) -> Self {
-        if let Some(services_charges) = self.body.order.service_charges.as_mut() {
-            services_charges.push(service_charge)
-        } else {
-            self.body.order.service_charges = Some(vec![service_charge])
-        }
+impl AddField<OrderLineItemDiscount> for CreateOrderBody {
+    fn add_field(&mut self, field: OrderLineItemDiscount) {
+        self.order.add_field(field);
+    }
 
-        self
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("order")
+            .join(&AddField::<OrderLineItemDiscount>::field_path(&self.order))
     }
 }
 
-// implements the necessary traits to release an OrderServiceCharge builder from a CreateOrderBody
-// builder
-impl AddField<OrderServiceCharge> for CreateOrderBody {
-    fn add_field(&mut self, field: OrderServiceCharge) {
-        if let Some(service_charges) = self.order.service_charges.as_mut() {
-            service_charges.push(field);
-        } else {
-            self.order.service_charges = Some(vec![field]);
-        }
+impl AddField<OrderFulfillment> for CreateOrderBody {
+    fn add_field(&mut self, field: OrderFulfillment) {
+        self.order.add_field(field);
+    }
+
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("order")
+            .join(&AddField::<OrderFulfillment>::field_path(&self.order))
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
+#[builder(skip_validate)]
 pub struct SearchOrderBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     cursor: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     limit: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    location_ids: Option<Vec<String>>,
+    location_ids: Option<Vec<LocationId>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[sub_builder]
     query: Option<SearchOrdersQuery>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(skip, preset(no_return_entries = "false"))]
     return_entries: Option<bool>
 }
 
@@ -176,207 +254,75 @@ impl Validate for SearchOrderBody {
     }
 }
 
-impl<T: ParentBuilder> Builder<SearchOrderBody, T> {
-    pub fn add_location_id(mut self, id: String) -> Self {
-        match self.body.location_ids.as_mut() {
-            Some(ids) => ids.push(id),
-            None => self.body.location_ids = Some(vec![id]),
-        }
-
-        self
-    }
-
-    pub fn location_ids(mut self, ids: Vec<String>) -> Self {
-        self.body.location_ids = Some(ids);
-
-        self
-    }
-
-    pub fn cursor(mut self, cursor: String) -> Self {
-        self.body.cursor = Some(cursor);
-
-        self
-    }
-
-    pub fn limit(mut self, limit: i32) -> Self {
-        self.body.limit = Some(limit);
-
-        self
-    }
-
-    pub fn no_return_entries(mut self) -> Self {
-        self.body.return_entries = Some(false);
-
-        self
-    }
-
-    pub fn query(mut self, query: SearchOrdersQuery) -> Self {
-        self.body.query = Some(query);
-
-        self
-    }
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
+pub struct BatchRetrieveOrdersBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_id: Option<LocationId>,
+    #[required]
+    order_ids: Vec<OrderId>,
 }
 
-// implements the necessary traits to release an SearchOrdersQuery builder from a SearchOrderBody
-// builder
-impl AddField<SearchOrdersQuery> for SearchOrderBody {
-    fn add_field(&mut self, field: SearchOrdersQuery) {
-        self.query = Some(field);
-    }
+#[derive(Serialize)]
+struct CloneOrderBody {
+    idempotency_key: String,
+    order_id: OrderId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<i64>,
 }
 
-#[derive(Clone, Debug, Serialize, Default)]
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
 pub struct OrderUpdateBody {
     fields_to_clear: Option<Vec<String>>,
+    #[idempotency_key]
     idempotency_key: Option<String>,
+    #[required]
+    #[sub_builder]
     order: Option<Order>,
 }
 
-impl Validate for OrderUpdateBody {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.order.is_none() {
-            Err(ValidationError)
-        } else {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
-
-            Ok(self)
-        }
-    }
-}
-
-impl<T: ParentBuilder> Builder<OrderUpdateBody, T> {
-    pub fn fields_to_clear(mut self, fields: Vec<String>) -> Self {
-        self.body.fields_to_clear = Some(fields);
-
-        self
-    }
-
-    pub fn order(mut self, order: Order) -> Self {
-        self.body.order = Some(order);
-
-        self
-    }
-}
-
-// implements the necessary traits to release an Order builder from a OrderUpdateBody
-// builder
-impl AddField<Order> for OrderUpdateBody {
-    fn add_field(&mut self, field: Order) {
-        self.order = Some(field);
-    }
-}
-
-#[derive(Clone, Debug, Serialize, Default)]
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
 pub struct PayOrderBody {
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[idempotency_key]
     idempotency_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[required]
     order_version: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[required]
     payment_ids: Option<Vec<String>>,
 }
 
-impl Validate for PayOrderBody {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        self.idempotency_key = Some(Uuid::new_v4().to_string());
-
-        if self.order_version.is_some() &&
-            self.payment_ids.is_some() {
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-
-
-    }
-}
-
-impl<T: ParentBuilder> Builder<PayOrderBody, T> {
-    fn oder_version(mut self, version: i64) -> Self {
-        self.body.order_version = Some(version);
-
-        self
-    }
-
-    fn payment_ids(mut self, ids: Vec<String>) -> Self {
-        self.body.payment_ids = Some(ids);
-
-        self
-    }
-}
-
-#[derive(Clone, Debug, Serialize, Default)]
+#[derive(Clone, Debug, Serialize, Default, SquareBuilder)]
 pub struct OrderCalculateBody {
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[required]
+    #[sub_builder]
     order: Option<Order>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[sub_builder]
     proposed_rewards: Option<Vec<OrderReward>>,
 }
 
-impl Validate for OrderCalculateBody {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.order.is_some() {
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
-}
-
-impl<T: ParentBuilder> Builder<OrderCalculateBody, T> {
-    fn order(mut self, order: Order) -> Self {
-        self.body.order = Some(order);
-
-        self
-    }
-
-    fn add_proposed_reward(mut self, reward: OrderReward) -> Self {
-        match self.body.proposed_rewards.as_mut() {
-            Some(rewards) => rewards.push(reward),
-            None => self.body.proposed_rewards = Some(vec![reward])
-        }
-
-        self
-    }
-}
-
-// implements the necessary traits to release an Order builder from a OrderCalculateBody
-// builder
-impl AddField<Order> for OrderCalculateBody {
-    fn add_field(&mut self, field: Order) {
-        self.order = Some(field)
-    }
-}
-
-// implements the necessary traits to release an OrderReward builder from a OrderCalculateBody
-// builder
-impl AddField<OrderReward> for OrderCalculateBody {
-    fn add_field(&mut self, field: OrderReward) {
-        match self.proposed_rewards.as_mut() {
-            Some(rewards) => rewards.push(field),
-            None => self.proposed_rewards = Some(vec![field])
-        }
-    }
-}
-
 #[cfg(test)]
 mod test_orders {
-    use crate::builder::Nil;
+    use crate::builder::{Builder, Nil};
     use crate::objects;
-    use crate::objects::enums::{Currency, OrderServiceChargeCalculationPhase, SortOrder, SearchOrdersSortField};
+    use crate::objects::enums::{Currency, OrderFulfillmentType, OrderLineItemDiscountType,
+                                OrderServiceChargeCalculationPhase, SortOrder, SearchOrdersSortField};
     use crate::objects::{Money, SearchOrdersSort};
     use super::*;
 
-    #[tokio::test]
-    async fn test_create_order_body_builder() {
+    #[test]
+    fn test_create_order_body_builder() {
         let expected = CreateOrderBody {
             idempotency_key: None,
             order: Order {
             id: None,
-            location_id: Some("location_id".to_string()),
+            location_id: Some("location_id".into()),
             close_at: None,
             created_at: None,
-            customer_id: Some("customer_id".to_string()),
+            customer_id: Some("customer_id".into()),
             discounts: None,
             fulfillments: None,
             line_items: None,
@@ -429,8 +375,7 @@ mod test_orders {
             .total_phase()
             .into_parent_builder()
             .unwrap()
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert!(actual.idempotency_key.is_some());
@@ -440,8 +385,8 @@ mod test_orders {
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
     }
 
-    #[tokio::test]
-    async fn test_create_order_body_builder_fail() {
+    #[test]
+    fn test_create_order_body_builder_fail() {
         let actual = Builder::from(CreateOrderBody::default())
             .location_id("location_id".to_string())
             .customer_id("customer_id".to_string())
@@ -453,6 +398,144 @@ mod test_orders {
         assert!(actual.is_err());
     }
 
+    #[test]
+    fn test_create_order_body_builder_line_items() {
+        let actual = Builder::from(CreateOrderBody::default())
+            .location_id("location_id".to_string())
+            .reference_id("reference_id".to_string())
+            .add_metadata("key".to_string(), "value".to_string())
+            .sub_builder_from(OrderLineItemTax::default())
+            .uid("state-tax".to_string())
+            .name("State Tax".to_string())
+            .percentage("7.25".to_string())
+            .additive()
+            .line_item_scope()
+            .into_parent_builder()
+            .unwrap()
+            .sub_builder_from(OrderLineItemDiscount::default())
+            .name("Summer Sale".to_string())
+            .percentage("10".to_string())
+            .order_scope()
+            .into_parent_builder()
+            .unwrap()
+            .sub_builder_from(OrderLineItem::default())
+            .quantity("2".to_string())
+            .name("Coffee".to_string())
+            .base_price_money(Money { amount: Some(350), currency: Currency::USD })
+            .add_applied_tax("state-tax".to_string())
+            .into_parent_builder()
+            .unwrap()
+            .sub_builder_from(OrderFulfillment::default())
+            .pickup_details(objects::OrderFulfillmentPickupDetails::default())
+            .into_parent_builder()
+            .unwrap()
+            .build_sync()
+            .unwrap();
+
+        assert_eq!(actual.order.reference_id, Some("reference_id".to_string()));
+        assert_eq!(actual.order.metadata.unwrap().get("key"), Some(&"value".to_string()));
+        assert_eq!(actual.order.taxes.unwrap().len(), 1);
+        assert!(matches!(actual.order.discounts.unwrap()[0].discount_type,
+                         Some(OrderLineItemDiscountType::FixedPercentage)));
+        let line_items = actual.order.line_items.unwrap();
+        assert_eq!(line_items.len(), 1);
+        assert_eq!(line_items[0].applied_taxes.as_ref().unwrap()[0].tax_uid, "state-tax");
+        assert!(matches!(actual.order.fulfillments.unwrap()[0].fulfillment_type,
+                         Some(OrderFulfillmentType::Pickup)));
+    }
+
+    #[test]
+    fn test_create_order_body_builder_line_item_fail() {
+        let actual = Builder::from(CreateOrderBody::default())
+            .location_id("location_id".to_string())
+            .sub_builder_from(OrderLineItem::default())
+            .quantity("1".to_string())
+            .name("Coffee".to_string())
+            .into_parent_builder();
+
+        assert!(actual.is_err());
+
+        let actual = Builder::from(CreateOrderBody::default())
+            .location_id("location_id".to_string())
+            .sub_builder_from(OrderFulfillment::default())
+            .state(objects::enums::OrderFulfillmentState::Proposed)
+            .into_parent_builder();
+
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_create_order_body_builder_violations() {
+        let mut builder = Builder::from(CreateOrderBody::default());
+        for _ in 0..2 {
+            builder = builder
+                .sub_builder_from(OrderLineItem::default())
+                .quantity("1".to_string())
+                .catalog_object_id("BJNQCF2FJ6S6UIDT65ABHLRX")
+                .into_parent_builder()
+                .unwrap();
+        }
+
+        let error = builder
+            .sub_builder_from(OrderLineItem::default())
+            .into_parent_builder()
+            .err()
+            .unwrap();
+
+        assert_eq!(error.object(), "OrderLineItem");
+        assert_eq!(
+            error.to_string(),
+            "could not build OrderLineItem: order.line_items[2].quantity: required; \
+             order.line_items[2]: one of catalog_object_id, name required"
+        );
+
+        let error = Builder::from(PayOrderBody::default()).build_sync().unwrap_err();
+        assert_eq!(error.violations().len(), 2);
+        assert_eq!(error.to_string(),
+                   "could not build PayOrderBody: order_version: required; payment_ids: required");
+    }
+
+    #[test]
+    fn test_batch_retrieve_orders_body_builder() {
+        let expected = BatchRetrieveOrdersBody {
+            location_id: Some("L1JC53TYHS40Z".into()),
+            order_ids: vec![
+                "HnbOXf4007VldqxbMvuzf0IjgyAZY".into(),
+                "CAISENgvlJ6jLWAzERDzjyHVybY".into(),
+            ],
+        };
+
+        let actual = Builder::from(BatchRetrieveOrdersBody::default())
+            .location_id("L1JC53TYHS40Z".to_string())
+            .add_order_id("HnbOXf4007VldqxbMvuzf0IjgyAZY".to_string())
+            .add_order_id("CAISENgvlJ6jLWAzERDzjyHVybY".to_string())
+            .build_sync();
+
+        assert!(Builder::from(BatchRetrieveOrdersBody::default()).build_sync().is_err());
+        assert_eq!(format!("{:?}", expected), format!("{:?}", actual.unwrap()))
+    }
+
+    #[tokio::test]
+    async fn test_batch_retrieve_orders() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let input = Builder::from(BatchRetrieveOrdersBody::default())
+            .add_order_id("HnbOXf4007VldqxbMvuzf0IjgyAZY".to_string())
+            .build_sync()
+            .unwrap();
+
+        let res = sut.orders()
+            .batch_retrieve(input)
+            .await;
+
+        assert!(res.is_ok())
+    }
+
     #[tokio::test]
     async fn test_create_order() {
         use dotenv::dotenv;
@@ -466,7 +549,7 @@ mod test_orders {
             idempotency_key: None,
             order: objects::Order {
                 id: None,
-                location_id: Some("L1JC53TYHS40Z".to_string()),
+                location_id: Some("L1JC53TYHS40Z".into()),
                 close_at: None,
                 created_at: None,
                 customer_id: None,
@@ -520,12 +603,12 @@ mod test_orders {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_search_order_body_builder() {
+    #[test]
+    fn test_search_order_body_builder() {
         let expected = SearchOrderBody {
             cursor: None,
             limit: Some(10),
-            location_ids: Some(vec!["e23icos".to_string(), "daiooaa".to_string(), "pßasmxaskm".to_string()]),
+            location_ids: Some(vec!["e23icos".into(), "daiooaa".into(), "pßasmxaskm".into()]),
             query: Some(SearchOrdersQuery {
                 filter: None,
                 sort: Some(SearchOrdersSort {
@@ -546,8 +629,7 @@ mod test_orders {
             .into_parent_builder()
             .unwrap()
             .no_return_entries()
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
@@ -565,7 +647,7 @@ mod test_orders {
         let input = SearchOrderBody {
             cursor: None,
             limit: None,
-            location_ids: Some(vec!["L1JC53TYHS40Z".to_string()]),
+            location_ids: Some(vec!["L1JC53TYHS40Z".into()]),
             query: Some(SearchOrdersQuery {
                 filter: None,
                 sort: Some(SearchOrdersSort {
@@ -600,8 +682,8 @@ mod test_orders {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_update_order_body_fail() {
+    #[test]
+    fn test_update_order_body_fail() {
 
         let res_vec = vec![
             Builder::from(OrderUpdateBody::default())
@@ -628,7 +710,7 @@ mod test_orders {
             idempotency_key: Some(Uuid::new_v4().to_string()),
             order: Some(Order {
                 id: None,
-                location_id: Some("L1JC53TYHS40Z".to_string()),
+                location_id: Some("L1JC53TYHS40Z".into()),
                 close_at: None,
                 created_at: None,
                 customer_id: None,
@@ -669,8 +751,8 @@ mod test_orders {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_pay_order_body_builder() {
+    #[test]
+    fn test_pay_order_body_builder() {
 
         let expected = PayOrderBody {
             idempotency_key: None,
@@ -679,10 +761,9 @@ mod test_orders {
         };
 
         let mut actual = Builder::from(PayOrderBody::default())
-            .oder_version(3)
+            .order_version(3)
             .payment_ids(vec!["some_id".to_string()])
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert!(actual.idempotency_key.is_some());
@@ -693,12 +774,25 @@ mod test_orders {
     }
 
     #[tokio::test]
-    async fn test_order_calculate_body_builder() {
+    #[allow(deprecated)]
+    async fn test_pay_order_body_deprecated_build() {
+        let actual = Builder::from(PayOrderBody::default())
+            .order_version(3)
+            .payment_ids(vec!["some_id".to_string()])
+            .build()
+            .await;
+
+        assert!(actual.is_ok());
+        assert!(Builder::from(PayOrderBody::default()).build().await.is_err());
+    }
+
+    #[test]
+    fn test_order_calculate_body_builder() {
 
         let expected = OrderCalculateBody {
             order: Some(Order {
                 id: None,
-                location_id: Some("location_id".to_string()),
+                location_id: Some("location_id".into()),
                 close_at: None,
                 created_at: None,
                 customer_id: None,
@@ -763,8 +857,7 @@ mod test_orders {
             .version(3)
             .into_parent_builder()
             .unwrap()
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual));
@@ -782,7 +875,7 @@ mod test_orders {
         let input = OrderCalculateBody {
             order: Some(Order {
                 id: None,
-                location_id: Some("L1JC53TYHS40Z".to_string()),
+                location_id: Some("L1JC53TYHS40Z".into()),
                 close_at: None,
                 created_at: None,
                 customer_id: None,
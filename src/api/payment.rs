@@ -3,21 +3,22 @@ Payment functionality of the [Square API](https://developer.squareup.com).
 */
 
 use crate::client::SquareClient;
-use crate::api::{Verb, SquareAPI};
-use crate::errors::{PaymentBuildError, ValidationError};
-use crate::errors::SquareError;
+use crate::datetime::Timestamp;
+use crate::api::{query_pairs, Verb, SquareAPI};
+use crate::errors::{BuildError, SquareError};
 use crate::objects::{Address, CashPaymentDetails, enums::Currency, ExternalPaymentDetails, Money, Payment};
 use crate::response::SquareResponse;
 
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
-use crate::builder::{Builder, ParentBuilder, Validate};
+use crate::builder::{Builder, ParentBuilder, SquareBuilder};
+use crate::builder::typestate::{Missing, Present, TypedBuilder};
 use crate::objects::enums::SortOrder;
+use crate::objects::ids::{CustomerId, LocationId, OrderId, PaymentId, TeamMemberId};
 
 impl SquareClient {
-    pub fn payments(&self) -> Payments {
+    pub fn payments(&self) -> Payments<'_> {
         Payments {
-            client: &self,
+            client: self,
         }
     }
 }
@@ -31,14 +32,14 @@ impl<'a> Payments<'a> {
     /// [Open in API Reference](https://developer.squareup.com/reference/square/payments/list-payments)
     ///
     /// # Arguments
-    /// * `parameters` - A vector of parameters created through the
-    /// [ListPaymentsParametersBuilder](ListPaymentsParametersBuilder)
-    pub async fn list(self, parameters: Option<Vec<(String, String)>>) -> Result<SquareResponse, SquareError> {
+    /// * `parameters` - The [ListPaymentsParams](ListPaymentsParams) created through the
+    ///   [ListPaymentsParametersBuilder](ListPaymentsParametersBuilder)
+    pub async fn list(self, parameters: Option<ListPaymentsParams>) -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
             SquareAPI::Payments("".to_string()),
             None::<&PaymentRequest>,
-            parameters,
+            query_pairs(parameters.as_ref())?,
         ).await
     }
 
@@ -75,10 +76,10 @@ impl<'a> Payments<'a> {
     ///
     /// # Arguments
     /// * `payment_id` - The idempotency key identifying the payment to be canceled.
-    pub async fn get(self, payment_id: String) -> Result<SquareResponse, SquareError> {
+    pub async fn get(self, payment_id: impl Into<PaymentId>) -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::GET,
-            SquareAPI::Payments(format!("/{}", payment_id)),
+            SquareAPI::Payments(format!("/{}", payment_id.into())),
             None::<&PaymentRequest>,
             None,
         ).await
@@ -91,11 +92,11 @@ impl<'a> Payments<'a> {
     /// # Arguments
     /// * `payment_id` - The idempotency key identifying the payment to be updated.
     /// * `body` - The request body with the updated [Payment](Payment) object.
-    pub async fn update(self, payment_id: String, body: UpdatePaymentBody)
+    pub async fn update(self, payment_id: impl Into<PaymentId>, body: UpdatePaymentBody)
         -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::PUT,
-            SquareAPI::Payments(format!("/{}", payment_id)),
+            SquareAPI::Payments(format!("/{}", payment_id.into())),
             Some(&body),
             None,
         ).await
@@ -106,11 +107,11 @@ impl<'a> Payments<'a> {
     ///
     /// # Arguments
     /// * `payment_id` - The idempotency key identifying the payment to be canceled.
-    pub async fn cancel(self, payment_id: String)
+    pub async fn cancel(self, payment_id: impl Into<PaymentId>)
         -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::POST,
-            SquareAPI::Payments(format!("/{}/cancel", payment_id)),
+            SquareAPI::Payments(format!("/{}/cancel", payment_id.into())),
             None::<&PaymentRequest>,
             None,
         ).await
@@ -122,14 +123,14 @@ impl<'a> Payments<'a> {
     /// # Arguments
     /// * `payment_id` - The idempotency key identifying the payment to be completed.
     /// * `version_token` - Used for optimistic concurrency. This opaque token identifies the
-    /// current [Payment](Payment) version that the caller expects. If the server has a different
-    /// version of the [Payment](Payment), the update fails and a response with a VERSION_MISMATCH
-    /// error is returned.
-    pub async fn complete(self, payment_id: String, version_token: Option<String>)
+    ///   current [Payment](Payment) version that the caller expects. If the server has a different
+    ///   version of the [Payment](Payment), the update fails and a response with a VERSION_MISMATCH
+    ///   error is returned.
+    pub async fn complete(self, payment_id: impl Into<PaymentId>, version_token: Option<String>)
         -> Result<SquareResponse, SquareError> {
         self.client.request(
             Verb::POST,
-            SquareAPI::Payments(format!("/{}/complete", payment_id)),
+            SquareAPI::Payments(format!("/{}/complete", payment_id.into())),
             Some(&CompletePaymentBody {
                 version_token
             }),
@@ -161,16 +162,16 @@ impl ListPaymentsParametersBuilder {
 
     /// The timestamp for the beginning of the reporting period, in RFC 3339 format. Inclusive.
     /// Default: The current time minus one year.
-    pub fn begin_time(mut self, begin_time: String) -> Self {
-        self.begin_time = Some(begin_time);
+    pub fn begin_time(mut self, begin_time: impl Into<Timestamp>) -> Self {
+        self.begin_time = Some(begin_time.into().into());
 
         self
     }
 
     /// The timestamp for the end of the reporting period, in RFC 3339 format.
     // Default: The current time.
-    pub fn end_time(mut self, end_time: String) -> Self {
-        self.end_time = Some(end_time);
+    pub fn end_time(mut self, end_time: impl Into<Timestamp>) -> Self {
+        self.end_time = Some(end_time.into().into());
 
         self
     }
@@ -199,8 +200,8 @@ impl ListPaymentsParametersBuilder {
 
     /// Limit results to the location supplied. By default, results are returned for the default
     /// (main) location associated with the seller.
-    pub fn location_id(mut self, location_id: String) -> Self {
-        self.location_id = Some(location_id);
+    pub fn location_id(mut self, location_id: impl Into<LocationId>) -> Self {
+        self.location_id = Some(location_id.into().into());
 
         self
     }
@@ -238,7 +239,7 @@ impl ListPaymentsParametersBuilder {
         self
     }
 
-    pub async fn build(self) -> Vec<(String,String)> {
+    pub fn build_sync(self) -> ListPaymentsParams {
         let ListPaymentsParametersBuilder {
             begin_time,
             end_time,
@@ -249,55 +250,65 @@ impl ListPaymentsParametersBuilder {
             last_4,
             card_brand,
             limit,
-
         } = self;
 
-        let mut res = vec![];
-
-
-        if let Some(begin_time) = begin_time {
-            res.push(("begin_time".to_string(), begin_time.to_string()))
-        }
-        if let Some(end_time) = end_time {
-            res.push(("end_time".to_string(), end_time.to_string()))
-        }
-        if let Some(sort_order) = sort_order {
-            res.push(("sort_order".to_string(), sort_order.to_string()))
-        }
-        if let Some(cursor) = cursor {
-            res.push(("cursor".to_string(), cursor.to_string()))
-        }
-        if let Some(location_id) = location_id {
-            res.push(("location_id".to_string(), location_id.to_string()))
-        }
-        if let Some(total) = total {
-            res.push(("total".to_string(), total.to_string()))
-        }
-        if let Some(last_4) = last_4 {
-            res.push(("last_4".to_string(), last_4.to_string()))
-        }
-        if let Some(card_brand) = card_brand {
-            res.push(("card_brand".to_string(), card_brand.to_string()))
-        }
-        if let Some(limit) = limit {
-            res.push(("limit".to_string(), limit.to_string()))
+        ListPaymentsParams {
+            begin_time,
+            end_time,
+            sort_order,
+            cursor,
+            location_id,
+            total,
+            last_4,
+            card_brand,
+            limit,
         }
+    }
 
-        res
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListPaymentsParams {
+        self.build_sync()
     }
 }
 
+/// The query parameters of [Payments::list](Payments::list), built through the
+/// [ListPaymentsParametersBuilder](ListPaymentsParametersBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListPaymentsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    begin_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_order: Option<SortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_4: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card_brand: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+}
+
 // -------------------------------------------------------------------------------------------------
 // PaymentRequest implementation
 // -------------------------------------------------------------------------------------------------
 /// The representation of a payment to the square API
-#[derive(Serialize, Debug, Deserialize, Default)]
+#[derive(Serialize, Debug, Deserialize, Default, SquareBuilder)]
 pub struct PaymentRequest {
     #[serde(rename(serialize = "source_id"), skip_serializing_if = "Option::is_none")]
+    #[required]
     source_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[idempotency_key]
     idempotency_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[required]
     amount_money: Option<Money>,
     #[serde(skip_serializing_if = "Option::is_none")]
     accept_partial_authorization: Option<String>,
@@ -312,7 +323,7 @@ pub struct PaymentRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     cash_details: Option<CashPaymentDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    customer_id: Option<String>,
+    customer_id: Option<CustomerId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     delay_action: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -320,11 +331,11 @@ pub struct PaymentRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     external_details: Option<ExternalPaymentDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    location_id: Option<String>,
+    location_id: Option<LocationId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    order_id: Option<String>,
+    order_id: Option<OrderId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reference_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -332,43 +343,44 @@ pub struct PaymentRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     statement_description_identifier: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    team_member_id: Option<String>,
+    team_member_id: Option<TeamMemberId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tip_money: Option<Money>,
     #[serde(skip_serializing_if = "Option::is_none")]
     verification_token: Option<String>,
 }
 
-impl Validate for PaymentRequest {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.source_id.is_some() &&
-            self.amount_money.is_some() {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
-
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
-}
-
 impl<T: ParentBuilder> Builder<PaymentRequest, T> {
-    pub fn source_id(mut self, source_id: String) -> Self {
-        self.body.source_id = Some(source_id);
+    pub fn amount(mut self, amount: i64, currency: Currency) -> Self {
+        self.body.amount_money = Some(Money { amount: Some(amount), currency });
 
         self
     }
+}
 
-    pub fn amount(mut self, amount: i64, currency: Currency) -> Self {
-        self.body.amount_money = Some(Money { amount: Some(amount), currency });
+impl PaymentRequest {
+    /// A [TypedBuilder](TypedBuilder) of a payment request, which only builds once `.source_id()`
+    /// and `.amount()` have been called.
+    pub fn builder() -> TypedBuilder<PaymentRequest, (Missing, Missing)> {
+        TypedBuilder::new()
+    }
+}
 
-        self
+// the state tracks the source_id and amount_money fields
+impl<S, A> TypedBuilder<PaymentRequest, (S, A)> {
+    pub fn source_id(self, source_id: String) -> TypedBuilder<PaymentRequest, (Present, A)> {
+        self.set(|builder| builder.source_id(source_id))
     }
 
-    pub fn verification_token(mut self, token: String) -> Self {
-        self.body.verification_token = Some(token);
+    pub fn amount(self, amount: i64, currency: Currency)
+                  -> TypedBuilder<PaymentRequest, (S, Present)> {
+        self.set(|builder| builder.amount(amount, currency))
+    }
+}
 
-        self
+impl TypedBuilder<PaymentRequest, (Present, Present)> {
+    pub fn build(self) -> Result<PaymentRequest, BuildError> {
+        self.finish()
     }
 }
 
@@ -383,58 +395,21 @@ struct CancelByIdempotencyKey {
 // -------------------------------------------------------------------------------------------------
 // UpdatePaymentBody implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Serialize, Debug, Deserialize, Default)]
+#[derive(Serialize, Debug, Deserialize, Default, SquareBuilder)]
 pub struct UpdatePaymentBody {
+    #[idempotency_key]
     idempotency_key: Option<String>,
+    #[builder(flatten(
+        amount_money: Option<Money>,
+        app_fee_money: Option<Money>,
+        approved_money: Option<Money>,
+        cash_details: Option<CashPaymentDetails>,
+        tip_money: Option<Money>,
+        version_token: Option<String>,
+    ))]
     payment: Payment
 }
 
-impl Validate for UpdatePaymentBody {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        self.idempotency_key = Some(Uuid::new_v4().to_string());
-
-        Ok(self)
-    }
-}
-
-impl<T: ParentBuilder> Builder<UpdatePaymentBody, T> {
-    pub fn amount_money(mut self, amount_money: Money) -> Self {
-        self.body.payment.amount_money = Some(amount_money);
-
-        self
-    }
-
-    pub fn app_fee_money(mut self, app_fee_money: Money) -> Self {
-        self.body.payment.app_fee_money = Some(app_fee_money);
-
-        self
-    }
-
-    pub fn approved_money(mut self, approved_money: Money) -> Self {
-        self.body.payment.approved_money = Some(approved_money);
-
-        self
-    }
-
-    pub fn cash_details(mut self, cash_details: CashPaymentDetails) -> Self {
-        self.body.payment.cash_details = Some(cash_details);
-
-        self
-    }
-
-    pub fn tip_money(mut self, tip_money: Money) -> Self {
-        self.body.payment.tip_money = Some(tip_money);
-
-        self
-    }
-
-    pub fn version_token(mut self, version_token: String) -> Self {
-        self.body.payment.version_token = Some(version_token);
-
-        self
-    }
-}
-
 #[derive(Serialize, Debug, Deserialize)]
 struct CompletePaymentBody {
     version_token: Option<String>,
@@ -443,6 +418,7 @@ struct CompletePaymentBody {
 #[cfg(test)]
 mod test_payments {
     use super::*;
+    use uuid::Uuid;
     
     #[tokio::test]
     async fn test_create_payment() {
@@ -485,8 +461,8 @@ mod test_payments {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_list_payments_parameters_builder() {
+    #[test]
+    fn test_list_payments_parameters_builder() {
         let expected = vec![
             ("sort_order".to_string(), "ASC".to_string()),
             ("location_id".to_string(), "DMIOW91D2MDS".to_string()),
@@ -499,10 +475,9 @@ mod test_payments {
             .card_brand("Visa".to_string())
             .total(10)
             .sort_ascending()
-            .build()
-            .await;
+            .build_sync();
 
-        assert_eq!(expected, actual);
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap());
     }
 
     #[tokio::test]
@@ -514,9 +489,9 @@ mod test_payments {
         let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token);
 
-        let input = vec![
-            ("sort_order".to_string(), "ASC".to_string()),
-        ];
+        let input = ListPaymentsParametersBuilder::new()
+            .sort_ascending()
+            .build_sync();
 
         let res = sut.payments()
             .list(Some(input))
@@ -557,8 +532,23 @@ mod test_payments {
         assert!(res.is_ok())
     }
 
-    #[tokio::test]
-    async fn test_update_payment_body_builder() {
+    #[test]
+    fn test_payment_request_typed_builder() {
+        let actual = PaymentRequest::builder()
+            .amount(10, Currency::USD)
+            .source_id("cnon:card-nonce-ok".to_string())
+            .with(|builder| builder.verification_token("some_token".to_string()))
+            .build()
+            .unwrap();
+
+        assert!(actual.idempotency_key.is_some());
+        assert_eq!(actual.source_id, Some("cnon:card-nonce-ok".to_string()));
+        assert_eq!(actual.amount_money, Some(Money { amount: Some(10), currency: Currency::USD }));
+        assert_eq!(actual.verification_token, Some("some_token".to_string()));
+    }
+
+    #[test]
+    fn test_update_payment_body_builder() {
         let expected = UpdatePaymentBody {
             idempotency_key: None,
             payment: Payment {
@@ -606,8 +596,7 @@ mod test_payments {
 
         let mut actual = Builder::from(UpdatePaymentBody::default())
             .amount_money(Money { amount: Some(30), currency: Currency::USD })
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert!(actual.idempotency_key.is_some());
@@ -4,13 +4,16 @@ Sites functionality of the [Square API](https://developer.squareup.com).
 
 use crate::api::{SquareAPI, Verb};
 use crate::client::SquareClient;
-use crate::errors::SquareError;
+use crate::errors::{RegisterDomainError, SquareError};
+use crate::objects::{Response, Snippet};
 use crate::response::SquareResponse;
 
+use serde::Serialize;
+
 impl SquareClient {
-    pub fn sites(&self) -> Sites {
+    pub fn sites(&self) -> Sites<'_> {
         Sites {
-            client: &self,
+            client: self,
         }
     }
 }
@@ -46,6 +49,136 @@ impl<'a> Sites<'a> {
             None,
         ).await
     }
+
+    /// Retrieves your [Snippet](Snippet) from a Square Online site.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/snippets/retrieve-snippet)
+    ///
+    /// # Arguments
+    /// * `site_id` - The ID of the site that contains the snippet, as returned by
+    ///   [list](Sites::list).
+    pub async fn retrieve_snippet(self, site_id: String)
+                                  -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::Sites(format!("/{}/snippet", site_id)),
+            None::<&Snippet>,
+            None,
+        ).await
+    }
+
+    /// Adds a [Snippet](Snippet) to a Square Online site or updates the existing snippet on the
+    /// site. The snippet code is appended to the end of the head element on every page of the
+    /// site, except checkout pages.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/snippets/upsert-snippet)
+    ///
+    /// # Arguments
+    /// * `site_id` - The ID of the site where you want to add or update the snippet, as returned
+    ///   by [list](Sites::list).
+    /// * `content` - The snippet code, which can contain valid HTML, JavaScript, or both.
+    ///
+    /// # Example: Add an analytics script to a site
+    /// ```rust
+    ///use square_ox::client::SquareClient;
+    ///
+    /// async {
+    ///     let snippet = SquareClient::new("some_token")
+    ///         .sites()
+    ///         .upsert_snippet(
+    ///             "some_site_id".to_string(),
+    ///             "<script>var foo = 'bar';</script>".to_string(),
+    ///         )
+    ///         .await;
+    /// };
+    /// ```
+    pub async fn upsert_snippet(self, site_id: String, content: String)
+                                -> Result<SquareResponse, SquareError> {
+        let body = SnippetWrapper {
+            snippet: Snippet {
+                content,
+                ..Default::default()
+            }
+        };
+
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Sites(format!("/{}/snippet", site_id)),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Removes your [Snippet](Snippet) from a Square Online site.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/snippets/delete-snippet)
+    ///
+    /// # Arguments
+    /// * `site_id` - The ID of the site that contains the snippet, as returned by
+    ///   [list](Sites::list).
+    pub async fn delete_snippet(self, site_id: String)
+                                -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::DELETE,
+            SquareAPI::Sites(format!("/{}/snippet", site_id)),
+            None::<&Snippet>,
+            None,
+        ).await
+    }
+
+    /// Activates the domain of a Square Online site for use with Apple Pay on the Web and
+    /// Square. The domain is looked up by first requesting the seller's sites through
+    /// [list](Sites::list), so this makes two requests to the Square API and fails with
+    /// [SiteNotFound](RegisterDomainError::SiteNotFound) if the seller has no such site. Use
+    /// [register_domain_name](Sites::register_domain_name) to skip the lookup if the domain is
+    /// already known.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/apple-pay/register-domain)
+    ///
+    /// # Arguments
+    /// * `site_id` - The ID of the site whose domain is to be registered, as returned by
+    ///   [list](Sites::list).
+    pub async fn register_domain(self, site_id: String)
+                                 -> Result<SquareResponse, RegisterDomainError> {
+        let sites = Sites { client: self.client }.list().await?;
+
+        let domain_name = IntoIterator::into_iter(
+            [sites.response, sites.opt_response01, sites.opt_response02, sites.opt_response03]
+        )
+            .flatten()
+            .filter_map(|response| match response {
+                Response::Sites(sites) => Some(sites),
+                _ => None,
+            })
+            .flatten()
+            .find(|site| site.id.as_deref() == Some(site_id.as_str()))
+            .and_then(|site| site.domain)
+            .ok_or(RegisterDomainError::SiteNotFound(site_id))?;
+
+        Ok(self.register_domain_name(domain_name).await?)
+    }
+
+    /// Activates a domain for use with Apple Pay on the Web and Square, such as the `domain` of a
+    /// [Site](crate::objects::Site) returned by [list](Sites::list).
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/apple-pay/register-domain)
+    ///
+    /// # Arguments
+    /// * `domain_name` - The domain to register, without the protocol, e.g. `example.com`.
+    pub async fn register_domain_name(self, domain_name: String)
+                                      -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::ApplePay("/domains".to_string()),
+            Some(&RegisterDomainBody { domain_name }),
+            None,
+        ).await
+    }
+}
+
+#[derive(Serialize)]
+struct SnippetWrapper {
+    snippet: Snippet,
+}
+
+#[derive(Serialize)]
+struct RegisterDomainBody {
+    domain_name: String,
 }
 
 #[cfg(test)]
@@ -58,7 +191,7 @@ mod test_sites {
         use std::env;
 
         dotenv().ok();
-        let access_token = env::var("SITE_ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let access_token = env::var("SITE_ACCESS_TOKEN").expect("SITE_ACCESS_TOKEN to be set");
         let sut = SquareClient::new(&access_token).production();
 
         let res = sut.sites()
@@ -67,4 +200,47 @@ mod test_sites {
 
         assert!(res.is_ok())
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_retrieve_snippet() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("SITE_ACCESS_TOKEN").expect("SITE_ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token).production();
+
+        let res = sut.sites()
+            .retrieve_snippet("site_278075276488921835".to_string())
+            .await;
+
+        assert!(res.is_ok())
+    }
+
+    #[tokio::test]
+    async fn test_register_domain_site_not_found() {
+        use crate::cassette::Cassette;
+        use serde_json::json;
+
+        let path = std::env::temp_dir()
+            .join(format!("square-ox-{}", uuid::Uuid::new_v4().to_simple()))
+            .join("sites.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, json!({
+            "interactions": [{
+                "request": { "verb": "GET", "path": "/v2/sites", "query": [], "body": null },
+                "response": {
+                    "status": 200,
+                    "body": { "sites": [{ "id": "site_278075276488921835", "domain": "example.com" }] }
+                }
+            }]
+        }).to_string()).unwrap();
+
+        let sut = SquareClient::new("some_token").cassette(Cassette::replay(&path).unwrap());
+
+        match sut.sites().register_domain("site_unknown".to_string()).await {
+            Err(RegisterDomainError::SiteNotFound(site_id)) => assert_eq!(site_id, "site_unknown"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
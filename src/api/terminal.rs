@@ -4,21 +4,23 @@ Terminals functionality of the [Square API](https://developer.squareup.com).
 
 use crate::api::{SquareAPI, Verb};
 use crate::client::SquareClient;
-use crate::errors::{SquareError, ValidationError};
+use crate::datetime::IsoDuration;
+use crate::errors::{BuildError, FieldPath, SquareError, ValidationError};
 use crate::objects::{DeviceCheckoutOptions, Money, PaymentOptions, TerminalCheckout,
                      TerminalCheckoutQuery, TerminalRefund, TerminalRefundQuery};
 use crate::objects::enums::{CheckoutOptionsPaymentType, TerminalCheckoutStatus};
 use crate::response::SquareResponse;
 
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 use crate::objects::TimeRange;
-use crate::builder::{AddField, Builder, ParentBuilder, Validate};
+use crate::builder::{AddField, SquareBuilder};
+use crate::builder::typestate::{Missing, Present, TypedBuilder};
+use crate::objects::ids::{CustomerId, OrderId, PaymentId};
 
 impl SquareClient {
-    pub fn terminal(&self) -> Terminal {
+    pub fn terminal(&self) -> Terminal<'_> {
         Terminal {
-            client: &self
+            client: self
         }
     }
 }
@@ -134,9 +136,22 @@ impl<'a> Terminal<'a> {
 // -------------------------------------------------------------------------------------------------
 // CreateTerminalCheckoutBody builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, SquareBuilder)]
+#[validate(with = "checkout_details")]
 pub struct CreateTerminalCheckoutBody {
+    #[idempotency_key]
     idempotency_key: Option<String>,
+    #[builder(flatten(
+        amount_money: Option<Money>,
+        device_options: Option<DeviceCheckoutOptions>,
+        customer_id: Option<CustomerId>,
+        deadline_duration: Option<IsoDuration>,
+        note: Option<String>,
+        order_id: Option<OrderId>,
+        payment_type: Option<CheckoutOptionsPaymentType>,
+        payment_options: Option<PaymentOptions>,
+        reference_id: Option<String>,
+    ))]
     checkout: TerminalCheckout,
 }
 
@@ -149,229 +164,115 @@ impl Default for CreateTerminalCheckoutBody {
     }
 }
 
-impl Validate for CreateTerminalCheckoutBody {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.checkout.amount_money.is_some() &&
-            self.checkout.device_options.is_some() {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
-}
-
-impl<T: ParentBuilder> Builder<CreateTerminalCheckoutBody, T> {
-    pub fn amount_money(mut self, amount: Money) -> Self {
-        self.body.checkout.amount_money = Some(amount);
-
-        self
-    }
-
-    pub fn device_options(mut self, device_options: DeviceCheckoutOptions) -> Self {
-        self.body.checkout.device_options = Some(device_options);
-
-        self
-    }
-
-    pub fn customer_id(mut self, customer_id: String) -> Self {
-        self.body.checkout.customer_id = Some(customer_id);
-
-        self
-    }
-
-    pub fn deadline_duration(mut self, deadline_duration: String) -> Self {
-        self.body.checkout.deadline_duration = Some(deadline_duration);
-
-        self
-    }
-
-    pub fn note(mut self, note: String) -> Self {
-        self.body.checkout.note = Some(note);
-
-        self
-    }
-
-    pub fn order_id(mut self, order_id: String) -> Self {
-        self.body.checkout.order_id = Some(order_id);
-
-        self
-    }
-
-    pub fn payment_type(mut self, payment_type: CheckoutOptionsPaymentType) -> Self {
-        self.body.checkout.payment_type = Some(payment_type);
-
-        self
-    }
-
-    pub fn payment_options(mut self, payment_options: PaymentOptions) -> Self {
-        self.body.checkout.payment_options = Some(payment_options);
-
-        self
-    }
-
-    pub fn reference_id(mut self, reference_id: String) -> Self {
-        self.body.checkout.reference_id = Some(reference_id);
-
-        self
-    }
+fn checkout_details(body: &CreateTerminalCheckoutBody, errors: &mut ValidationError) {
+    errors.require("checkout.amount_money", &body.checkout.amount_money);
+    errors.require("checkout.device_options", &body.checkout.device_options);
 }
 
 impl AddField<DeviceCheckoutOptions> for CreateTerminalCheckoutBody {
     fn add_field(&mut self, field: DeviceCheckoutOptions) {
         self.checkout.device_options = Some(field);
     }
+
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("checkout.device_options")
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
 // SearchTerminalCheckoutBody builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
 pub struct SearchTerminalCheckoutBody {
+    #[sub_builder]
     query: Option<TerminalCheckoutQuery>,
     cursor: Option<String>,
     limit: Option<i32>,
 }
 
-impl Validate for SearchTerminalCheckoutBody {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        Ok(self)
-    }
-}
-
-impl<T: ParentBuilder> Builder<SearchTerminalCheckoutBody, T> {
-    pub fn query(mut self, query: TerminalCheckoutQuery) -> Self {
-        self.body.query = Some(query);
-
-        self
-    }
-
-    pub fn cursor(mut self, cursor: String) -> Self {
-        self.body.cursor = Some(cursor);
-
-        self
-    }
-
-    pub fn limit(mut self, limit: i32) -> Self {
-        self.body.limit = Some(limit);
-
-        self
-    }
-}
-
-impl AddField<TerminalCheckoutQuery> for SearchTerminalCheckoutBody {
-    fn add_field(&mut self, field: TerminalCheckoutQuery) {
-        self.query = Some(field);
-    }
-}
-
 // -------------------------------------------------------------------------------------------------
 // CreateTerminalRefundBody builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
+#[validate(with = "refund_details")]
 pub struct CreateTerminalRefundBody {
+    #[idempotency_key]
     idempotency_key: Option<String>,
+    #[builder(flatten(
+        amount_money: Option<Money>,
+        device_id: Option<String>,
+        payment_id: Option<PaymentId>,
+        reason: Option<String>,
+        deadline_duration: Option<IsoDuration>,
+    ))]
     refund: TerminalRefund,
 }
 
-impl Validate for CreateTerminalRefundBody {
-    fn validate(mut self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.refund.device_id.is_some() &&
-            self.refund.amount_money.is_some() &&
-            self.refund.reason.is_some() &&
-            self.refund.payment_id.is_some() {
-            self.idempotency_key = Some(Uuid::new_v4().to_string());
-
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
+fn refund_details(body: &CreateTerminalRefundBody, errors: &mut ValidationError) {
+    errors.require("refund.device_id", &body.refund.device_id);
+    errors.require("refund.amount_money", &body.refund.amount_money);
+    errors.require("refund.reason", &body.refund.reason);
+    errors.require("refund.payment_id", &body.refund.payment_id);
 }
 
-impl<T: ParentBuilder> Builder<CreateTerminalRefundBody, T> {
-    pub fn amount_money(mut self, amount_money: Money) -> Self {
-        self.body.refund.amount_money = Some(amount_money);
-
-        self
+impl CreateTerminalRefundBody {
+    /// A [TypedBuilder](TypedBuilder) of a terminal refund, which only builds once
+    /// `.amount_money()`, `.device_id()`, `.payment_id()` and `.reason()` have been called.
+    pub fn builder()
+        -> TypedBuilder<CreateTerminalRefundBody, (Missing, Missing, Missing, Missing)> {
+        TypedBuilder::new()
     }
+}
 
-    pub fn device_id(mut self, device_id: String) -> Self {
-        self.body.refund.device_id = Some(device_id);
-
-        self
+// the state tracks the amount_money, device_id, payment_id and reason fields
+impl<A, D, P, R> TypedBuilder<CreateTerminalRefundBody, (A, D, P, R)> {
+    pub fn amount_money(self, amount_money: Money)
+                        -> TypedBuilder<CreateTerminalRefundBody, (Present, D, P, R)> {
+        self.set(|builder| builder.amount_money(amount_money))
     }
 
-    pub fn payment_id(mut self, payment_id: String) -> Self {
-        self.body.refund.payment_id = Some(payment_id);
-
-        self
+    pub fn device_id(self, device_id: String)
+                     -> TypedBuilder<CreateTerminalRefundBody, (A, Present, P, R)> {
+        self.set(|builder| builder.device_id(device_id))
     }
 
-    pub fn reason(mut self, reason: String) -> Self {
-        self.body.refund.reason = Some(reason);
-
-        self
+    pub fn payment_id(self, payment_id: impl Into<PaymentId>)
+                      -> TypedBuilder<CreateTerminalRefundBody, (A, D, Present, R)> {
+        self.set(|builder| builder.payment_id(payment_id))
     }
 
-    pub fn deadline_duration(mut self, reason: String) -> Self {
-        self.body.refund.deadline_duration = Some(reason);
+    pub fn reason(self, reason: String)
+                  -> TypedBuilder<CreateTerminalRefundBody, (A, D, P, Present)> {
+        self.set(|builder| builder.reason(reason))
+    }
+}
 
-        self
+impl TypedBuilder<CreateTerminalRefundBody, (Present, Present, Present, Present)> {
+    pub fn build(self) -> Result<CreateTerminalRefundBody, BuildError> {
+        self.finish()
     }
 }
 
 // -------------------------------------------------------------------------------------------------
 // SearchTerminalRefundBody builder implementation
 // -------------------------------------------------------------------------------------------------
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
 pub struct SearchTerminalRefundBody {
     cursor: Option<String>,
     limit: Option<i32>,
+    #[sub_builder]
     query: Option<TerminalRefundQuery>,
 }
 
-impl Validate for SearchTerminalRefundBody {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        Ok(self)
-    }
-}
-
-impl<T: ParentBuilder> Builder<SearchTerminalRefundBody, T> {
-    pub fn query(mut self, query: TerminalRefundQuery) -> Self {
-        self.body.query = Some(query);
-
-        self
-    }
-
-    pub fn limit(mut self, limit: i32) -> Self {
-        self.body.limit = Some(limit);
-
-        self
-    }
-
-    pub fn cursor(mut self, cursor: String) -> Self {
-        self.body.cursor = Some(cursor);
-
-        self
-    }
-}
-
-impl AddField<TerminalRefundQuery> for SearchTerminalRefundBody {
-    fn add_field(&mut self, field: TerminalRefundQuery) {
-        self.query = Some(field);
-    }
-}
-
 #[cfg(test)]
 mod test_terminals {
-    use crate::builder::BackIntoBuilder;
+    use crate::builder::{BackIntoBuilder, Builder};
     use super::*;
     use crate::objects::enums::{Currency, SortOrder};
     use crate::objects::{TerminalCheckoutQueryFilter, TerminalCheckoutQuerySort};
 
-    #[tokio::test]
-    async fn test_create_terminal_checkout_body_builder() {
+    #[test]
+    fn test_create_terminal_checkout_body_builder() {
         let expected = CreateTerminalCheckoutBody {
             idempotency_key: None,
             checkout: TerminalCheckout {
@@ -413,8 +314,7 @@ mod test_terminals {
             .skip_receipt_screen()
             .into_parent_builder()
             .unwrap()
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert!(actual.idempotency_key.is_some());
@@ -424,8 +324,8 @@ mod test_terminals {
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
     }
 
-    #[tokio::test]
-    async fn test_search_terminal_checkout_body_builder() {
+    #[test]
+    fn test_search_terminal_checkout_body_builder() {
         let expected = SearchTerminalCheckoutBody {
             query: Some(TerminalCheckoutQuery {
                 filter: Some(TerminalCheckoutQueryFilter {
@@ -448,8 +348,7 @@ mod test_terminals {
             .device_id("some_id".to_string())
             .into_parent_builder()
             .unwrap()
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
@@ -486,8 +385,8 @@ mod test_terminals {
         assert!(res.is_err())
     }
 
-    #[tokio::test]
-    async fn test_create_terminal_refund_body_builder() {
+    #[test]
+    fn test_create_terminal_refund_body_builder() {
         let expected = CreateTerminalRefundBody {
             idempotency_key: None,
             refund: TerminalRefund {
@@ -497,7 +396,7 @@ mod test_terminals {
                     currency: Currency::USD
                 }),
                 device_id: Some("some_id".to_string()),
-                payment_id: Some("some_id".to_string()),
+                payment_id: Some("some_id".into()),
                 reason: Some("some reason".to_string()),
                 app_id: None,
                 cancel_reason: None,
@@ -516,8 +415,7 @@ mod test_terminals {
             .device_id("some_id".to_string())
             .payment_id("some_id".to_string())
             .reason("some reason".to_string())
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         actual.idempotency_key = None;
@@ -525,21 +423,51 @@ mod test_terminals {
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
     }
 
-    #[tokio::test]
-    async fn test_create_terminal_refund_body_builder_fail() {
+    #[test]
+    fn test_create_terminal_refund_body_typed_builder() {
+        let actual = CreateTerminalRefundBody::builder()
+            .reason("some reason".to_string())
+            .payment_id("some_id")
+            .device_id("some_id".to_string())
+            .amount_money(Money { amount: Some(10), currency: Currency::USD })
+            .with(|builder| builder.deadline_duration(std::time::Duration::from_secs(300)))
+            .build()
+            .unwrap();
+
+        assert!(actual.idempotency_key.is_some());
+        assert_eq!(actual.refund.payment_id, Some("some_id".into()));
+        assert_eq!(actual.refund.deadline_duration, Some("PT5M".into()));
+    }
+
+    #[test]
+    fn test_create_terminal_refund_body_builder_invalid_deadline_duration() {
+        let res = Builder::from(CreateTerminalRefundBody::default())
+            .amount_money(Money { amount: Some(10), currency: Currency::USD })
+            .device_id("some_id".to_string())
+            .payment_id("some_id".to_string())
+            .reason("some reason".to_string())
+            .deadline_duration("5 minutes")
+            .build_sync();
+
+        let errors = res.unwrap_err();
+        assert_eq!(errors.violations().len(), 1);
+        assert_eq!(errors.violations()[0].path.to_string(), "refund.deadline_duration");
+    }
+
+    #[test]
+    fn test_create_terminal_refund_body_builder_fail() {
 
         let res = Builder::from(CreateTerminalRefundBody::default())
             .payment_id("some_id".to_string())
             .device_id("some_id".to_string())
             .amount_money(Money { amount: Some(10), currency: Currency::USD })
-            .build()
-            .await;
+            .build_sync();
 
         assert!(res.is_err())
     }
 
-    #[tokio::test]
-    async fn test_search_terminal_refund_body_builder() {
+    #[test]
+    fn test_search_terminal_refund_body_builder() {
         use crate::objects::TerminalRefundQueryFilter;
         let expected = SearchTerminalRefundBody {
             cursor: Some("some cursor".to_string()),
@@ -563,8 +491,7 @@ mod test_terminals {
             .cancel_requested()
             .into_parent_builder()
             .unwrap()
-            .build()
-            .await
+            .build_sync()
             .unwrap();
 
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
@@ -0,0 +1,491 @@
+/*!
+Vendors functionality of the [Square API](https://developer.squareup.com).
+ */
+
+use crate::api::{SquareAPI, Verb};
+use crate::builder::{AddField, Builder, ParentBuilder, SquareBuilder};
+use crate::client::SquareClient;
+use crate::errors::{FieldPath, SquareError, ValidationError, ViolationReason};
+use crate::objects::{Address, SearchVendorsFilter, SearchVendorsSort, Vendor, VendorContact};
+use crate::objects::enums::{SearchVendorsSortField, SortOrder, VendorStatus};
+use crate::response::SquareResponse;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+impl SquareClient {
+    /// Returns a [Vendors](Vendors) object through which you can make calls specifically to the
+    /// Vendors endpoint of the [Square API](https://developer.squareup.com).
+    /// # Example: Retrieve a vendor by its id.
+    /// ```rust
+    /// use square_ox::{
+    ///         response::{SquareResponse, ResponseError},
+    ///         client::SquareClient,
+    ///     };
+    ///
+    /// async {
+    ///     let vendor = SquareClient::new("some_token")
+    ///         .vendors()
+    ///         .retrieve("some_vendor_id".to_string())
+    ///         .await;
+    ///     };
+    /// ```
+    pub fn vendors(&self) -> Vendors<'_> {
+        Vendors {
+            client: self,
+        }
+    }
+}
+
+/// Allows you to make calls to the [Square API](https://developer.squareup.com) at the Vendors
+/// endpoint with all currently implemented methods.
+pub struct Vendors<'a> {
+    client: &'a SquareClient,
+}
+
+impl<'a> Vendors<'a> {
+    /// Creates one or more [Vendor](Vendor) objects to represent suppliers to a seller.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/vendors/bulk-create-vendors)
+    ///
+    /// # Arguments
+    /// * `body` - A [BulkCreateVendorsBody](BulkCreateVendorsBody) holding the vendors keyed by
+    ///   a client-supplied key, which is also used to key the results in the response.
+    pub async fn bulk_create(self, body: BulkCreateVendorsBody)
+                             -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Vendors("/bulk-create".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Retrieves one or more [Vendor](Vendor) objects of specified IDs.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/vendors/bulk-retrieve-vendors)
+    ///
+    /// # Arguments
+    /// * `body` - A [BulkRetrieveVendorsBody](BulkRetrieveVendorsBody) holding the vendor ids.
+    pub async fn bulk_retrieve(self, body: BulkRetrieveVendorsBody)
+                               -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Vendors("/bulk-retrieve".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Updates one or more existing [Vendor](Vendor) objects as suppliers to a seller.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/vendors/bulk-update-vendors)
+    ///
+    /// # Arguments
+    /// * `body` - A [BulkUpdateVendorsBody](BulkUpdateVendorsBody) holding the updates keyed by
+    ///   the id of the vendor they are to be applied to.
+    pub async fn bulk_update(self, body: BulkUpdateVendorsBody)
+                             -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::PUT,
+            SquareAPI::Vendors("/bulk-update".to_string()),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Creates a single [Vendor](Vendor) object to represent a supplier to a seller.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/vendors/create-vendor)
+    ///
+    /// # Arguments
+    /// * `vendor` - A [Vendor](Vendor) wrapped in a [VendorWrapper](VendorWrapper).
+    ///
+    /// # Example
+    /// ```rust
+    /// use square_ox::{
+    ///     client::SquareClient,
+    ///     api::vendors::VendorWrapper,
+    ///     builder::Builder
+    /// };
+    ///
+    /// async {
+    ///     let vendor = Builder::from(VendorWrapper::default())
+    ///         .name("Foo Supplies".to_string())
+    ///         .build_sync()
+    ///         .unwrap();
+    ///
+    ///     let res = SquareClient::new("some_token")
+    ///         .vendors()
+    ///         .create(vendor)
+    ///         .await;
+    /// };
+    /// ```
+    pub async fn create(self, vendor: VendorWrapper)
+                        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Vendors("/create".to_string()),
+            Some(&vendor),
+            None,
+        ).await
+    }
+
+    /// Retrieves the [Vendor](Vendor) of a specified ID.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/vendors/retrieve-vendor)
+    ///
+    /// # Arguments
+    /// * `vendor_id` - ID of the [Vendor](Vendor) to retrieve.
+    pub async fn retrieve(self, vendor_id: String)
+                          -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::Vendors(format!("/{}", vendor_id)),
+            None::<&Vendor>,
+            None,
+        ).await
+    }
+
+    /// Updates an existing [Vendor](Vendor) object as a supplier to a seller.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/vendors/update-vendor)
+    ///
+    /// # Arguments
+    /// * `vendor_id` - ID of the [Vendor](Vendor) to update.
+    /// * `vendor` - A [VendorWrapper](VendorWrapper) holding the updated fields.
+    pub async fn update(self, vendor_id: String, vendor: VendorWrapper)
+                        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::PUT,
+            SquareAPI::Vendors(format!("/{}", vendor_id)),
+            Some(&vendor),
+            None,
+        ).await
+    }
+
+    /// Searches for [Vendor](Vendor)s using a filter against supported [Vendor](Vendor)
+    /// properties and a supported sorter.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/vendors/search-vendors)
+    ///
+    /// # Arguments
+    /// * `query` - A [SearchVendorsQuery](SearchVendorsQuery).
+    pub async fn search(self, query: SearchVendorsQuery)
+                        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Vendors("/search".to_string()),
+            Some(&query),
+            None,
+        ).await
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// VendorWrapper builder implementation
+// -------------------------------------------------------------------------------------------------
+/// Build a wrapper around a [Vendor](Vendor) for the create and update requests.
+///
+/// A [VendorWrapper](VendorWrapper) is only valid if the [Vendor](Vendor) it holds either has a
+/// name, which is required upon creation, or the id of the [Vendor](Vendor) that is to be updated.
+/// * `.name()` or `.id()`
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "vendor_identity")]
+pub struct VendorWrapper {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[idempotency_key]
+    pub(crate) idempotency_key: Option<String>,
+    #[builder(flatten(
+        id: Option<String>,
+        name: Option<String>,
+        address: Option<Address>,
+        account_number: Option<String>,
+        note: Option<String>,
+        /// The current version of the [Vendor](Vendor), required when updating to enable
+        /// optimistic concurrency control.
+        version: Option<i32>,
+        /// Add an individual [VendorContact](VendorContact) by the use of `.add_contact()`.
+        contacts: Option<Vec<VendorContact>>,
+        #[builder(skip, preset(
+            active = "VendorStatus::Active",
+            inactive = "VendorStatus::Inactive",
+        ))]
+        status: Option<VendorStatus>,
+    ))]
+    pub(crate) vendor: Vendor,
+}
+
+fn vendor_identity(body: &VendorWrapper, errors: &mut ValidationError) {
+    errors.require_one_of("vendor", &[
+        ("name", body.vendor.name.is_some()),
+        ("id", body.vendor.id.is_some()),
+    ]);
+}
+
+// -------------------------------------------------------------------------------------------------
+// BulkCreateVendorsBody builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "vendor_names")]
+pub struct BulkCreateVendorsBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[idempotency_key]
+    idempotency_key: Option<String>,
+    /// The [Vendor](Vendor)s to create, keyed by a client-supplied key that is used to match the
+    /// created [Vendor](Vendor)s in the response.
+    vendors: HashMap<String, Vendor>,
+}
+
+// every vendor that is created needs a name
+fn vendor_names(body: &BulkCreateVendorsBody, errors: &mut ValidationError) {
+    errors.check(!body.vendors.is_empty(), "vendors", ViolationReason::Empty);
+    for (key, vendor) in body.vendors.iter() {
+        errors.require(FieldPath::from("vendors").key(key.as_str()).field("name"), &vendor.name);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// BulkRetrieveVendorsBody builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+pub struct BulkRetrieveVendorsBody {
+    #[required]
+    vendor_ids: Vec<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// BulkUpdateVendorsBody builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "updated_vendors")]
+pub struct BulkUpdateVendorsBody {
+    /// The updates of the [Vendor](Vendor)s, keyed by the id of the [Vendor](Vendor) to update.
+    vendors: HashMap<String, VendorWrapper>,
+    // updates added through a sub builder without the id of the vendor to update, which cannot be
+    // keyed and fail the validation
+    #[serde(skip)]
+    #[builder(skip)]
+    vendors_without_id: Vec<VendorWrapper>,
+}
+
+fn updated_vendors(body: &BulkUpdateVendorsBody, errors: &mut ValidationError) {
+    errors.check(
+        !body.vendors.is_empty() || !body.vendors_without_id.is_empty(),
+        "vendors",
+        ViolationReason::Empty,
+    );
+    for index in 0..body.vendors_without_id.len() {
+        errors.check(
+            false,
+            FieldPath::from("vendors").index(index).field("vendor").field("id"),
+            ViolationReason::Required,
+        );
+    }
+}
+
+impl<T: ParentBuilder> Builder<BulkUpdateVendorsBody, T> {
+    /// Start a sub builder for the update of the [Vendor](Vendor) with the given id, which is
+    /// added under that id by `.into_parent_builder()`.
+    pub fn vendor_builder(self, vendor_id: String) -> Builder<VendorWrapper, Self> {
+        let path = self.path.join(&FieldPath::from("vendors").key(vendor_id.as_str()));
+        let mut body = VendorWrapper::default();
+        body.vendor.id = Some(vendor_id);
+
+        Builder {
+            body,
+            parent_builder: Some(self),
+            path,
+        }
+    }
+}
+
+// Allows a VendorWrapper to be built from within a BulkUpdateVendorsBody builder through
+// .sub_builder_from() or .vendor_builder(). The update is keyed by the id held by the wrapped
+// vendor, an update without an id fails the validation of the BulkUpdateVendorsBody.
+impl AddField<VendorWrapper> for BulkUpdateVendorsBody {
+    fn add_field(&mut self, field: VendorWrapper) {
+        match field.vendor.id.clone() {
+            Some(id) => { self.vendors.insert(id, field); },
+            None => self.vendors_without_id.push(field),
+        }
+    }
+
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("vendors")
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// SearchVendorsQuery builder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+pub struct SearchVendorsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(flatten(
+        /// Add a name to filter the [Vendor](Vendor)s by with `.add_name()`.
+        name: Option<Vec<String>>,
+        /// Add a [VendorStatus](VendorStatus) to filter the [Vendor](Vendor)s by with
+        /// `.add_status()`.
+        #[builder(each = "add_status")]
+        status: Option<Vec<VendorStatus>>,
+    ))]
+    filter: Option<SearchVendorsFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(flatten(
+        #[builder(skip, preset(
+            sort_by_name = "SearchVendorsSortField::Name",
+            sort_by_created_at = "SearchVendorsSortField::CreatedAt",
+        ))]
+        field: Option<SearchVendorsSortField>,
+        #[builder(skip, preset(sort_ascending = "SortOrder::Asc", sort_descending = "SortOrder::Desc"))]
+        order: Option<SortOrder>,
+    ))]
+    sort: Option<SearchVendorsSort>,
+}
+
+#[cfg(test)]
+mod test_vendors {
+    use super::*;
+    use crate::builder::BackIntoBuilder;
+
+    #[test]
+    fn test_vendor_wrapper_builder() {
+        let expected = VendorWrapper {
+            idempotency_key: None,
+            vendor: Vendor {
+                id: None,
+                account_number: Some("4025391".to_string()),
+                address: None,
+                contacts: Some(vec![VendorContact {
+                    id: None,
+                    email_address: Some("joe@joesfreshseafood.com".to_string()),
+                    name: Some("Joe Burrow".to_string()),
+                    ordinal: Some(1),
+                    phone_number: None,
+                    removed: None
+                }]),
+                created_at: None,
+                name: Some("Joe's Fresh Seafood".to_string()),
+                note: None,
+                status: Some(VendorStatus::Active),
+                updated_at: None,
+                version: None
+            }
+        };
+
+        let mut actual = Builder::from(VendorWrapper::default())
+            .name("Joe's Fresh Seafood".to_string())
+            .account_number("4025391".to_string())
+            .add_contact(VendorContact {
+                id: None,
+                email_address: Some("joe@joesfreshseafood.com".to_string()),
+                name: Some("Joe Burrow".to_string()),
+                ordinal: Some(1),
+                phone_number: None,
+                removed: None
+            })
+            .active()
+            .build_sync()
+            .unwrap();
+
+        assert!(actual.idempotency_key.is_some());
+
+        actual.idempotency_key = None;
+
+        assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
+    }
+
+    #[test]
+    fn test_vendor_wrapper_builder_fail() {
+        let res = Builder::from(VendorWrapper::default())
+            .note("no name or id".to_string())
+            .build_sync();
+
+        assert!(res.is_err())
+    }
+
+    #[test]
+    fn test_bulk_update_vendors_body_builder() {
+        let actual = Builder::from(BulkUpdateVendorsBody::default())
+            .sub_builder_from(VendorWrapper::default())
+            .id("INV_V_JDKYHBWT1D4F8MFH63DBMEN8Y4".to_string())
+            .version(1)
+            .inactive()
+            .into_parent_builder()
+            .unwrap()
+            .build_sync()
+            .unwrap();
+
+        assert!(actual.vendors.contains_key("INV_V_JDKYHBWT1D4F8MFH63DBMEN8Y4"))
+    }
+
+    #[test]
+    fn test_bulk_update_vendors_body_vendor_builder() {
+        let actual = Builder::from(BulkUpdateVendorsBody::default())
+            .vendor_builder("INV_V_JDKYHBWT1D4F8MFH63DBMEN8Y4".to_string())
+            .version(1)
+            .inactive()
+            .into_parent_builder()
+            .unwrap()
+            .build_sync()
+            .unwrap();
+
+        assert!(actual.vendors.contains_key("INV_V_JDKYHBWT1D4F8MFH63DBMEN8Y4"))
+    }
+
+    #[test]
+    fn test_bulk_update_vendors_body_builder_fail() {
+        let res = Builder::from(BulkUpdateVendorsBody::default())
+            .sub_builder_from(VendorWrapper::default())
+            .name("no id".to_string())
+            .into_parent_builder()
+            .unwrap()
+            .build_sync();
+
+        let errors = res.unwrap_err();
+        assert_eq!(errors.violations().len(), 1);
+        assert_eq!(errors.violations()[0].path.to_string(), "vendors[0].vendor.id");
+    }
+
+    #[test]
+    fn test_search_vendors_query_builder() {
+        let expected = SearchVendorsQuery {
+            cursor: None,
+            filter: Some(SearchVendorsFilter {
+                name: Some(vec!["Joe's Fresh Seafood".to_string()]),
+                status: Some(vec![VendorStatus::Active])
+            }),
+            sort: Some(SearchVendorsSort {
+                field: Some(SearchVendorsSortField::CreatedAt),
+                order: Some(SortOrder::Asc)
+            })
+        };
+
+        let actual = Builder::from(SearchVendorsQuery::default())
+            .add_name("Joe's Fresh Seafood".to_string())
+            .add_status(VendorStatus::Active)
+            .sort_by_created_at()
+            .sort_ascending()
+            .build_sync()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
+    }
+
+    #[tokio::test]
+    async fn test_search_vendors() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let input = Builder::from(SearchVendorsQuery::default())
+            .add_status(VendorStatus::Active)
+            .build_sync()
+            .unwrap();
+
+        let res = sut.vendors()
+            .search(input)
+            .await;
+
+        assert!(res.is_ok())
+    }
+}
@@ -0,0 +1,508 @@
+/*!
+Webhook Subscriptions functionality of the [Square API](https://developer.squareup.com).
+ */
+
+use crate::api::{query_pairs, SquareAPI, Verb};
+use crate::builder::SquareBuilder;
+use crate::client::SquareClient;
+use crate::errors::{SquareError, ValidationError, ViolationReason};
+use crate::objects::WebhookSubscription;
+use crate::objects::enums::SortOrder;
+use crate::response::SquareResponse;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+impl SquareClient {
+    /// Returns a [WebhookSubscriptions](WebhookSubscriptions) object through which you can make
+    /// calls specifically to the Webhook Subscriptions endpoint of the
+    /// [Square API](https://developer.squareup.com).
+    /// # Example: List all webhook subscriptions.
+    /// ```rust
+    /// use square_ox::{
+    ///         response::{SquareResponse, ResponseError},
+    ///         client::SquareClient,
+    ///     };
+    ///
+    /// async {
+    ///     let subscriptions = SquareClient::new("some_token")
+    ///         .webhook_subscriptions()
+    ///         .list(None)
+    ///         .await;
+    ///     };
+    /// ```
+    pub fn webhook_subscriptions(&self) -> WebhookSubscriptions<'_> {
+        WebhookSubscriptions {
+            client: self,
+        }
+    }
+}
+
+/// Allows you to make calls to the [Square API](https://developer.squareup.com) at the Webhook
+/// Subscriptions endpoint with all currently implemented methods.
+pub struct WebhookSubscriptions<'a> {
+    client: &'a SquareClient,
+}
+
+impl<'a> WebhookSubscriptions<'a> {
+    /// Lists all webhook event types that can be subscribed to.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/webhook-subscriptions/list-webhook-event-types)
+    ///
+    /// # Arguments
+    /// * `api_version` - The API version for which to list event types. Setting this field
+    ///   overrides the default version used by the application.
+    pub async fn list_event_types(self, api_version: Option<String>)
+                                  -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::Webhooks("/event-types".to_string()),
+            None::<&WebhookSubscription>,
+            query_pairs(Some(&ListEventTypesParams { api_version }))?,
+        ).await
+    }
+
+    /// Lists all [WebhookSubscription](WebhookSubscription)s owned by your application.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/webhook-subscriptions/list-webhook-subscriptions)
+    ///
+    /// # Arguments
+    /// * `parameters` - The [ListWebhookSubscriptionsParams](ListWebhookSubscriptionsParams)
+    ///   created through the
+    ///   [ListWebhookSubscriptionsParametersBuilder](ListWebhookSubscriptionsParametersBuilder)
+    pub async fn list(self, parameters: Option<ListWebhookSubscriptionsParams>)
+                      -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::Webhooks("/subscriptions".to_string()),
+            None::<&WebhookSubscription>,
+            query_pairs(parameters.as_ref())?,
+        ).await
+    }
+
+    /// Creates a [WebhookSubscription](WebhookSubscription).
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/webhook-subscriptions/create-webhook-subscription)
+    ///
+    /// # Arguments
+    /// * `subscription` - A [WebhookSubscription](WebhookSubscription) wrapped in a
+    ///   [WebhookSubscriptionCreationWrapper](WebhookSubscriptionCreationWrapper).
+    ///
+    /// # Example
+    /// ```rust
+    /// use square_ox::{
+    ///     client::SquareClient,
+    ///     api::webhook_subscriptions::WebhookSubscriptionCreationWrapper,
+    ///     builder::Builder
+    /// };
+    ///
+    /// async {
+    ///     let subscription = Builder::from(WebhookSubscriptionCreationWrapper::default())
+    ///         .name("Example Webhook Subscription".to_string())
+    ///         .notification_url("https://example-webhook-url.com".to_string())
+    ///         .add_event_type("payment.created".to_string())
+    ///         .build_sync()
+    ///         .unwrap();
+    ///
+    ///     let res = SquareClient::new("some_token")
+    ///         .webhook_subscriptions()
+    ///         .create(subscription)
+    ///         .await;
+    /// };
+    /// ```
+    pub async fn create(self, subscription: WebhookSubscriptionCreationWrapper)
+                        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Webhooks("/subscriptions".to_string()),
+            Some(&subscription),
+            None,
+        ).await
+    }
+
+    /// Retrieves a [WebhookSubscription](WebhookSubscription) using its id.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/webhook-subscriptions/retrieve-webhook-subscription)
+    ///
+    /// # Arguments
+    /// * `subscription_id` - The ID of the [WebhookSubscription](WebhookSubscription) to retrieve.
+    pub async fn retrieve(self, subscription_id: String)
+                          -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::GET,
+            SquareAPI::Webhooks(format!("/subscriptions/{}", subscription_id)),
+            None::<&WebhookSubscription>,
+            None,
+        ).await
+    }
+
+    /// Updates a [WebhookSubscription](WebhookSubscription).
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/webhook-subscriptions/update-webhook-subscription)
+    ///
+    /// # Arguments
+    /// * `subscription_id` - The ID of the [WebhookSubscription](WebhookSubscription) to update.
+    /// * `subscription` - A [WebhookSubscriptionUpdateWrapper](WebhookSubscriptionUpdateWrapper)
+    ///   holding the fields to update.
+    pub async fn update(self, subscription_id: String, subscription: WebhookSubscriptionUpdateWrapper)
+                        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::PUT,
+            SquareAPI::Webhooks(format!("/subscriptions/{}", subscription_id)),
+            Some(&subscription),
+            None,
+        ).await
+    }
+
+    /// Deletes a [WebhookSubscription](WebhookSubscription).
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/webhook-subscriptions/delete-webhook-subscription)
+    ///
+    /// # Arguments
+    /// * `subscription_id` - The ID of the [WebhookSubscription](WebhookSubscription) to delete.
+    pub async fn delete(self, subscription_id: String)
+                        -> Result<SquareResponse, SquareError> {
+        self.client.request(
+            Verb::DELETE,
+            SquareAPI::Webhooks(format!("/subscriptions/{}", subscription_id)),
+            None::<&WebhookSubscription>,
+            None,
+        ).await
+    }
+
+    /// Updates a [WebhookSubscription](WebhookSubscription) by replacing the existing signature
+    /// key with a new one.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/webhook-subscriptions/update-webhook-subscription-signature-key)
+    ///
+    /// # Arguments
+    /// * `subscription_id` - The ID of the [WebhookSubscription](WebhookSubscription) to update.
+    pub async fn update_signature_key(self, subscription_id: String)
+                                      -> Result<SquareResponse, SquareError> {
+        let body = UpdateSignatureKeyBody {
+            idempotency_key: Uuid::new_v4().to_string(),
+        };
+
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Webhooks(format!("/subscriptions/{}/signature-key", subscription_id)),
+            Some(&body),
+            None,
+        ).await
+    }
+
+    /// Tests a [WebhookSubscription](WebhookSubscription) by sending a test event to the
+    /// notification URL.
+    /// [Open in API Reference](https://developer.squareup.com/reference/square/webhook-subscriptions/test-webhook-subscription)
+    ///
+    /// # Arguments
+    /// * `subscription_id` - The ID of the [WebhookSubscription](WebhookSubscription) to test.
+    /// * `event_type` - The event type that will be used to test the
+    ///   [WebhookSubscription](WebhookSubscription). The event type must be contained in the list
+    ///   of event types in the [WebhookSubscription](WebhookSubscription).
+    pub async fn test(self, subscription_id: String, event_type: Option<String>)
+                      -> Result<SquareResponse, SquareError> {
+        let body = TestSubscriptionBody {
+            event_type,
+        };
+
+        self.client.request(
+            Verb::POST,
+            SquareAPI::Webhooks(format!("/subscriptions/{}/test", subscription_id)),
+            Some(&body),
+            None,
+        ).await
+    }
+}
+
+#[derive(Serialize)]
+struct UpdateSignatureKeyBody {
+    idempotency_key: String,
+}
+
+#[derive(Serialize)]
+struct TestSubscriptionBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_type: Option<String>,
+}
+
+// the query parameters of WebhookSubscriptions::list_event_types
+#[derive(Serialize)]
+struct ListEventTypesParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_version: Option<String>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// ListWebhookSubscriptionsParametersBuilder implementation
+// -------------------------------------------------------------------------------------------------
+#[derive(Default)]
+pub struct ListWebhookSubscriptionsParametersBuilder {
+    cursor: Option<String>,
+    include_disabled: Option<bool>,
+    sort_order: Option<SortOrder>,
+    limit: Option<i32>,
+}
+
+impl ListWebhookSubscriptionsParametersBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A pagination cursor returned by a previous call to this endpoint.
+    pub fn cursor(mut self, cursor: String) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+
+    /// Includes disabled subscriptions. By default, all enabled subscriptions are returned.
+    pub fn include_disabled(mut self) -> Self {
+        self.include_disabled = Some(true);
+
+        self
+    }
+
+    /// Sorts the returned list by when the subscription was created.
+    pub fn sort_ascending(mut self) -> Self {
+        self.sort_order = Some(SortOrder::Asc);
+
+        self
+    }
+
+    /// Sorts the returned list by when the subscription was created.
+    pub fn sort_descending(mut self) -> Self {
+        self.sort_order = Some(SortOrder::Desc);
+
+        self
+    }
+
+    /// The maximum number of results to be returned in a single page (100 max).
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    pub fn build_sync(self) -> ListWebhookSubscriptionsParams {
+        let ListWebhookSubscriptionsParametersBuilder {
+            cursor,
+            include_disabled,
+            sort_order,
+            limit,
+        } = self;
+
+        ListWebhookSubscriptionsParams {
+            cursor,
+            include_disabled,
+            sort_order,
+            limit,
+        }
+    }
+
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
+    pub async fn build(self) -> ListWebhookSubscriptionsParams {
+        self.build_sync()
+    }
+}
+
+/// The query parameters of [WebhookSubscriptions::list](WebhookSubscriptions::list), built
+/// through the [ListWebhookSubscriptionsParametersBuilder](ListWebhookSubscriptionsParametersBuilder).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListWebhookSubscriptionsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_disabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_order: Option<SortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i32>,
+}
+
+// -------------------------------------------------------------------------------------------------
+// WebhookSubscriptionCreationWrapper builder implementation
+// -------------------------------------------------------------------------------------------------
+/// Build a wrapper around a [WebhookSubscription](WebhookSubscription) for the create request.
+///
+/// A [WebhookSubscription](WebhookSubscription) must have a notification url and at least one
+/// event type upon creation, otherwise it is not seen as a valid new subscription.
+/// * `.notification_url()`
+/// * `.add_event_type()` or `.event_types()`
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "new_subscription")]
+pub struct WebhookSubscriptionCreationWrapper {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[idempotency_key]
+    idempotency_key: Option<String>,
+    #[builder(flatten(
+        name: Option<String>,
+        enabled: Option<bool>,
+        notification_url: Option<String>,
+        /// The API version of the subscription. If not set, the application's current API version
+        /// is used.
+        api_version: Option<String>,
+        /// Set all event types at once, or add an individual event type, e.g. `payment.created`,
+        /// with `.add_event_type()`.
+        event_types: Option<Vec<String>>,
+    ))]
+    subscription: WebhookSubscription,
+}
+
+fn new_subscription(body: &WebhookSubscriptionCreationWrapper, errors: &mut ValidationError) {
+    let has_event_types = body.subscription.event_types.as_ref()
+        .is_some_and(|event_types| !event_types.is_empty());
+
+    errors.require("subscription.notification_url", &body.subscription.notification_url);
+    errors.check(has_event_types, "subscription.event_types", ViolationReason::Empty);
+}
+
+// -------------------------------------------------------------------------------------------------
+// WebhookSubscriptionUpdateWrapper builder implementation
+// -------------------------------------------------------------------------------------------------
+/// Build a wrapper around a [WebhookSubscription](WebhookSubscription) for the update request.
+///
+/// The [WebhookSubscription](WebhookSubscription) holding the fields to update is built through
+/// a sub builder.
+/// # Example: Disable a subscription
+/// ```
+/// use square_ox::{
+///     builder::{Builder, BackIntoBuilder},
+///     api::webhook_subscriptions::WebhookSubscriptionUpdateWrapper,
+///     objects::WebhookSubscription,
+/// };
+///
+/// let update = Builder::from(WebhookSubscriptionUpdateWrapper::default())
+///     .sub_builder_from(WebhookSubscription::default())
+///     .enabled(false)
+///     .into_parent_builder()
+///     .unwrap()
+///     .build_sync();
+/// ```
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+pub struct WebhookSubscriptionUpdateWrapper {
+    #[required]
+    #[sub_builder]
+    subscription: Option<WebhookSubscription>,
+}
+
+#[cfg(test)]
+mod test_webhook_subscriptions {
+    use super::*;
+    use crate::builder::{BackIntoBuilder, Builder};
+
+    #[test]
+    fn test_list_webhook_subscriptions_parameters_builder() {
+        let expected = vec![
+            ("include_disabled".to_string(), "true".to_string()),
+            ("sort_order".to_string(), "ASC".to_string()),
+            ("limit".to_string(), "10".to_string()),
+        ];
+
+        let actual = ListWebhookSubscriptionsParametersBuilder::new()
+            .sort_ascending()
+            .limit(10)
+            .include_disabled()
+            .build_sync();
+
+        assert_eq!(Some(expected), query_pairs(Some(&actual)).unwrap())
+    }
+
+    #[test]
+    fn test_webhook_subscription_creation_wrapper_builder() {
+        let expected = WebhookSubscriptionCreationWrapper {
+            idempotency_key: None,
+            subscription: WebhookSubscription {
+                id: None,
+                api_version: None,
+                created_at: None,
+                enabled: None,
+                event_types: Some(vec!["payment.created".to_string()]),
+                name: Some("Example Webhook Subscription".to_string()),
+                notification_url: Some("https://example-webhook-url.com".to_string()),
+                signature_key: None,
+                updated_at: None
+            }
+        };
+
+        let mut actual = Builder::from(WebhookSubscriptionCreationWrapper::default())
+            .name("Example Webhook Subscription".to_string())
+            .notification_url("https://example-webhook-url.com".to_string())
+            .add_event_type("payment.created".to_string())
+            .build_sync()
+            .unwrap();
+
+        assert!(actual.idempotency_key.is_some());
+
+        actual.idempotency_key = None;
+
+        assert_eq!(format!("{:?}", expected), format!("{:?}", actual))
+    }
+
+    #[test]
+    fn test_webhook_subscription_creation_wrapper_builder_fail() {
+        let res = Builder::from(WebhookSubscriptionCreationWrapper::default())
+            .notification_url("https://example-webhook-url.com".to_string())
+            .build_sync();
+
+        assert!(res.is_err())
+    }
+
+    #[test]
+    fn test_webhook_subscription_update_wrapper_builder() {
+        let actual = Builder::from(WebhookSubscriptionUpdateWrapper::default())
+            .sub_builder_from(WebhookSubscription::default())
+            .enabled(false)
+            .into_parent_builder()
+            .unwrap()
+            .build_sync()
+            .unwrap();
+
+        assert_eq!(actual.subscription.unwrap().enabled, Some(false))
+    }
+
+    #[test]
+    fn test_webhook_subscription_update_wrapper_builder_violations() {
+        let actual = Builder::from(WebhookSubscriptionUpdateWrapper::default())
+            .sub_builder_from(WebhookSubscription::default())
+            .add_event_type("payment.created")
+            .add_event_type("payment.updated")
+            .into_parent_builder()
+            .unwrap()
+            .build_sync()
+            .unwrap();
+
+        assert_eq!(
+            actual.subscription.unwrap().event_types,
+            Some(vec!["payment.created".to_string(), "payment.updated".to_string()])
+        );
+
+        let error = Builder::from(WebhookSubscriptionUpdateWrapper::default())
+            .sub_builder_from(WebhookSubscription::default())
+            .into_parent_builder()
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "could not build WebhookSubscription: subscription: one of api_version, enabled, \
+             event_types, name, notification_url required"
+        );
+
+        let error = Builder::from(WebhookSubscriptionUpdateWrapper::default())
+            .build_sync()
+            .unwrap_err();
+
+        assert_eq!(error.to_string(),
+                   "could not build WebhookSubscriptionUpdateWrapper: subscription: required");
+    }
+
+    #[tokio::test]
+    async fn test_list_webhook_subscriptions() {
+        use dotenv::dotenv;
+        use std::env;
+
+        dotenv().ok();
+        let access_token = env::var("ACCESS_TOKEN").expect("ACCESS_TOKEN to be set");
+        let sut = SquareClient::new(&access_token);
+
+        let res = sut.webhook_subscriptions()
+            .list(None)
+            .await;
+
+        assert!(res.is_ok())
+    }
+}
@@ -1,81 +1,30 @@
 use super::*;
-use crate::objects::{TimeRange, DeviceCheckoutOptions, Money, Order, OrderLineItem, OrderServiceCharge, SearchOrdersFilter, SearchOrdersQuery, SearchOrdersSort, TerminalCheckoutQuery, TerminalCheckoutQueryFilter, TerminalCheckoutQuerySort, TerminalRefundQuery, TerminalRefundQueryFilter, TipSettings, InventoryChange, InventoryPhysicalCount, InventoryAdjustment, InventoryTransfer};
-use crate::objects::enums::{InventoryChangeType, OrderServiceChargeCalculationPhase, SearchOrdersSortField, SortOrder, TerminalCheckoutStatus};
-
-// -------------------------------------------------------------------------------------------------
-// OrderServiceCharge builder implementation
-// -------------------------------------------------------------------------------------------------
-impl Validate for OrderServiceCharge {
-    fn validate(self) -> Result<Self, ValidationError> {
-        println!("{:?}", &self);
-        if self.amount_money.is_some() &&
-            self.name.is_some() &&
-            self.calculation_phase.is_some() {
-            println!("no error");
-            Ok(self)
-        } else {
-            println!("error");
-            Err(ValidationError)
-        }
-    }
-}
-
-impl<T: ParentBuilder> Builder<OrderServiceCharge, T> {
-    pub fn amount_money(mut self, amount: Money) -> Self {
-        self.body.amount_money = Some(amount);
-
-        self
-    }
-
-    pub fn total_phase(mut self)
-        -> Self {
-        self.body.calculation_phase = Some(OrderServiceChargeCalculationPhase::TotalPhase);
-
-        self
-    }
-
-    pub fn subtotal_phase(mut self)
-        -> Self {
-        self.body.calculation_phase = Some(OrderServiceChargeCalculationPhase::SubtotalPhase);
-
-        self
-    }
-
-    pub fn not_taxable(mut self)-> Self {
-        self.body.taxable = Some(false);
-
-        self
-    }
-
-    pub fn taxable(mut self) -> Self {
-        self.body.taxable = Some(true);
-
-        self
-    }
-
-    pub fn name(mut self, name: String) -> Self {
-        self.body.name = Some(name);
-
-        self
-    }
-}
+use crate::errors::ViolationReason;
+use crate::objects::{Money, Order, OrderFulfillment, OrderFulfillmentPickupDetails, OrderFulfillmentShipmentDetails, OrderLineItem, OrderLineItemAppliedDiscount, OrderLineItemAppliedTax, OrderLineItemDiscount, OrderLineItemTax, SearchOrdersQuery, SearchOrdersSort, InventoryChange, InventoryAdjustment};
+use crate::objects::enums::{InventoryChangeType, InventoryState, OrderFulfillmentType, OrderLineItemDiscountType, SearchOrdersSortField, SortOrder};
 
 // -------------------------------------------------------------------------------------------------
 // SearchOrdersQuery builder implementation
 // -------------------------------------------------------------------------------------------------
-impl Validate for SearchOrdersQuery {
-    fn validate(self) -> Result<Self, ValidationError> {
-        Ok(self)
+// the time ranges of the date time filter have to hold timestamps that Square accepts
+pub(crate) fn date_time_filter(query: &SearchOrdersQuery, errors: &mut ValidationError) {
+    let filter = query.filter.as_ref().and_then(|filter| filter.date_time_filter.as_ref());
+
+    if let Some(filter) = filter {
+        let ranges = [
+            ("filter.date_time_filter.closed_at", &filter.closed_at),
+            ("filter.date_time_filter.created_at", &filter.created_at),
+            ("filter.date_time_filter.updated_at", &filter.updated_at),
+        ];
+        for (path, range) in ranges.iter() {
+            if let Some(range) = range {
+                range.check(*path, errors);
+            }
+        }
     }
 }
 
 impl<T: ParentBuilder> Builder<SearchOrdersQuery, T> {
-    pub fn filter(mut self, filter: SearchOrdersFilter) -> Self {
-        self.body.filter = Some(filter);
-
-        self
-    }
-
     pub fn sort_ascending(mut self) -> Self {
         match self.body.sort.as_mut() {
             Some(sort) => sort.sort_order= Some(SortOrder::Asc),
@@ -115,332 +64,170 @@ impl<T: ParentBuilder> Builder<SearchOrdersQuery, T> {
 // -------------------------------------------------------------------------------------------------
 // Order builder implementation
 // -------------------------------------------------------------------------------------------------
-impl Validate for Order {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.location_id.is_some(){
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
-    }
-}
-
 impl<T: ParentBuilder> Builder<Order, T> {
-    pub fn location_id(mut self, location_id: String) -> Self {
-        self.body.location_id = Some(location_id);
-
-        self
-    }
-
-    pub fn version(mut self, version: i64) -> Self {
-        self.body.version = Some(version);
-
-        self
-    }
-
-    pub fn add_service_charge(mut self, service_charge: OrderServiceCharge) -> Self {
-        if let Some(service_charges) = self.body.service_charges.as_mut() {
-            service_charges.push(service_charge);
-        } else {
-            self.body.service_charges = Some(vec![service_charge])
-        };
-
-        self
-    }
-
     pub fn add_order_item(mut self, order_item: OrderLineItem) -> Self {
-        if let Some(line_items) = self.body.line_items.as_mut() {
-            line_items.push(order_item);
-        } else {
-            self.body.line_items = Some(vec![order_item])
-        };
+        self.body.line_items.get_or_insert_with(Vec::new).push(order_item);
 
         self
     }
 }
 
-impl AddField<OrderServiceCharge> for Order {
-    fn add_field(&mut self, field: OrderServiceCharge) {
-        if let Some(service_charges) = self.service_charges.as_mut() {
-            service_charges.push(field);
-        } else {
-            self.service_charges = Some(vec![field]);
-        }
-    }
-}
-
-impl AddField<OrderLineItem> for Order {
-    fn add_field(&mut self, field: OrderLineItem) {
-        if let Some(line_items) = self.line_items.as_mut() {
-            line_items.push(field);
-        } else {
-            self.line_items = Some(vec![field]);
-        }
-    }
-}
-
 // -------------------------------------------------------------------------------------------------
-// DeviceCheckoutOptions builder implementation
+// OrderLineItem builder implementation
 // -------------------------------------------------------------------------------------------------
-impl Validate for DeviceCheckoutOptions {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        if self.device_id.is_some() {
-            Ok(self)
-        } else {
-            Err(ValidationError)
-        }
+// a line item either references a catalog item variation or is an ad hoc item that needs a name
+// and a price
+pub(crate) fn ad_hoc_line_item(line_item: &OrderLineItem, errors: &mut ValidationError) {
+    if line_item.catalog_object_id.is_none() && line_item.name.is_some() {
+        errors.require("base_price_money", &line_item.base_price_money);
     }
 }
 
-impl<T: ParentBuilder> Builder<DeviceCheckoutOptions, T> {
-    pub fn device_id(mut self, device_id: String) -> Self {
-        self.body.device_id = Some(device_id);
-
-        self
-    }
-
-    pub fn collect_signature(mut self) -> Self {
-        self.body.collect_signature = Some(true);
-
-        self
-    }
-
-    pub fn show_itemized_cart(mut self) -> Self {
-        self.body.show_itemized_cart = Some(true);
+impl<T: ParentBuilder> Builder<OrderLineItem, T> {
+    /// Apply a line item scoped [OrderLineItemTax](OrderLineItemTax) by its uid.
+    pub fn add_applied_tax(mut self, tax_uid: String) -> Self {
+        self.body.applied_taxes.get_or_insert_with(Vec::new).push(OrderLineItemAppliedTax {
+            tax_uid,
+            applied_money: None,
+            uid: None,
+        });
 
         self
     }
 
-    pub fn skip_receipt_screen(mut self) -> Self {
-        self.body.skip_receipt_screen = Some(true);
-
-        self
-    }
-
-    pub fn tip_settings(mut self, tip_settings: TipSettings) -> Self {
-        self.body.tip_settings = Some(tip_settings);
+    /// Apply a line item scoped [OrderLineItemDiscount](OrderLineItemDiscount) by its uid.
+    pub fn add_applied_discount(mut self, discount_uid: String) -> Self {
+        self.body.applied_discounts.get_or_insert_with(Vec::new).push(OrderLineItemAppliedDiscount {
+            discount_uid,
+            applied_money: None,
+            uid: None,
+        });
 
         self
     }
 }
 
 // -------------------------------------------------------------------------------------------------
-// TerminalCheckoutQuery builder implementation
+// OrderLineItemTax builder implementation
 // -------------------------------------------------------------------------------------------------
-impl Validate for TerminalCheckoutQuery {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        Ok(self)
+// a tax either references a catalog tax or is an ad hoc tax with a name and a percentage
+pub(crate) fn ad_hoc_tax(tax: &OrderLineItemTax, errors: &mut ValidationError) {
+    if tax.catalog_object_id.is_none() && tax.name.is_some() {
+        errors.require("percentage", &tax.percentage);
     }
 }
 
-impl<T: ParentBuilder> Builder<TerminalCheckoutQuery, T> {
-    pub fn sort_ascending(mut self) -> Self {
-        self.body.sort = Some(TerminalCheckoutQuerySort { sort_order: Some(SortOrder::Asc) });
-
-        self
-    }
-
-    pub fn sort_descending(mut self) -> Self {
-        self.body.sort = Some(TerminalCheckoutQuerySort { sort_order: Some(SortOrder::Desc) });
-
-        self
-    }
-
-    pub fn created_at(mut self, created_at: TimeRange) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.created_at = Some(created_at);
-        } else {
-            self.body.filter = Some(TerminalCheckoutQueryFilter {
-                created_at: Some(created_at),
-                device_id: None,
-                status: None
-            })
-        };
-
-        self
+// -------------------------------------------------------------------------------------------------
+// OrderLineItemDiscount builder implementation
+// -------------------------------------------------------------------------------------------------
+// a discount either references a catalog discount or is an ad hoc discount with a name and a
+// percentage or an amount
+pub(crate) fn ad_hoc_discount(discount: &OrderLineItemDiscount, errors: &mut ValidationError) {
+    if discount.catalog_object_id.is_none() && discount.name.is_some() {
+        errors.require_one_of("", &[
+            ("percentage", discount.percentage.is_some()),
+            ("amount_money", discount.amount_money.is_some()),
+        ]);
     }
+}
 
-    pub fn device_id(mut self, device_id: String) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.device_id = Some(device_id);
-        } else {
-            self.body.filter = Some(TerminalCheckoutQueryFilter {
-                created_at: None,
-                device_id: Some(device_id),
-                status: None
-            })
-        };
+impl<T: ParentBuilder> Builder<OrderLineItemDiscount, T> {
+    /// A fixed percentage discount as a decimal string, e.g. `"10"`.
+    pub fn percentage(mut self, percentage: String) -> Self {
+        self.body.percentage = Some(percentage);
+        self.body.discount_type = Some(OrderLineItemDiscountType::FixedPercentage);
 
         self
     }
 
-    pub fn status(mut self, status: TerminalCheckoutStatus) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.status = Some(status);
-        } else {
-            self.body.filter = Some(TerminalCheckoutQueryFilter {
-                created_at: None,
-                device_id: None,
-                status: Some(status)
-            })
-        };
+    /// A fixed amount discount.
+    pub fn amount_money(mut self, amount_money: Money) -> Self {
+        self.body.amount_money = Some(amount_money);
+        self.body.discount_type = Some(OrderLineItemDiscountType::FixedAmount);
 
         self
     }
 }
 
 // -------------------------------------------------------------------------------------------------
-// SearchOrdersQuery builder implementation
+// OrderFulfillment builder implementation
 // -------------------------------------------------------------------------------------------------
-impl Validate for TerminalRefundQuery {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        Ok(self)
+// a fulfillment needs the details matching its type
+pub(crate) fn fulfillment_details(fulfillment: &OrderFulfillment, errors: &mut ValidationError) {
+    match fulfillment.fulfillment_type {
+        Some(OrderFulfillmentType::Pickup) => {
+            errors.require("pickup_details", &fulfillment.pickup_details)
+        },
+        Some(OrderFulfillmentType::Shipment) => {
+            errors.require("shipment_details", &fulfillment.shipment_details)
+        },
+        Some(OrderFulfillmentType::Delivery) => {},
+        Some(OrderFulfillmentType::Unknown(ref value)) => {
+            errors.invalid("type", format!("unknown fulfillment type {}", value))
+        },
+        None => errors.check(false, "type", ViolationReason::Required),
     }
 }
 
-impl<T: ParentBuilder> Builder<TerminalRefundQuery, T> {
-    pub fn created_at(mut self, created_at: TimeRange) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.created_at = Some(created_at)
-        } else {
-            self.body.filter = Some(TerminalRefundQueryFilter {
-                created_at: Some(created_at),
-                device_id: None,
-                status: None
-            })
-        }
-
-        self
-    }
-
-    pub fn device_id(mut self, device_id: String) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.device_id = Some(device_id)
-        } else {
-            self.body.filter = Some(TerminalRefundQueryFilter {
-                created_at: None,
-                device_id: Some(device_id),
-                status: None
-            })
-        }
-
-        self
-    }
-
-    pub fn pending(mut self) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.status = Some(TerminalCheckoutStatus::Pending)
-        } else {
-            self.body.filter = Some(TerminalRefundQueryFilter {
-                created_at: None,
-                device_id: None,
-                status: Some(TerminalCheckoutStatus::Pending)
-            })
-        }
-
-        self
-    }
-
-    pub fn in_progress(mut self) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.status = Some(TerminalCheckoutStatus::InProgress)
-        } else {
-            self.body.filter = Some(TerminalRefundQueryFilter {
-                created_at: None,
-                device_id: None,
-                status: Some(TerminalCheckoutStatus::InProgress)
-            })
-        }
-
-        self
-    }
-
-    pub fn cancel_requested(mut self) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.status = Some(TerminalCheckoutStatus::CancelRequested)
-        } else {
-            self.body.filter = Some(TerminalRefundQueryFilter {
-                created_at: None,
-                device_id: None,
-                status: Some(TerminalCheckoutStatus::CancelRequested)
-            })
-        }
+impl<T: ParentBuilder> Builder<OrderFulfillment, T> {
+    /// Make this a pickup fulfillment with the given details.
+    pub fn pickup_details(mut self, pickup_details: OrderFulfillmentPickupDetails) -> Self {
+        self.body.fulfillment_type = Some(OrderFulfillmentType::Pickup);
+        self.body.pickup_details = Some(pickup_details);
 
         self
     }
 
-    pub fn canceled(mut self) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.status = Some(TerminalCheckoutStatus::Canceled)
-        } else {
-            self.body.filter = Some(TerminalRefundQueryFilter {
-                created_at: None,
-                device_id: None,
-                status: Some(TerminalCheckoutStatus::Canceled)
-            })
-        }
+    /// Make this a shipment fulfillment with the given details.
+    pub fn shipment_details(mut self, shipment_details: OrderFulfillmentShipmentDetails) -> Self {
+        self.body.fulfillment_type = Some(OrderFulfillmentType::Shipment);
+        self.body.shipment_details = Some(shipment_details);
 
         self
     }
 
-    pub fn completed(mut self) -> Self {
-        if let Some(filter) = self.body.filter.as_mut() {
-            filter.status = Some(TerminalCheckoutStatus::Completed)
-        } else {
-            self.body.filter = Some(TerminalRefundQueryFilter {
-                created_at: None,
-                device_id: None,
-                status: Some(TerminalCheckoutStatus::Completed)
-            })
-        }
+    pub fn delivery(mut self) -> Self {
+        self.body.fulfillment_type = Some(OrderFulfillmentType::Delivery);
 
         self
     }
+}
 
-    pub fn sort_ascending(mut self) -> Self {
-        self.body.sort = Some(TerminalCheckoutQuerySort{ sort_order: Some(SortOrder::Asc) });
-
-        self
+// -------------------------------------------------------------------------------------------------
+// InventoryChange builder implementation
+// -------------------------------------------------------------------------------------------------
+impl AddField<InventoryAdjustment> for InventoryChange {
+    fn add_field(&mut self, field: InventoryAdjustment) {
+        self.inventory_change_type = InventoryChangeType::Adjustment;
+        self.adjustment = Some(field);
     }
 
-    pub fn sort_descending(mut self) -> Self {
-        self.body.sort = Some(TerminalCheckoutQuerySort{ sort_order: Some(SortOrder::Desc) });
-
-        self
+    fn field_path(&self) -> FieldPath {
+        FieldPath::from("adjustment")
     }
 }
 
 // -------------------------------------------------------------------------------------------------
-// SearchOrdersQuery builder implementation
+// InventoryAdjustment builder implementation
 // -------------------------------------------------------------------------------------------------
-impl Validate for InventoryChange {
-    fn validate(self) -> Result<Self, ValidationError> where Self: Sized {
-        Ok(self)
+// a vendor can only be referenced when the adjustment moves stock coming from a vendor
+pub(crate) fn vendor_adjustment(adjustment: &InventoryAdjustment, errors: &mut ValidationError) {
+    if let Some(vendor_id) = &adjustment.vendor_id {
+        let from_vendor = matches!(
+            adjustment.from_state,
+            Some(InventoryState::None) |
+            Some(InventoryState::OrderedFromVendor) |
+            Some(InventoryState::ReceivedFromVendor)
+        ) || matches!(
+            adjustment.to_state,
+            Some(InventoryState::OrderedFromVendor) |
+            Some(InventoryState::ReceivedFromVendor)
+        );
+
+        if vendor_id.is_empty() {
+            errors.check(false, "vendor_id", ViolationReason::Required);
+        } else if !from_vendor {
+            errors.invalid("vendor_id", "can only be set when stock moves from a vendor");
+        }
     }
 }
-
-impl<T: ParentBuilder> Builder<InventoryChange, T> {
-    pub fn change_type(mut self, change_type: InventoryChangeType) -> Self {
-        self.body.inventory_change_type = change_type;
-
-        self
-    }
-
-    pub fn physical_count(mut self, physical_count: InventoryPhysicalCount) -> Self {
-        self.body.physical_count = Some(physical_count);
-
-        self
-    }
-
-    pub fn adjustment(mut self, adjustment: InventoryAdjustment) -> Self {
-        self.body.adjustment = Some(adjustment);
-
-        self
-    }
-
-    pub fn transfer(mut self, transfer: InventoryTransfer) -> Self {
-        self.body.transfer = Some(transfer);
-
-        self
-    }
-}
\ No newline at end of file
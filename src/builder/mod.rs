@@ -1,8 +1,14 @@
-use crate::errors::{BuildError, ValidationError};
+use crate::errors::{BuildError, FieldPath, ValidationError};
 pub mod implementations;
+pub mod typestate;
+
+pub use square_ox_derive::SquareBuilder;
+
+use uuid::Uuid;
 
 // Any Object that is buildable implements this trait
 // When implemented it allows the use of the object with the Builder::from() method
+// Implementations return all the violations they find at once, with paths relative to the object.
 pub trait Validate {
     fn validate(self) -> Result<Self, ValidationError> where Self: Sized;
 }
@@ -12,6 +18,12 @@ pub trait Validate {
 // Allows the use of the .sub_builder_from() method.
 pub trait AddField<T> {
     fn add_field(&mut self, field: T);
+
+    // The path under which the next field of type T is added, used to locate the violations of a
+    // sub builder, e.g. line_items[2] when two line items have been added already.
+    fn field_path(&self) -> FieldPath {
+        FieldPath::new()
+    }
 }
 
 // This trait allows a builder to release a sub builder and allows that sub builder to add its field
@@ -28,7 +40,9 @@ pub struct Builder<T, U>
           U: ParentBuilder
 {
     pub(crate) body: T,
-    pub(crate) parent_builder: Option<U>
+    pub(crate) parent_builder: Option<U>,
+    // the path from the body of the outermost builder to this builder's body
+    pub(crate) path: FieldPath,
 }
 
 pub struct Nil;
@@ -43,12 +57,18 @@ impl ParentBuilder for Nil{}
 impl<T: Validate, U: ParentBuilder> ParentBuilder for Builder<T, U> {}
 
 // gives builders the ability to validate and build the objects they hold in their body field.
+// Building does no I/O, so build_sync needs no runtime.
 impl<T: Validate, U: ParentBuilder> Builder<T, U> {
+    pub fn build_sync(self) -> Result<T, BuildError> {
+        let path = self.path;
+
+        self.body.validate()
+            .map_err(|errors| BuildError::new::<T>(errors.prefixed(&path)))
+    }
+
+    #[deprecated(note = "building is synchronous, use `build_sync` instead")]
     pub async fn build(self) -> Result<T, BuildError> {
-        match self.body.validate() {
-            Ok(body) => Ok(body),
-            Err(_) => Err(BuildError)
-        }
+        self.build_sync()
     }
 }
 
@@ -61,7 +81,7 @@ impl<T: Validate, V: ParentBuilder + BackIntoBuilder<T, V>> Builder<T, V> {
             Ok(body) => {
                 Ok(self.parent_builder.unwrap().add_field(body))
             },
-            Err(_) => Err(BuildError)
+            Err(errors) => Err(BuildError::new::<T>(errors.prefixed(&self.path)))
         }
     }
 }
@@ -76,9 +96,12 @@ impl<V: AddField<T> + Validate, U: ParentBuilder, T: Validate> BackIntoBuilder<T
     }
 
     fn sub_builder_from(self, body: T) -> Builder<T, Builder<V, U>> {
+        let path = self.path.join(&AddField::<T>::field_path(&self.body));
+
         Builder {
             body,
             parent_builder: Some(self),
+            path,
         }
     }
 }
@@ -89,7 +112,14 @@ impl<T: Validate> From<T> for Builder<T, Nil> {
     fn from(body: T) -> Self {
         Builder {
             body,
-            parent_builder: None::<Nil>
+            parent_builder: None::<Nil>,
+            path: FieldPath::new(),
         }
     }
-}
\ No newline at end of file
+}
+// A fresh idempotency key, set by the Validate implementations generated for fields marked with
+// #[idempotency_key].
+#[doc(hidden)]
+pub fn new_idempotency_key() -> String {
+    Uuid::new_v4().to_string()
+}
@@ -0,0 +1,97 @@
+/*!
+Builders that check at compile time that all required fields have been set.
+
+A [TypedBuilder](TypedBuilder) wraps a [Builder](Builder) and tracks in its `S` type parameter
+which of the required fields of the object have been set, one [Missing](Missing) or
+[Present](Present) marker per field. The setters of the required fields change the marker of their
+field to [Present](Present), and `.build()` is only implemented once all markers are
+[Present](Present), so that forgetting a required field is a compile error instead of a
+[BuildError](crate::errors::BuildError) at runtime. All other fields are set through the
+[with](TypedBuilder::with) method, which gives access to the setters of the runtime-validated
+[Builder](Builder).
+
+The setters handed out by [with](TypedBuilder::with) can still overwrite a required field, and the
+rules that depend on the values of the fields, such as a valid timestamp, can only be checked at
+runtime, so `.build()` still returns a [BuildError](crate::errors::BuildError) when the object is
+invalid.
+
+# Example: Build a payment request
+```rust
+use square_ox::{
+    api::payment::PaymentRequest,
+    objects::enums::Currency,
+};
+
+let payment = PaymentRequest::builder()
+    .source_id("cnon:card-nonce-ok".to_string())
+    .amount(1000, Currency::USD)
+    .with(|builder| builder.verification_token("some_token".to_string()))
+    .build()
+    .unwrap();
+```
+
+Leaving out `.amount()` fails to compile:
+```compile_fail
+use square_ox::api::payment::PaymentRequest;
+
+let payment = PaymentRequest::builder()
+    .source_id("cnon:card-nonce-ok".to_string())
+    .build();
+```
+ */
+
+use crate::builder::{Builder, Nil, Validate};
+use crate::errors::BuildError;
+
+use std::marker::PhantomData;
+
+/// Marks a required field that has not been set yet.
+pub struct Missing;
+
+/// Marks a required field that has been set.
+pub struct Present;
+
+/// A builder of the object `T` that tracks the required fields set so far in `S`.
+pub struct TypedBuilder<T: Validate, S> {
+    builder: Builder<T, Nil>,
+    state: PhantomData<S>,
+}
+
+impl<T: Validate + Default, S> TypedBuilder<T, S> {
+    pub(crate) fn new() -> Self {
+        TypedBuilder {
+            builder: Builder::from(T::default()),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<T: Validate, S> TypedBuilder<T, S> {
+    /// Sets optional fields through the setters of the runtime-validated [Builder](Builder).
+    pub fn with(self, set: impl FnOnce(Builder<T, Nil>) -> Builder<T, Nil>) -> Self {
+        TypedBuilder {
+            builder: set(self.builder),
+            state: PhantomData,
+        }
+    }
+
+    /// Turns this builder into a runtime-validated [Builder](Builder), e.g. to keep building it
+    /// dynamically.
+    pub fn into_builder(self) -> Builder<T, Nil> {
+        self.builder
+    }
+
+    // applies a setter of a required field and moves the builder into the state N
+    pub(crate) fn set<N>(self, set: impl FnOnce(Builder<T, Nil>) -> Builder<T, Nil>)
+                         -> TypedBuilder<T, N> {
+        TypedBuilder {
+            builder: set(self.builder),
+            state: PhantomData,
+        }
+    }
+
+    // builds the object, only to be called from states in which all required fields are set
+    pub(crate) fn finish(self) -> Result<T, BuildError> {
+        self.builder.build_sync()
+    }
+}
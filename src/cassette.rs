@@ -0,0 +1,496 @@
+/*!
+Record-and-replay of the HTTP interactions of a [SquareClient](crate::client::SquareClient),
+for writing deterministic tests.
+
+A [Cassette](Cassette) in record mode lets the requests through to the
+[Square API](https://developer.squareup.com) and writes every request/response pair to a JSON
+file. A [Cassette](Cassette) in replay mode serves the recorded responses back without touching
+the network, matching the requests on their verb, path, query and normalized body.
+
+Before anything is written to disk the access token of the client and all bearer tokens are
+removed, and the values of fields holding personal data, such as `email_address`, `note` or the
+`last_4` of a card, are replaced with `REDACTED`, or with `0` if they are numbers. Idempotency keys are left out of the recorded
+requests, as they are different on every run.
+
+# Example: Record the interactions once, and replay them in every following run
+```rust
+use square_ox::{
+    cassette::Cassette,
+    client::SquareClient,
+};
+use std::path::Path;
+
+async {
+    let path = Path::new("tests/cassettes/list_locations.json");
+    let cassette = if path.exists() {
+        Cassette::replay(path).unwrap()
+    } else {
+        Cassette::record(path)
+    };
+
+    let res = SquareClient::new("some_token")
+        .cassette(cassette)
+        .locations()
+        .list()
+        .await;
+};
+```
+ */
+
+use crate::api::Verb;
+use crate::errors::SquareError;
+use crate::response::ResponseError;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// The value that replaces secrets and personal data in a recorded [Cassette](Cassette).
+pub const REDACTED: &str = "REDACTED";
+
+// fields holding personal data or secrets, their values are replaced before they are written
+const SCRUBBED_FIELDS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "signature_key",
+    "given_name",
+    "family_name",
+    "nickname",
+    "company_name",
+    "first_name",
+    "last_name",
+    "cardholder_name",
+    "display_name",
+    "recipient_name",
+    "email_address",
+    "buyer_email_address",
+    "phone_number",
+    "buyer_phone_number",
+    "birthday",
+    "note",
+    "customer_note",
+    "buyer_note",
+    "address_line_1",
+    "address_line_2",
+    "address_line_3",
+    "locality",
+    "sublocality",
+    "sublocality_2",
+    "sublocality_3",
+    "postal_code",
+    "last_4",
+    "exp_month",
+    "exp_year",
+    "bin",
+    "fingerprint",
+];
+
+// fields of a request that are different on every run and thus left out when matching
+const VOLATILE_FIELDS: &[&str] = &["idempotency_key", "occurred_at"];
+
+/// Whether a [Cassette](Cassette) records new interactions or replays recorded ones.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+/// A file of recorded HTTP interactions, attached to a client through
+/// [SquareClient::cassette](crate::client::SquareClient::cassette). Clones of a
+/// [Cassette](Cassette) share the same recording.
+#[derive(Clone, Debug)]
+pub struct Cassette {
+    mode: CassetteMode,
+    tape: Arc<Mutex<Tape>>,
+}
+
+#[derive(Debug)]
+struct Tape {
+    path: PathBuf,
+    interactions: Vec<Interaction>,
+    // the interactions that have already been served in replay mode
+    played: Vec<bool>,
+}
+
+/// The content of a cassette file.
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct CassetteFile {
+    pub interactions: Vec<Interaction>,
+}
+
+/// A single recorded request together with its response.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// A request as it is recorded, and as it is matched against during replay.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RecordedRequest {
+    pub verb: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub body: Value,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub square_version: Option<String>,
+    pub body: Value,
+}
+
+impl Cassette {
+    /// Create a [Cassette](Cassette) in record mode. The file at the given path is overwritten
+    /// as soon as the first interaction has been recorded.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the JSON file to write the interactions to.
+    pub fn record<P: AsRef<Path>>(path: P) -> Self {
+        Self::new(CassetteMode::Record, path.as_ref().to_path_buf(), vec![])
+    }
+
+    /// Create a [Cassette](Cassette) in replay mode from a previously recorded file.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the JSON file to read the interactions from.
+    pub fn replay<P: AsRef<Path>>(path: P) -> Result<Self, SquareError> {
+        let file: CassetteFile = serde_json::from_str(&fs::read_to_string(path.as_ref())?)?;
+
+        Ok(Self::new(CassetteMode::Replay, path.as_ref().to_path_buf(), file.interactions))
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// The interactions recorded or loaded so far.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.tape().interactions.clone()
+    }
+
+    fn new(mode: CassetteMode, path: PathBuf, interactions: Vec<Interaction>) -> Self {
+        let played = vec![false; interactions.len()];
+
+        Cassette {
+            mode,
+            tape: Arc::new(Mutex::new(Tape { path, interactions, played })),
+        }
+    }
+
+    fn tape(&self) -> std::sync::MutexGuard<'_, Tape> {
+        self.tape.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    // serves the status, body and version of the first recorded response of a matching request
+    // that has not been played yet
+    pub(crate) fn play(&self, request: &RecordedRequest)
+                       -> Result<(u16, String, Option<String>), SquareError> {
+        let mut tape = self.tape();
+
+        let index = tape.interactions.iter()
+            .zip(tape.played.iter())
+            .position(|(interaction, played)| !played && &interaction.request == request)
+            .ok_or_else(|| SquareError::from(Some(vec![ResponseError {
+                category: "CASSETTE_ERROR".to_string(),
+                code: "NO_MATCHING_INTERACTION".to_string(),
+                detail: Some(format!("No recorded interaction in {} matches {} {}.",
+                                     tape.path.display(), request.verb, request.path)),
+                field: None,
+            }])))?;
+
+        tape.played[index] = true;
+
+        let response = &tape.interactions[index].response;
+        let body = match &response.body {
+            Value::String(text) => text.clone(),
+            body => body.to_string(),
+        };
+
+        Ok((response.status, body, response.square_version.clone()))
+    }
+
+    // records the response to the request and writes the cassette file
+    pub(crate) fn record_interaction(
+        &self,
+        request: RecordedRequest,
+        status: u16,
+        response: &str,
+        square_version: Option<String>,
+        access_token: &str,
+    ) -> Result<(), SquareError> {
+        let response = if access_token.is_empty() {
+            response.to_string()
+        } else {
+            response.replace(access_token, REDACTED)
+        };
+
+        let mut body = serde_json::from_str(&response).unwrap_or(Value::String(response));
+        scrub(&mut body);
+
+        let mut tape = self.tape();
+        tape.interactions.push(Interaction {
+            request,
+            response: RecordedResponse { status, square_version, body },
+        });
+        tape.played.push(true);
+
+        if let Some(parent) = tape.path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)?;
+            }
+        }
+
+        let file = CassetteFile { interactions: tape.interactions.clone() };
+        fs::write(&tape.path, serde_json::to_string_pretty(&file)?)?;
+
+        Ok(())
+    }
+}
+
+impl RecordedRequest {
+    /// Create the normalized form of a request: the path is taken without the host, the query
+    /// parameters are sorted, volatile fields are removed from the body and the values of
+    /// personal fields are replaced.
+    pub fn new(verb: &Verb, url: &str, query: &[(String, String)], body: Value) -> Self {
+        let path = reqwest::Url::parse(url)
+            .map(|url| url.path().to_string())
+            .unwrap_or_else(|_| url.to_string());
+
+        let mut query: Vec<(String, String)> = query.iter()
+            .map(|(key, value)| match SCRUBBED_FIELDS.contains(&key.as_str()) {
+                true => (key.clone(), REDACTED.to_string()),
+                false => (key.clone(), value.clone()),
+            })
+            .collect();
+        query.sort();
+
+        let mut body = body;
+        remove_volatile(&mut body);
+        scrub(&mut body);
+
+        RecordedRequest {
+            verb: verb_name(verb).to_string(),
+            path,
+            query,
+            body,
+        }
+    }
+}
+
+fn verb_name(verb: &Verb) -> &'static str {
+    match verb {
+        Verb::GET => "GET",
+        Verb::POST => "POST",
+        Verb::PUT => "PUT",
+        Verb::PATCH => "PATCH",
+        Verb::DELETE => "DELETE",
+    }
+}
+
+fn remove_volatile(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for field in VOLATILE_FIELDS {
+                map.remove(*field);
+            }
+            map.values_mut().for_each(remove_volatile);
+        },
+        Value::Array(values) => values.iter_mut().for_each(remove_volatile),
+        _ => {},
+    }
+}
+
+// replaces the values of personal fields and any bearer tokens found in the value, numbers are
+// replaced with 0 so that the recorded responses still deserialize
+fn scrub(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    Value::String(_) if SCRUBBED_FIELDS.contains(&key.as_str()) => {
+                        *value = Value::String(REDACTED.to_string());
+                    },
+                    Value::Number(_) if SCRUBBED_FIELDS.contains(&key.as_str()) => {
+                        *value = Value::from(0);
+                    },
+                    value => scrub(value),
+                }
+            }
+        },
+        Value::Array(values) => values.iter_mut().for_each(scrub),
+        Value::String(text) if text.starts_with("Bearer ") => {
+            *text = format!("Bearer {}", REDACTED);
+        },
+        _ => {},
+    }
+}
+
+#[cfg(test)]
+mod test_cassette {
+    use super::*;
+    use crate::client::SquareClient;
+    use crate::objects::Response;
+    use serde_json::json;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("square-ox-{}", uuid::Uuid::new_v4().to_simple()))
+            .join(name)
+    }
+
+    #[test]
+    fn test_normalize_request() {
+        let body = json!({
+            "idempotency_key": "8193148c-9586-11e6-99f9-28cfe92138cf",
+            "given_name": "Amelia",
+            "address": { "address_line_1": "500 Electric Ave", "country": "US" },
+            "reference_id": "Bearer EAAAEXAMPLE",
+        });
+        let query = vec![
+            ("limit".to_string(), "10".to_string()),
+            ("email_address".to_string(), "amelia@example.com".to_string()),
+        ];
+
+        let actual = RecordedRequest::new(
+            &Verb::POST,
+            "https://connect.squareupsandbox.com/v2/customers",
+            &query,
+            body,
+        );
+
+        let expected = RecordedRequest {
+            verb: "POST".to_string(),
+            path: "/v2/customers".to_string(),
+            query: vec![
+                ("email_address".to_string(), REDACTED.to_string()),
+                ("limit".to_string(), "10".to_string()),
+            ],
+            body: json!({
+                "given_name": REDACTED,
+                "address": { "address_line_1": REDACTED, "country": "US" },
+                "reference_id": "Bearer REDACTED",
+            }),
+        };
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn test_scrub_nested() {
+        let mut body = json!({
+            "payments": [{
+                "id": "GQTFp1ZlXdpoW4o6eGiZhbjosiDFf",
+                "note": "Gift for Amelia",
+                "card_details": {
+                    "card": {
+                        "card_brand": "VISA",
+                        "last_4": "1111",
+                        "exp_month": 11,
+                        "exp_year": 2030,
+                        "billing_address": { "locality": "Kalamazoo", "country": "US" },
+                    },
+                },
+            }],
+        });
+
+        scrub(&mut body);
+
+        assert_eq!(body, json!({
+            "payments": [{
+                "id": "GQTFp1ZlXdpoW4o6eGiZhbjosiDFf",
+                "note": REDACTED,
+                "card_details": {
+                    "card": {
+                        "card_brand": "VISA",
+                        "last_4": REDACTED,
+                        "exp_month": 0,
+                        "exp_year": 0,
+                        "billing_address": { "locality": REDACTED, "country": "US" },
+                    },
+                },
+            }],
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_replay() {
+        let path = temp_path("locations.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, json!({
+            "interactions": [{
+                "request": { "verb": "GET", "path": "/v2/locations", "query": [], "body": null },
+                "response": {
+                    "status": 200,
+                    "body": { "locations": [{ "id": "L1JC53TYHS40Z", "name": "Default" }] }
+                }
+            }]
+        }).to_string()).unwrap();
+
+        let client = SquareClient::new("some_token")
+            .cassette(Cassette::replay(&path).unwrap());
+
+        match client.locations().list().await.unwrap().response {
+            Some(Response::Locations(locations)) => {
+                assert_eq!(locations[0].id, Some("L1JC53TYHS40Z".into()))
+            },
+            other => panic!("unexpected response: {:?}", other),
+        }
+
+        // every interaction is only served once
+        assert!(client.locations().list().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_replay_failed_status() {
+        let path = temp_path("locations.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, json!({
+            "interactions": [{
+                "request": { "verb": "GET", "path": "/v2/locations", "query": [], "body": null },
+                "response": { "status": 404, "body": {} }
+            }]
+        }).to_string()).unwrap();
+
+        let client = SquareClient::new("some_token")
+            .cassette(Cassette::replay(&path).unwrap());
+
+        assert!(client.locations().list().await.is_err());
+    }
+
+    #[cfg(feature = "mock")]
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        use crate::mock::MockServer;
+        use crate::objects::Customer;
+
+        let path = temp_path("customers.json");
+        let server = MockServer::start();
+        let customer = Customer {
+            given_name: Some("Amelia".to_string()),
+            email_address: Some("amelia@example.com".to_string()),
+            ..Default::default()
+        };
+
+        let recording = server.client().cassette(Cassette::record(&path));
+        assert!(recording.customers().create(customer.clone()).await.is_ok());
+
+        let recorded = fs::read_to_string(&path).unwrap();
+        assert!(!recorded.contains("amelia@example.com"));
+        assert!(!recorded.contains("mock_access_token"));
+
+        drop(server);
+
+        let replaying = SquareClient::new("some_token").cassette(Cassette::replay(&path).unwrap());
+        match replaying.customers().create(customer).await.unwrap().response {
+            Some(Response::Customer(customer)) => {
+                assert_eq!(customer.email_address, Some(REDACTED.to_string()))
+            },
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+}
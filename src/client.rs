@@ -15,19 +15,45 @@ let client = SquareClient::new(ACCESS_TOKEN);
 ```
 After creating a client you will be able to use all of the clients methods.
 
+# Example: Pointing a client at a custom environment
+Requests can be sent to any server implementing the [Square API](https://developer.squareup.com),
+such as a local stand-in or a proxy, by giving the client a custom base URL.
+
+```rust
+use square_ox::client::{SquareClient, Url};
+
+let client = SquareClient::new("your_square_access_token")
+    .custom(Url::parse("http://localhost:8080/v2/").unwrap());
+```
+
 */
 use crate::api::{SquareAPI, Verb};
+use crate::cassette::{Cassette, CassetteMode, RecordedRequest};
 use crate::errors::SquareError;
 use crate::response::SquareResponse;
 
-use reqwest::{header, Client};
+use reqwest::{header, Client, RequestBuilder};
 use serde::Serialize;
+use serde_json::Value;
 use std::default::Default;
+use std::time::Duration;
+use uuid::Uuid;
 
-#[derive(Copy, Clone)]
+pub use reqwest::Url;
+
+/// The version of the [Square API](https://developer.squareup.com) the objects of the crate are
+/// modelled after, sent in the `Square-Version` header of every request unless it is overridden
+/// through [SquareClient::square_version](SquareClient::square_version).
+pub const SQUARE_VERSION: &str = "2022-09-21";
+
+/// The environment a [SquareClient](SquareClient) sends its requests to.
+#[derive(Clone, Debug)]
 pub enum ClientMode {
     Production,
     Sandboxed,
+    /// A custom base URL, e.g. a local stand-in, an egress proxy or a recording proxy. The
+    /// endpoint paths, such as `payments`, are appended to it.
+    Custom(Url),
 }
 
 /// The default mode we start a client in is Sandboxed
@@ -43,6 +69,9 @@ impl Default for ClientMode {
 pub struct SquareClient {
     access_token: String,
     pub(crate) client_mode: ClientMode,
+    pub(crate) square_version: String,
+    options: RequestOptions,
+    cassette: Option<Cassette>,
 }
 
 impl SquareClient {
@@ -50,7 +79,7 @@ impl SquareClient {
     ///
     /// # Arguments
     /// * `access_token` - The access token for the Square App you
-    /// want to use the client with is required.
+    ///   want to use the client with is required.
     ///
     /// # Example: Create a new client
     /// ```
@@ -63,6 +92,9 @@ impl SquareClient {
         Self {
             access_token: access_token.to_string(),
             client_mode: Default::default(),
+            square_version: SQUARE_VERSION.to_string(),
+            options: Default::default(),
+            cassette: None,
         }
     }
 
@@ -80,8 +112,156 @@ impl SquareClient {
     /// ```
     pub fn production(self) -> Self {
         Self {
-            access_token: self.access_token,
             client_mode: ClientMode::Production,
+            ..self
+        }
+    }
+
+    /// Set the client to send its requests to a custom base URL instead of one of the Square
+    /// environments.
+    ///
+    /// # Arguments
+    /// * `base_url` - The [Url](Url) the endpoint paths are appended to. A trailing `/` is added
+    ///   if it is missing, so both `http://localhost:8080/v2` and `http://localhost:8080/v2/` work.
+    ///
+    /// # Example
+    /// ```
+    /// const ACCESS_TOKEN:&str = "your_square_access_token";
+    ///
+    /// use square_ox::client::{SquareClient, Url};
+    /// let client = SquareClient::new(ACCESS_TOKEN)
+    ///     .custom(Url::parse("http://localhost:8080/v2").unwrap());
+    /// ```
+    pub fn custom(self, base_url: Url) -> Self {
+        Self {
+            client_mode: ClientMode::Custom(base_url),
+            ..self
+        }
+    }
+
+    /// Pin the client to a specific version of the [Square API](https://developer.squareup.com),
+    /// instead of the [SQUARE_VERSION](SQUARE_VERSION) the crate is modelled after.
+    ///
+    /// # Arguments
+    /// * `version` - The version to send in the `Square-Version` header, e.g. `2022-09-21`.
+    ///
+    /// # Example
+    /// ```
+    /// const ACCESS_TOKEN:&str = "your_square_access_token";
+    ///
+    /// use square_ox::client::SquareClient;
+    /// let client = SquareClient::new(ACCESS_TOKEN).square_version("2022-08-23");
+    /// ```
+    pub fn square_version(self, version: &str) -> Self {
+        Self {
+            square_version: version.to_string(),
+            ..self
+        }
+    }
+
+    /// Returns a copy of the client that sends its requests with another version of the
+    /// [Square API](https://developer.squareup.com), for overriding the version of single
+    /// requests.
+    ///
+    /// # Arguments
+    /// * `version` - The version to send in the `Square-Version` header, e.g. `2022-09-21`.
+    ///
+    /// # Example
+    /// ```
+    /// const ACCESS_TOKEN:&str = "your_square_access_token";
+    ///
+    /// use square_ox::client::SquareClient;
+    /// async {
+    ///     let client = SquareClient::new(ACCESS_TOKEN);
+    ///
+    ///     let res = client.with_square_version("2022-08-23")
+    ///         .locations()
+    ///         .list()
+    ///         .await;
+    /// };
+    /// ```
+    pub fn with_square_version(&self, version: &str) -> Self {
+        self.clone().square_version(version)
+    }
+
+    /// Returns a copy of the client that sends its requests with the given
+    /// [RequestOptions](RequestOptions), for setting a custom idempotency key, a timeout, extra
+    /// headers or the API version of single requests.
+    ///
+    /// # Arguments
+    /// * `options` - The [RequestOptions](RequestOptions) to send the requests with.
+    ///
+    /// # Example: Safely retry a payment with the same idempotency key
+    /// ```
+    /// const ACCESS_TOKEN:&str = "your_square_access_token";
+    ///
+    /// use square_ox::{
+    ///     api::payment::PaymentRequest,
+    ///     builder::Builder,
+    ///     client::{RequestOptions, SquareClient},
+    ///     objects::enums::Currency,
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// async {
+    ///     let client = SquareClient::new(ACCESS_TOKEN);
+    ///     let options = RequestOptions::new()
+    ///         .idempotency_key("order-1234-payment")
+    ///         .timeout(Duration::from_secs(10));
+    ///
+    ///     for _ in 0..3 {
+    ///         let payment = Builder::from(PaymentRequest::default())
+    ///             .source_id("cnon:card-nonce-ok".to_string())
+    ///             .amount(1000, Currency::USD)
+    ///             .build_sync()
+    ///             .unwrap();
+    ///
+    ///         if client.with_options(options.clone()).payments().create(payment).await.is_ok() {
+    ///             break;
+    ///         }
+    ///     }
+    /// };
+    /// ```
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        let mut client = self.clone();
+        if let Some(version) = &options.square_version {
+            client.square_version = version.clone();
+        }
+        client.options = options;
+
+        client
+    }
+
+    // returns a copy of the client for the chunk with the given index of a chunked request, which
+    // suffixes an overridden idempotency key with the index so that every chunk is a distinct
+    // operation
+    pub(crate) fn for_chunk(&self, index: usize) -> Self {
+        let mut client = self.clone();
+        if let Some(idempotency_key) = &self.options.idempotency_key {
+            client.options.idempotency_key = Some(format!("{}-{}", idempotency_key, index));
+        }
+
+        client
+    }
+
+    /// Record the requests of the client to a [Cassette](crate::cassette::Cassette), or replay
+    /// them from one without touching the network.
+    ///
+    /// # Arguments
+    /// * `cassette` - The [Cassette](crate::cassette::Cassette) to record to or replay from.
+    ///
+    /// # Example
+    /// ```
+    /// const ACCESS_TOKEN:&str = "your_square_access_token";
+    ///
+    /// use square_ox::{cassette::Cassette, client::SquareClient};
+    /// let client = SquareClient::new(ACCESS_TOKEN)
+    ///     .cassette(Cassette::record("tests/cassettes/payments.json"));
+    /// ```
+    pub fn cassette(self, cassette: Cassette) -> Self {
+        Self {
+            cassette: Some(cassette),
+            ..self
         }
     }
 
@@ -89,7 +269,7 @@ impl SquareClient {
     /// # Arguments
     /// * `api` - The [SquareAPI](crate::api::SquareAPI) to send the request to
     /// * `body` - The json that will be included in the request.
-    /// All types that meet the conditions to be deserialized to JSON are accepted.
+    ///   All types that meet the conditions to be deserialized to JSON are accepted.
     ///
     /// # Example:
     /// ```
@@ -103,7 +283,7 @@ impl SquareClient {
     ///         builder::Builder
     ///     };
     ///     const ACCESS_TOKEN:&str = "your_square_access_token";
-    ///     let payment = Builder::from(PaymentRequest::default()).build().await;
+    ///     let payment = Builder::from(PaymentRequest::default()).build_sync();
     ///
     ///     let client = client::SquareClient::new(ACCESS_TOKEN);
     ///     client.request( Verb::POST, SquareAPI::Payments("".to_string()), Some(&payment), None).await.expect("");
@@ -120,7 +300,95 @@ impl SquareClient {
     where
         T: Serialize + ?Sized,
     {
-        let url = self.endpoint(endpoint).clone();
+        let url = self.endpoint(endpoint);
+        let mut json = json.map(serde_json::to_value).transpose()?;
+        if let Some(json) = json.as_mut() {
+            self.options.apply_idempotency_key(json);
+        }
+
+        let recorded = RecordedRequest::new(
+            &verb,
+            &url,
+            parameters.as_deref().unwrap_or_default(),
+            json.clone().unwrap_or_default(),
+        );
+        let mut builder = self.request_builder(verb, &url)?;
+
+        // Add query parameters if there are any
+        if let Some(parameters) = parameters {
+            builder = builder.query(&parameters);
+        }
+
+        // Add a json body if there is one
+        if let Some(json) = json {
+            builder = builder.json(&json)
+        }
+
+        self.send(builder, recorded).await
+    }
+
+    /// Sends a multipart/form-data request to a given [SquareAPI](crate::api::SquareAPI). Used by
+    /// the endpoints that accept file uploads, such as
+    /// [create_image](crate::api::catalog::Catalog::create_image).
+    pub(crate) async fn multipart_request(
+        &self,
+        verb: Verb,
+        endpoint: SquareAPI,
+        mut parts: Vec<FormPart>,
+    ) -> Result<SquareResponse, SquareError> {
+        for part in parts.iter_mut().filter(|part| part.content_type == "application/json") {
+            if let Ok(mut json) = serde_json::from_slice::<Value>(&part.data) {
+                self.options.apply_idempotency_key(&mut json);
+                part.data = serde_json::to_vec(&json)?;
+            }
+        }
+
+        // only the JSON parts are recorded, file contents are left out
+        let url = self.endpoint(endpoint);
+        let recorded = RecordedRequest::new(
+            &verb,
+            &url,
+            &[],
+            Value::Object(parts.iter()
+                .map(|part| (
+                    part.name.to_string(),
+                    serde_json::from_slice(&part.data).unwrap_or(Value::Null),
+                ))
+                .collect()),
+        );
+
+        let boundary = format!("square-ox-{}", Uuid::new_v4().to_simple());
+
+        let mut body = Vec::new();
+        for part in parts {
+            body.extend(format!("--{}\r\n", boundary).as_bytes());
+            match part.file_name {
+                Some(file_name) => body.extend(format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+                    part.name, file_name
+                ).as_bytes()),
+                None => body.extend(format!(
+                    "Content-Disposition: form-data; name=\"{}\"\r\n",
+                    part.name
+                ).as_bytes()),
+            }
+            body.extend(format!("Content-Type: {}\r\n\r\n", part.content_type).as_bytes());
+            body.extend(part.data);
+            body.extend(b"\r\n");
+        }
+        body.extend(format!("--{}--\r\n", boundary).as_bytes());
+
+        let builder = self.request_builder(verb, &url)?
+            .header(
+                header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", boundary),
+            )
+            .body(body);
+
+        self.send(builder, recorded).await
+    }
+
+    fn request_builder(&self, verb: Verb, url: &str) -> Result<RequestBuilder, SquareError> {
         let authorization_header = format!("Bearer {}", &self.access_token);
 
         // Add the headers to the request
@@ -129,48 +397,161 @@ impl SquareClient {
             header::AUTHORIZATION,
             header::HeaderValue::from_str(&authorization_header)?,
         );
+        headers.insert(
+            "Square-Version",
+            header::HeaderValue::from_str(&self.square_version)?,
+        );
 
-        // Create a client with the appropriate headers
-        let client = Client::builder().default_headers(headers).build()?;
+        for (name, value) in &self.options.headers {
+            headers.insert(
+                header::HeaderName::from_bytes(name.as_bytes())?,
+                header::HeaderValue::from_str(value)?,
+            );
+        }
 
-        println!("url: {}", &url);
+        // Create a client with the appropriate headers
+        let mut client = Client::builder().default_headers(headers);
+        if let Some(timeout) = self.options.timeout {
+            client = client.timeout(timeout);
+        }
+        let client = client.build()?;
 
         // Send the request to the Square API, and get the response
-        let mut builder = match verb {
-            Verb::GET => client.get(&url),
-            Verb::POST => client.post(&url),
-            Verb::PUT => client.put(&url),
-            Verb::PATCH => client.patch(&url),
-            Verb::DELETE => client.delete(&url),
+        let builder = match verb {
+            Verb::GET => client.get(url),
+            Verb::POST => client.post(url),
+            Verb::PUT => client.put(url),
+            Verb::PATCH => client.patch(url),
+            Verb::DELETE => client.delete(url),
         };
 
-        // Add query parameters if there are any
-        if let Some(parameters) = parameters {
-            builder = builder.query(&parameters);
-        }
-
-        // Add a json body if there is one
-        if let Some(json) = json {
-            builder = builder.json(json)
-        }
-
-        // Deserialize the response into a SquareResponse
-        // let response: SquareResponse = builder.send().await?.json().await?;
+        Ok(builder)
+    }
 
-        // TODO remove the debug code!
-        let response = builder.send().await?.text().await?;
+    async fn send(&self, builder: RequestBuilder, recorded: RecordedRequest)
+                  -> Result<SquareResponse, SquareError> {
+        let (status, response, square_version) = match &self.cassette {
+            Some(cassette) if cassette.mode() == CassetteMode::Replay => cassette.play(&recorded)?,
+            cassette => {
+                let response = builder.send().await?;
+                let status = response.status().as_u16();
+                let square_version = response.headers()
+                    .get("Square-Version")
+                    .and_then(|version| version.to_str().ok())
+                    .map(String::from);
+                let response = response.text().await?;
 
-        println!("{:?}", response);
+                if let Some(cassette) = cassette {
+                    cassette.record_interaction(
+                        recorded,
+                        status,
+                        &response,
+                        square_version.clone(),
+                        &self.access_token,
+                    )?;
+                }
 
-        let response: SquareResponse = serde_json::from_str(&response)?;
+                (status, response, square_version)
+            },
+        };
 
-        println!("{:?}", response);
+        // Deserialize the response into a SquareResponse
+        let mut response: SquareResponse = serde_json::from_str(&response)?;
+        response.square_version = square_version;
 
         // handle the possibility of an error being returned by the Square API
         if response.errors.is_some() && response.errors.as_ref().unwrap().len() > 0 {
             return Err(SquareError::from(response.errors))
         }
 
+        // a failed status is an error even if the body holds no errors
+        if !(200..300).contains(&status) {
+            return Err(SquareError::from(None))
+        }
+
         Ok(response)
     }
 }
+
+/// Options for single requests, applied to all requests of the client returned by
+/// [SquareClient::with_options](SquareClient::with_options).
+///
+/// # Example
+/// ```
+/// use square_ox::client::RequestOptions;
+/// use std::time::Duration;
+///
+/// let options = RequestOptions::new()
+///     .idempotency_key("order-1234-payment")
+///     .timeout(Duration::from_secs(10))
+///     .header("X-Request-Source", "checkout")
+///     .square_version("2022-08-23");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    idempotency_key: Option<String>,
+    timeout: Option<Duration>,
+    headers: Vec<(String, String)>,
+    square_version: Option<String>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Use the given idempotency key instead of the random one generated when the body of the
+    /// request was built, so that retries of the same operation are only executed once.
+    ///
+    /// Only the top-level `idempotency_key` of a request body is replaced, nested keys, such as
+    /// the keys of the single vendor updates of a
+    /// [BulkUpdateVendorsBody](crate::api::vendors::BulkUpdateVendorsBody), are sent as built.
+    /// Chunked requests, such as
+    /// [batch_change_chunked](crate::api::inventory::Inventory::batch_change_chunked), send the
+    /// n-th chunk, counting from 0, with the key `<key>-<n>`.
+    pub fn idempotency_key(mut self, idempotency_key: &str) -> Self {
+        self.idempotency_key = Some(idempotency_key.to_string());
+
+        self
+    }
+
+    /// The time to wait for the whole request, from connecting until the response body has been
+    /// read, before failing it.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Add an extra header to the request.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+
+        self
+    }
+
+    /// Override the version of the [Square API](https://developer.squareup.com) sent in the
+    /// `Square-Version` header.
+    pub fn square_version(mut self, version: &str) -> Self {
+        self.square_version = Some(version.to_string());
+
+        self
+    }
+
+    // replaces the idempotency key of a request body that has one
+    fn apply_idempotency_key(&self, json: &mut Value) {
+        if let (Some(idempotency_key), Some(json)) = (&self.idempotency_key, json.as_object_mut()) {
+            if json.contains_key("idempotency_key") {
+                json.insert("idempotency_key".to_string(), Value::String(idempotency_key.clone()));
+            }
+        }
+    }
+}
+
+/// A single part of a multipart/form-data request body.
+pub(crate) struct FormPart {
+    pub(crate) name: &'static str,
+    pub(crate) file_name: Option<String>,
+    pub(crate) content_type: &'static str,
+    pub(crate) data: Vec<u8>,
+}
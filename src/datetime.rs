@@ -0,0 +1,457 @@
+/*!
+Date, time and duration values of the [Square API](https://developer.squareup.com).
+
+Square expects timestamps as [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) strings, e.g.
+`2022-09-12T07:20:50.52Z`, and durations as ISO 8601 durations, e.g. `PT5M`. The setters of the
+builders that take a timestamp accept anything that converts into a [Timestamp](Timestamp), and
+the setters that take a duration accept anything that converts into an
+[IsoDuration](IsoDuration), so the crate takes care of formatting typed values:
+
+* [SystemTime](std::time::SystemTime) and [Duration](std::time::Duration) from the standard
+  library are always supported.
+* `chrono::DateTime` and `chrono::Duration` are supported with the `chrono` feature, which also
+  enables parsing returned timestamps into `chrono::DateTime<Utc>` values.
+* `time::OffsetDateTime` and `time::Duration` are supported with the `time` feature, which also
+  enables parsing returned timestamps into `time::OffsetDateTime` values.
+* Negative durations are rejected by Square, so `chrono::Duration` and `time::Duration` are only
+  converted with `TryFrom`, which fails for them.
+* Plain strings are passed on to Square as they are, so that existing code keeps working. Building
+  an object reports those that Square would reject as a
+  [ValidationError](crate::errors::ValidationError).
+
+The timestamps and durations of the objects returned by Square are typed as well, so that they
+can be converted with [IsoDuration::to_std](IsoDuration::to_std) or, with the `chrono` and `time`
+features, `Timestamp::to_datetime` and `Timestamp::to_offset_datetime`.
+
+# Example: List the payments of the last day
+```rust
+use square_ox::api::payment::ListPaymentsParametersBuilder;
+use std::time::{Duration, SystemTime};
+
+let parameters = ListPaymentsParametersBuilder::new()
+    .begin_time(SystemTime::now() - Duration::from_secs(24 * 60 * 60))
+    .build_sync();
+```
+ */
+
+use crate::errors::{FieldPath, ValidationError};
+
+use serde::{Deserialize, Serialize};
+#[cfg(any(feature = "chrono", feature = "time"))]
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// An [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) timestamp, e.g.
+/// `2022-09-12T07:20:50.52Z`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct Timestamp(String);
+
+impl Timestamp {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns true if the timestamp is a valid RFC 3339 timestamp. Timestamps created from
+    /// typed values are always valid.
+    pub fn is_valid(&self) -> bool {
+        is_rfc3339(&self.0)
+    }
+
+    // records a violation at `path` unless Square accepts the timestamp
+    pub(crate) fn check(&self, path: impl Into<FieldPath>, errors: &mut ValidationError) {
+        if !self.is_valid() {
+            errors.invalid(path, format!("`{}` is not an RFC 3339 timestamp", self.0));
+        }
+    }
+
+    /// Parse the timestamp into a `chrono::DateTime<Utc>`, returning None if it is not a valid
+    /// RFC 3339 timestamp.
+    #[cfg(feature = "chrono")]
+    pub fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        parse_datetime(&self.0)
+    }
+
+    /// Parse the timestamp into a `time::OffsetDateTime`, returning None if it is not a valid
+    /// RFC 3339 timestamp.
+    #[cfg(feature = "time")]
+    pub fn to_offset_datetime(&self) -> Option<time::OffsetDateTime> {
+        time::OffsetDateTime::parse(&self.0, &time::format_description::well_known::Rfc3339).ok()
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(timestamp: String) -> Self {
+        Timestamp(timestamp)
+    }
+}
+
+impl From<&str> for Timestamp {
+    fn from(timestamp: &str) -> Self {
+        Timestamp(timestamp.to_string())
+    }
+}
+
+impl From<Timestamp> for String {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        // milliseconds since the unix epoch, negative for times before it
+        let total_millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(since_epoch) => since_epoch.as_millis() as i128,
+            Err(before_epoch) => -(before_epoch.duration().as_millis() as i128),
+        };
+        let secs = total_millis.div_euclid(1000) as i64;
+        let millis = total_millis.rem_euclid(1000);
+
+        let (days, rem) = (secs.div_euclid(86400), secs.rem_euclid(86400));
+
+        // converts days since the unix epoch into a civil date, see
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        let fraction = if millis > 0 { format!(".{:03}", millis) } else { String::new() };
+
+        Timestamp(format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+            year, month, day, rem / 3600, rem % 3600 / 60, rem % 60, fraction
+        ))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    fn from(datetime: chrono::DateTime<Tz>) -> Self {
+        Timestamp(datetime.with_timezone(&chrono::Utc)
+            .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        Timestamp::from(SystemTime::from(datetime))
+    }
+}
+
+/// An ISO 8601 duration, e.g. `PT5M` for five minutes.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct IsoDuration(String);
+
+impl IsoDuration {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns true if the duration is a valid ISO 8601 duration without years and months, which
+    /// do not have a fixed length.
+    pub fn is_valid(&self) -> bool {
+        parse_duration(&self.0).is_some()
+    }
+
+    // records a violation at `path` unless Square accepts the duration
+    pub(crate) fn check(&self, path: impl Into<FieldPath>, errors: &mut ValidationError) {
+        if !self.is_valid() {
+            errors.invalid(path, format!("`{}` is not an ISO 8601 duration", self.0));
+        }
+    }
+
+    /// Convert the duration into a [Duration](std::time::Duration), returning None if it is not
+    /// a valid ISO 8601 duration or contains years or months.
+    pub fn to_std(&self) -> Option<Duration> {
+        parse_duration(&self.0)
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for IsoDuration {
+    fn from(duration: String) -> Self {
+        IsoDuration(duration)
+    }
+}
+
+impl From<&str> for IsoDuration {
+    fn from(duration: &str) -> Self {
+        IsoDuration(duration.to_string())
+    }
+}
+
+impl From<IsoDuration> for String {
+    fn from(duration: IsoDuration) -> Self {
+        duration.0
+    }
+}
+
+impl From<Duration> for IsoDuration {
+    fn from(duration: Duration) -> Self {
+        IsoDuration(format_duration(duration))
+    }
+}
+
+// negative durations are not accepted by Square
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::Duration> for IsoDuration {
+    type Error = chrono::OutOfRangeError;
+
+    fn try_from(duration: chrono::Duration) -> Result<Self, Self::Error> {
+        duration.to_std().map(IsoDuration::from)
+    }
+}
+
+// negative durations are not accepted by Square
+#[cfg(feature = "time")]
+impl TryFrom<time::Duration> for IsoDuration {
+    type Error = time::error::ConversionRange;
+
+    fn try_from(duration: time::Duration) -> Result<Self, Self::Error> {
+        Duration::try_from(duration).map(IsoDuration::from)
+    }
+}
+
+/// Parse an RFC 3339 timestamp returned by Square, such as the `created_at` field of an object,
+/// into a `chrono::DateTime<Utc>`.
+#[cfg(feature = "chrono")]
+pub fn parse_datetime(timestamp: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|datetime| datetime.with_timezone(&chrono::Utc))
+}
+
+// the current time as an RFC 3339 timestamp in UTC, e.g. 2022-07-09T12:25:34Z
+pub(crate) fn now() -> Timestamp {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+    Timestamp::from(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (days, hours, minutes, seconds) =
+        (secs / 86400, secs % 86400 / 3600, secs % 3600 / 60, secs % 60);
+    let nanos = duration.subsec_nanos();
+
+    let mut res = "P".to_string();
+    if days > 0 {
+        res.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || nanos > 0 || days == 0 {
+        res.push('T');
+    }
+    if hours > 0 {
+        res.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        res.push_str(&format!("{}M", minutes));
+    }
+    if nanos > 0 {
+        let fraction = format!("{:09}", nanos);
+        res.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+    } else if seconds > 0 || secs == 0 {
+        res.push_str(&format!("{}S", seconds));
+    }
+
+    res
+}
+
+// parses ISO 8601 durations with weeks, days, hours, minutes and (fractional) seconds, returning
+// None for durations that do not fit into a Duration
+fn parse_duration(duration: &str) -> Option<Duration> {
+    let rest = duration.strip_prefix('P')?;
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut total = Duration::from_secs(0);
+    let mut in_time = false;
+    let mut number = String::new();
+    let mut components = 0;
+    let mut time_components = 0;
+    for c in rest.chars() {
+        match c {
+            '0'..='9' | '.' | ',' => number.push(if c == ',' { '.' } else { c }),
+            'T' if !in_time && number.is_empty() => in_time = true,
+            designator => {
+                let value: f64 = number.parse().ok()?;
+                let unit = match (in_time, designator) {
+                    (false, 'W') => 7.0 * 86400.0,
+                    (false, 'D') => 86400.0,
+                    (true, 'H') => 3600.0,
+                    (true, 'M') => 60.0,
+                    (true, 'S') => 1.0,
+                    _ => return None,
+                };
+
+                let component = Duration::try_from_secs_f64(value * unit).ok()?;
+                total = total.checked_add(component)?;
+                number.clear();
+                components += 1;
+                if in_time {
+                    time_components += 1;
+                }
+            },
+        }
+    }
+
+    // a time designator must be followed by at least one time component, e.g. P1DT is invalid
+    if !number.is_empty() || components == 0 || (in_time && time_components == 0) {
+        return None;
+    }
+
+    Some(total)
+}
+
+// checks the format YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM) and the ranges of the date
+// and time, allowing a leap second
+fn is_rfc3339(timestamp: &str) -> bool {
+    let bytes = timestamp.as_bytes();
+    let digits = |range: std::ops::Range<usize>| {
+        bytes.get(range).is_some_and(|b| b.iter().all(u8::is_ascii_digit))
+    };
+    let is = |i: usize, expected: &[u8]| bytes.get(i).is_some_and(|b| expected.contains(b));
+    let number = |range: std::ops::Range<usize>| timestamp[range].parse::<u32>().unwrap_or(0);
+
+    if !(digits(0..4) && is(4, b"-") && digits(5..7) && is(7, b"-") && digits(8..10)
+        && is(10, b"Tt") && digits(11..13) && is(13, b":") && digits(14..16) && is(16, b":")
+        && digits(17..19)) {
+        return false;
+    }
+
+    let (year, month, day) = (number(0..4), number(5..7), number(8..10));
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => return false,
+    };
+    if day == 0 || day > days_in_month || number(11..13) > 23 || number(14..16) > 59
+        || number(17..19) > 60 {
+        return false;
+    }
+
+    let mut i = 19;
+    if is(i, b".") {
+        i += 1;
+        let start = i;
+        while is(i, b"0123456789") {
+            i += 1;
+        }
+        if i == start {
+            return false;
+        }
+    }
+
+    match bytes.get(i) {
+        Some(b'Z') | Some(b'z') => i + 1 == bytes.len(),
+        Some(b'+') | Some(b'-') => digits(i + 1..i + 3) && is(i + 3, b":")
+            && digits(i + 4..i + 6) && i + 6 == bytes.len()
+            && number(i + 1..i + 3) <= 23 && number(i + 4..i + 6) <= 59,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test_datetime {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_from_system_time() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_662_967_250_520);
+
+        assert_eq!(Timestamp::from(time).as_str(), "2022-09-12T07:20:50.520Z");
+        assert_eq!(Timestamp::from(UNIX_EPOCH).as_str(), "1970-01-01T00:00:00Z");
+        assert_eq!(Timestamp::from(UNIX_EPOCH - Duration::from_millis(1_500)).as_str(),
+                   "1969-12-31T23:59:58.500Z");
+    }
+
+    #[test]
+    fn test_timestamp_is_valid() {
+        assert!(Timestamp::from("2022-09-12T07:20:50.52Z").is_valid());
+        assert!(Timestamp::from("2022-09-12T07:20:50-07:00").is_valid());
+        assert!(!Timestamp::from("2022-09-12").is_valid());
+        assert!(!Timestamp::from("2022-09-12T07:20:50.Z").is_valid());
+        assert!(!Timestamp::from("some time").is_valid());
+        assert!(!Timestamp::from("2022-13-45 99:00:00Z").is_valid());
+        assert!(!Timestamp::from("2022-09-12 07:20:50Z").is_valid());
+        assert!(!Timestamp::from("2022-02-29T07:20:50Z").is_valid());
+        assert!(Timestamp::from("2024-02-29T07:20:50Z").is_valid());
+        assert!(!Timestamp::from("2022-09-12T24:00:00Z").is_valid());
+        assert!(!Timestamp::from("2022-09-12T07:20:50+24:00").is_valid());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(IsoDuration::from(Duration::from_secs(300)).as_str(), "PT5M");
+        assert_eq!(IsoDuration::from(Duration::from_secs(90061)).as_str(), "P1DT1H1M1S");
+        assert_eq!(IsoDuration::from(Duration::from_secs(86400)).as_str(), "P1D");
+        assert_eq!(IsoDuration::from(Duration::from_millis(1500)).as_str(), "PT1.5S");
+        assert_eq!(IsoDuration::from(Duration::from_secs(0)).as_str(), "PT0S");
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(IsoDuration::from("PT5M").to_std(), Some(Duration::from_secs(300)));
+        assert_eq!(IsoDuration::from("P1W").to_std(), Some(Duration::from_secs(604800)));
+        assert_eq!(IsoDuration::from("PT1.5S").to_std(), Some(Duration::from_millis(1500)));
+        assert_eq!(IsoDuration::from("P1M").to_std(), None);
+        assert_eq!(IsoDuration::from("PT").to_std(), None);
+        assert_eq!(IsoDuration::from("P1DT").to_std(), None);
+        assert_eq!(IsoDuration::from("P1DT2H").to_std(), Some(Duration::from_secs(93600)));
+        assert_eq!(IsoDuration::from("5 minutes").to_std(), None);
+        assert_eq!(IsoDuration::from("PT99999999999999999999999S").to_std(), None);
+        assert_eq!(IsoDuration::from("P99999999999999W").to_std(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversions() {
+        use chrono::{FixedOffset, TimeZone};
+
+        let datetime = FixedOffset::west_opt(7 * 3600).unwrap()
+            .with_ymd_and_hms(2022, 9, 12, 0, 20, 50).unwrap();
+        let timestamp = Timestamp::from(datetime);
+
+        assert_eq!(timestamp.as_str(), "2022-09-12T07:20:50Z");
+        assert_eq!(timestamp.to_datetime(), Some(datetime.with_timezone(&chrono::Utc)));
+        assert_eq!(IsoDuration::try_from(chrono::Duration::minutes(5)).unwrap().as_str(), "PT5M");
+        assert!(IsoDuration::try_from(chrono::Duration::minutes(-5)).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_conversions() {
+        let datetime = time::OffsetDateTime::from_unix_timestamp(1_662_967_250).unwrap()
+            .to_offset(time::UtcOffset::from_hms(-7, 0, 0).unwrap());
+        let timestamp = Timestamp::from(datetime);
+
+        assert_eq!(timestamp.as_str(), "2022-09-12T07:20:50Z");
+        assert_eq!(timestamp.to_offset_datetime(), Some(datetime));
+        assert_eq!(IsoDuration::try_from(time::Duration::minutes(5)).unwrap().as_str(), "PT5M");
+        assert!(IsoDuration::try_from(time::Duration::minutes(-5)).is_err());
+    }
+}
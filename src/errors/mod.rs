@@ -2,9 +2,18 @@
 The errors returned by components of the crate.
  */
 
+mod validation;
+
 use serde::{Deserialize, Serialize};
+use crate::objects::enums::Currency;
 use crate::response::ResponseError;
 
+use std::fmt;
+
+pub use validation::{
+    BuildError, FieldPath, PathSegment, ValidationError, Violation, ViolationReason
+};
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SquareError(Option<Vec<ResponseError>>);
 
@@ -32,6 +41,20 @@ impl From<reqwest::header::InvalidHeaderValue> for SquareError {
     }
 }
 
+impl From<reqwest::header::InvalidHeaderName> for SquareError {
+    fn from(r: reqwest::header::InvalidHeaderName) -> Self {
+        eprintln!("Reqwest Header Failed: {:?}", r);
+        SquareError(None)
+    }
+}
+
+impl From<std::io::Error> for SquareError {
+    fn from(e: std::io::Error) -> Self {
+        eprintln!("IO Failed: {:?}", e);
+        SquareError(None)
+    }
+}
+
 impl From<serde_json::Error> for SquareError {
     fn from(s: serde_json::Error) -> Self {
         eprintln!("Serde JSON Failed: {:?}", s);
@@ -39,6 +62,20 @@ impl From<serde_json::Error> for SquareError {
     }
 }
 
+impl From<serde_urlencoded::ser::Error> for SquareError {
+    fn from(s: serde_urlencoded::ser::Error) -> Self {
+        eprintln!("Serde URL Encoding Failed: {:?}", s);
+        SquareError(None)
+    }
+}
+
+impl From<serde_urlencoded::de::Error> for SquareError {
+    fn from(s: serde_urlencoded::de::Error) -> Self {
+        eprintln!("Serde URL Decoding Failed: {:?}", s);
+        SquareError(None)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PaymentError {
     code: PaymentErrorCode,
@@ -58,68 +95,77 @@ pub enum PaymentErrorCategory {
     PaymentErrorCat,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct PaymentBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct LocationsBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct SearchQueryBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CustomersListBuildError;
-
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ListParametersBuilderError;
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CustomerBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CustomerSearchQueryBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct BookingsPostBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct BookingsCancelBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CustomerDeleteBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct LocationBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CardBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CreateOrderRequestBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CreatePaymentLinkBuildError;
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct PaymentLinkBuildError;
+/// The reasons a chunk of a chunked request can fail, see
+/// [ChunkFailure](crate::response::ChunkFailure).
+#[derive(Debug)]
+pub enum ChunkError {
+    /// A request of the chunk to the Square API failed.
+    Square(SquareError),
+    /// The responses of the chunk hold more kinds of responses than fit into a
+    /// [SquareResponse](crate::response::SquareResponse) once merged with the other chunks.
+    TooManyResponseKinds,
+}
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ObjectUpsertRequestBuildError;
+impl From<SquareError> for ChunkError {
+    fn from(error: SquareError) -> Self {
+        ChunkError::Square(error)
+    }
+}
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct InventoryChangeBodyBuildError;
+impl fmt::Display for ChunkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChunkError::Square(error) => write!(f, "the request of the chunk failed: {:?}", error),
+            ChunkError::TooManyResponseKinds => {
+                write!(f, "the chunk returned more kinds of responses than a response can hold")
+            },
+        }
+    }
+}
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct DeviceOptionsBuildError;
+impl std::error::Error for ChunkError {}
 
+/// The reasons [Sites::register_domain](crate::api::sites::Sites::register_domain) can fail.
 #[derive(Serialize, Deserialize, Debug)]
-pub struct CreateTerminalCheckoutBodyBuildError;
+pub enum RegisterDomainError {
+    /// A request to the Square API failed.
+    Square(SquareError),
+    /// None of the sites returned by [Sites::list](crate::api::sites::Sites::list) has the given
+    /// id and a domain.
+    SiteNotFound(String),
+}
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct CreateTerminalRefundBodyBuildError;
+impl From<SquareError> for RegisterDomainError {
+    fn from(error: SquareError) -> Self {
+        RegisterDomainError::Square(error)
+    }
+}
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct BuildError;
+impl fmt::Display for RegisterDomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegisterDomainError::Square(error) => {
+                write!(f, "the request to the Square API failed: {:?}", error)
+            },
+            RegisterDomainError::SiteNotFound(site_id) => {
+                write!(f, "no site with the id {} and a domain was found", site_id)
+            },
+        }
+    }
+}
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct ValidationError;
\ No newline at end of file
+impl std::error::Error for RegisterDomainError {}
+
+/// The reasons an operation on [Money](crate::objects::Money) can fail.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum MoneyError {
+    /// The amounts are in different currencies, the first one given is the currency of `self`.
+    CurrencyMismatch(Currency, Currency),
+    /// The result does not fit into an `i64`.
+    Overflow,
+    /// The amount was split into zero parts.
+    InvalidSplit,
+}
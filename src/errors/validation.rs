@@ -0,0 +1,342 @@
+// The field-level diagnostics returned when a builder fails to validate the object it holds.
+
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// A single step of a [FieldPath](FieldPath).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A named field of an object.
+    Field(&'static str),
+    /// The position of an element in a list.
+    Index(usize),
+    /// The key of an element in a map.
+    Key(String),
+}
+
+/// The location of a field within an object, relative to the object held by the outermost
+/// [Builder](crate::builder::Builder), e.g. `order.line_items[2].quantity`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldPath(Vec<PathSegment>);
+
+impl FieldPath {
+    /// The empty path, which points to the object itself.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Extends the path with a named field.
+    pub fn field(mut self, name: &'static str) -> Self {
+        self.0.push(PathSegment::Field(name));
+
+        self
+    }
+
+    /// Extends the path with the position of an element in a list.
+    pub fn index(mut self, index: usize) -> Self {
+        self.0.push(PathSegment::Index(index));
+
+        self
+    }
+
+    /// Extends the path with the key of an element in a map.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.0.push(PathSegment::Key(key.into()));
+
+        self
+    }
+
+    /// The path to `other`, taken relative to the field this path points to.
+    pub fn join(&self, other: &FieldPath) -> Self {
+        let mut path = self.clone();
+        path.0.extend(other.0.iter().cloned());
+
+        path
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Converts a dotted path of field names such as `"booking.customer_id"`.
+impl From<&'static str> for FieldPath {
+    fn from(path: &'static str) -> Self {
+        FieldPath(path.split('.')
+            .filter(|name| !name.is_empty())
+            .map(PathSegment::Field)
+            .collect())
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+                PathSegment::Key(key) => write!(f, "[{:?}]", key)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Serialize for FieldPath {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Why a field failed validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationReason {
+    /// The field has to be set.
+    Required,
+    /// The list has to hold at least one element.
+    Empty,
+    /// At least one of the named fields has to be set.
+    OneOfRequired(Vec<&'static str>),
+    /// The value of the field is not accepted, with an explanation of why.
+    Invalid(String),
+}
+
+impl fmt::Display for ViolationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationReason::Required => write!(f, "required"),
+            ViolationReason::Empty => write!(f, "must not be empty"),
+            ViolationReason::OneOfRequired(fields) => {
+                write!(f, "one of {} required", fields.join(", "))
+            },
+            ViolationReason::Invalid(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl Serialize for ViolationReason {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// A field that failed validation, e.g. `order.line_items[2].quantity: required`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub path: FieldPath,
+    pub reason: ViolationReason,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.reason)
+        } else {
+            write!(f, "{}: {}", self.path, self.reason)
+        }
+    }
+}
+
+/// All the violations found while validating an object, returned by
+/// [Validate](crate::builder::Validate) implementations.
+///
+/// # Example: Collecting the violations of an object
+/// ```rust
+/// use square_ox::errors::{ValidationError, ViolationReason};
+///
+/// let name: Option<String> = None;
+/// let quantity = String::new();
+///
+/// let mut errors = ValidationError::new();
+/// errors.require("name", &name);
+/// errors.check(!quantity.is_empty(), "quantity", ViolationReason::Required);
+///
+/// assert_eq!(errors.to_string(), "name: required; quantity: required");
+/// assert!(errors.into_result(()).is_err());
+/// ```
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationError {
+    violations: Vec<Violation>,
+}
+
+impl ValidationError {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a violation for the field at `path` with the given reason, unless `valid` holds.
+    pub fn check(&mut self, valid: bool, path: impl Into<FieldPath>, reason: ViolationReason) {
+        if !valid {
+            self.violations.push(Violation { path: path.into(), reason });
+        }
+    }
+
+    /// Records that the field at `path` is required, unless it is set.
+    pub fn require<T>(&mut self, path: impl Into<FieldPath>, value: &Option<T>) {
+        self.check(value.is_some(), path, ViolationReason::Required);
+    }
+
+    /// Records that one of the named fields of the object at `path` is required, unless at least
+    /// one of them is set.
+    pub fn require_one_of(&mut self, path: impl Into<FieldPath>, fields: &[(&'static str, bool)]) {
+        self.check(
+            fields.iter().any(|(_, set)| *set),
+            path,
+            ViolationReason::OneOfRequired(fields.iter().map(|(name, _)| *name).collect()),
+        );
+    }
+
+    /// Records that the value of the field at `path` is not accepted.
+    pub fn invalid(&mut self, path: impl Into<FieldPath>, reason: impl Into<String>) {
+        self.check(false, path, ViolationReason::Invalid(reason.into()));
+    }
+
+    /// Records the violations of an object nested within the field at `path`.
+    pub fn nested(&mut self, path: impl Into<FieldPath>, errors: ValidationError) {
+        let path = path.into();
+
+        self.violations.extend(errors.prefixed(&path).violations);
+    }
+
+    /// The same violations, with paths relative to the object holding the field at `path`.
+    pub fn prefixed(mut self, path: &FieldPath) -> Self {
+        for violation in self.violations.iter_mut() {
+            violation.path = path.join(&violation.path);
+        }
+
+        self
+    }
+
+    /// Returns the value if no violations were recorded, and the violations otherwise.
+    pub fn into_result<T>(self, value: T) -> Result<T, ValidationError> {
+        if self.violations.is_empty() {
+            Ok(value)
+        } else {
+            Err(self)
+        }
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", violation)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// The error returned when a [Builder](crate::builder::Builder) could not build the object it
+/// holds, naming the type of the object and all the fields that failed validation.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct BuildError {
+    object: &'static str,
+    violations: Vec<Violation>,
+}
+
+impl BuildError {
+    pub(crate) fn new<T>(errors: ValidationError) -> Self {
+        let type_name = std::any::type_name::<T>();
+
+        BuildError {
+            object: type_name.rsplit("::").next().unwrap_or(type_name),
+            violations: errors.violations,
+        }
+    }
+
+    /// The name of the type of the object that could not be built, e.g. `CreateOrderBody`.
+    pub fn object(&self) -> &'static str {
+        self.object
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not build {}: ", self.object)?;
+
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", violation)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+#[cfg(test)]
+mod test_validation {
+    use super::*;
+
+    #[test]
+    fn test_field_path_display() {
+        let path = FieldPath::from("order.line_items").index(2).field("quantity");
+        assert_eq!(path.to_string(), "order.line_items[2].quantity");
+
+        let path = FieldPath::new().field("vendors").key("VENDOR1").field("name");
+        assert_eq!(path.to_string(), "vendors[\"VENDOR1\"].name");
+
+        assert!(FieldPath::from("").is_empty());
+    }
+
+    #[test]
+    fn test_nested_violations() {
+        let mut line_item = ValidationError::new();
+        line_item.check(false, "quantity", ViolationReason::Required);
+        line_item.require_one_of("", &[("catalog_object_id", false), ("name", false)]);
+
+        let mut errors = ValidationError::new();
+        errors.require::<String>("order.location_id", &None);
+        errors.nested(FieldPath::from("order.line_items").index(2), line_item);
+
+        assert_eq!(
+            errors.to_string(),
+            "order.location_id: required; order.line_items[2].quantity: required; \
+             order.line_items[2]: one of catalog_object_id, name required"
+        );
+
+        let error = BuildError::new::<crate::objects::Order>(errors);
+        assert_eq!(error.object(), "Order");
+        assert_eq!(error.violations().len(), 3);
+        assert_eq!(
+            serde_json::to_value(&error.violations()[0]).unwrap(),
+            serde_json::json!({ "path": "order.location_id", "reason": "required" })
+        );
+    }
+
+    #[test]
+    fn test_into_result() {
+        assert_eq!(ValidationError::new().into_result(5), Ok(5));
+
+        let mut errors = ValidationError::new();
+        errors.invalid("version", "must be greater than 1");
+        assert_eq!(errors.into_result(5).unwrap_err().to_string(), "version: must be greater than 1");
+    }
+}
@@ -13,12 +13,27 @@
 //! For examples of how this library can be used, see the `examples` directory in the repository.
 //! The examples are a work in progress, although the `payments_api` example provides a good use case.
 //! It can be run with `cargo run --example payments_api`.
+//!
+//! # Features
+//! * `chrono` - Lets the builders accept `chrono` date, time and duration values, and enables
+//!   parsing the returned timestamps into them, see the [datetime](crate::datetime) module.
+//! * `time` - Lets the builders accept `time` date, time and duration values, and enables
+//!   parsing the returned timestamps into them, see the [datetime](crate::datetime) module.
+//! * `mock` - Enables the [mock](crate::mock) module, an in-process fake of the
+//!   [Square API](https://developer.squareup.com) for running tests offline.
 
 #![deny(clippy::all)]
 
+// lets the code generated by square-ox-derive refer to this crate as `::square_ox` from within it
+extern crate self as square_ox;
+
 pub mod client;
 pub mod api;
 pub mod errors;
 pub mod response;
 pub mod objects;
 pub mod builder;
+pub mod cassette;
+pub mod datetime;
+#[cfg(feature = "mock")]
+pub mod mock;
@@ -0,0 +1,132 @@
+// Fake of the Catalog endpoint. Temporary ids starting with `#` are replaced by new ids, also
+// within nested objects such as the variations of an item, and returned as id mappings.
+
+use super::{list, new_id, strings, MockRequest, MockResponse, State};
+use crate::datetime::now;
+
+use serde_json::{json, Value};
+
+pub(crate) fn handle(state: &mut State, request: &MockRequest) -> Option<MockResponse> {
+    let res = match request.route() {
+        ("GET", path) if path == ["catalog", "list"] => {
+            let types = request.query("types")
+                .map(|types| types.split(',').map(String::from).collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            list("objects", of_types(state, &types))
+        },
+        ("POST", path) if path == ["catalog", "search"] => {
+            let types = strings(&request.body["object_types"]);
+
+            list("objects", of_types(state, &types))
+        },
+        ("POST", path) if path == ["catalog", "object"] => {
+            let mut id_mappings = vec![];
+            let object = upsert(state, request.body["object"].clone(), &mut id_mappings);
+
+            MockResponse::ok(json!({ "catalog_object": object, "id_mappings": id_mappings }))
+        },
+        ("POST", path) if path == ["catalog", "batch-upsert"] => {
+            let mut id_mappings = vec![];
+            let mut objects = vec![];
+
+            for batch in request.body["batches"].as_array().cloned().unwrap_or_default() {
+                for object in batch["objects"].as_array().cloned().unwrap_or_default() {
+                    objects.push(upsert(state, object, &mut id_mappings));
+                }
+            }
+
+            MockResponse::ok(json!({ "objects": objects, "id_mappings": id_mappings }))
+        },
+        ("POST", path) if path == ["catalog", "batch-retrieve"] => {
+            let objects = strings(&request.body["object_ids"])
+                .iter()
+                .filter_map(|id| state.catalog.get(id).cloned())
+                .collect();
+
+            list("objects", objects)
+        },
+        ("POST", path) if path == ["catalog", "batch-delete"] => {
+            let deleted: Vec<String> = strings(&request.body["object_ids"])
+                .into_iter()
+                .filter(|id| state.catalog.remove(id).is_some())
+                .collect();
+
+            MockResponse::ok(json!({ "deleted_object_ids": deleted, "deleted_at": now() }))
+        },
+        ("GET", path) if path.len() == 3 && path[1] == "object" => {
+            match state.catalog.get(path[2]) {
+                Some(object) => MockResponse::ok(json!({ "object": object })),
+                None => MockResponse::not_found("Catalog object", path[2]),
+            }
+        },
+        ("DELETE", path) if path.len() == 3 && path[1] == "object" => {
+            match state.catalog.remove(path[2]) {
+                Some(_) => MockResponse::ok(json!({
+                    "deleted_object_ids": [path[2]],
+                    "deleted_at": now(),
+                })),
+                None => MockResponse::not_found("Catalog object", path[2]),
+            }
+        },
+        _ => return None,
+    };
+
+    Some(res)
+}
+
+fn of_types(state: &State, types: &[String]) -> Vec<Value> {
+    state.catalog.all()
+        .into_iter()
+        .filter(|object| {
+            types.is_empty() || types.iter().any(|t| object["type"].as_str() == Some(t))
+        })
+        .collect()
+}
+
+// stores the object with new ids for all temporary ids, bumping the version of existing objects
+fn upsert(state: &mut State, mut object: Value, id_mappings: &mut Vec<Value>) -> Value {
+    let mut temporary_ids = vec![];
+    collect_temporary_ids(&object, &mut temporary_ids);
+
+    for client_object_id in temporary_ids {
+        let object_id = new_id();
+        replace_id(&mut object, &client_object_id, &object_id);
+        id_mappings.push(json!({ "client_object_id": client_object_id, "object_id": object_id }));
+    }
+
+    let version = object["id"].as_str()
+        .and_then(|id| state.catalog.get(id))
+        .and_then(|existing| existing["version"].as_i64())
+        .unwrap_or(0);
+    object["version"] = json!(version + 1);
+    object["updated_at"] = json!(now());
+    object["is_deleted"] = json!(false);
+
+    state.catalog.insert(object)
+}
+
+fn collect_temporary_ids(value: &Value, ids: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(id)) = map.get("id") {
+                if id.starts_with('#') && !ids.contains(id) {
+                    ids.push(id.clone());
+                }
+            }
+            map.values().for_each(|value| collect_temporary_ids(value, ids));
+        },
+        Value::Array(values) => values.iter().for_each(|value| collect_temporary_ids(value, ids)),
+        _ => {},
+    }
+}
+
+// replaces the temporary id everywhere it is used, e.g. as the item_id of a variation
+fn replace_id(value: &mut Value, temporary_id: &str, id: &str) {
+    match value {
+        Value::String(s) if s == temporary_id => *s = id.to_string(),
+        Value::Object(map) => map.values_mut().for_each(|value| replace_id(value, temporary_id, id)),
+        Value::Array(values) => values.iter_mut().for_each(|value| replace_id(value, temporary_id, id)),
+        _ => {},
+    }
+}
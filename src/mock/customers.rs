@@ -0,0 +1,64 @@
+// Fake of the Customers endpoint.
+
+use super::{list, merge, new_id, MockRequest, MockResponse, State};
+
+use serde_json::{json, Value};
+
+pub(crate) fn handle(state: &mut State, request: &MockRequest) -> Option<MockResponse> {
+    let res = match request.route() {
+        ("GET", path) if path == ["customers"] => list("customers", state.customers.all()),
+        ("POST", path) if path == ["customers"] => {
+            let mut customer = request.body.clone();
+            if let Some(customer) = customer.as_object_mut() {
+                customer.remove("idempotency_key");
+            }
+            customer["id"] = json!(new_id());
+            customer["version"] = json!(0);
+
+            MockResponse::ok(json!({ "customer": state.customers.insert(customer) }))
+        },
+        ("POST", path) if path == ["customers", "search"] => {
+            let filter = &request.body["query"]["filter"];
+
+            let customers = state.customers.all()
+                .into_iter()
+                .filter(|customer| matches_filter(customer, filter))
+                .collect();
+
+            list("customers", customers)
+        },
+        ("GET", path) if path.len() == 2 => match state.customers.get(path[1]) {
+            Some(customer) => MockResponse::ok(json!({ "customer": customer })),
+            None => MockResponse::not_found("Customer", path[1]),
+        },
+        ("PUT", path) if path.len() == 2 => match state.customers.get_mut(path[1]) {
+            Some(customer) => {
+                merge(customer, &request.body);
+                customer["version"] = json!(customer["version"].as_i64().unwrap_or(0) + 1);
+
+                MockResponse::ok(json!({ "customer": customer }))
+            },
+            None => MockResponse::not_found("Customer", path[1]),
+        },
+        ("DELETE", path) if path.len() == 2 => match state.customers.remove(path[1]) {
+            Some(_) => MockResponse::ok(json!({})),
+            None => MockResponse::not_found("Customer", path[1]),
+        },
+        _ => return None,
+    };
+
+    Some(res)
+}
+
+// supports the exact and fuzzy email address, phone number and reference id filters
+fn matches_filter(customer: &Value, filter: &Value) -> bool {
+    ["email_address", "phone_number", "reference_id"].iter().all(|field| {
+        let value = customer[*field].as_str().unwrap_or_default();
+
+        match (&filter[*field]["exact"], &filter[*field]["fuzzy"]) {
+            (Value::String(exact), _) => value == exact,
+            (_, Value::String(fuzzy)) => value.to_lowercase().contains(&fuzzy.to_lowercase()),
+            _ => true,
+        }
+    })
+}
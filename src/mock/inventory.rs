@@ -0,0 +1,213 @@
+// Fake of the Inventory endpoint. Physical counts set the quantity of a state at a location,
+// adjustments move quantities between states and transfers move them between locations.
+
+use super::{list, new_id, strings, MockRequest, MockResponse, State};
+use crate::datetime::now;
+
+use serde_json::{json, Value};
+
+pub(crate) fn handle(state: &mut State, request: &MockRequest) -> Option<MockResponse> {
+    let res = match request.route() {
+        ("POST", path) if path == ["inventory", "changes", "batch-create"] => {
+            let mut changes = vec![];
+            let mut object_ids = vec![];
+
+            for change in request.body["changes"].as_array().cloned().unwrap_or_default() {
+                let change = apply(state, change);
+
+                if let Some(id) = inner(&change)["catalog_object_id"].as_str() {
+                    object_ids.push(id.to_string());
+                }
+                changes.push(change);
+            }
+
+            MockResponse::ok(json!({
+                "counts": counts(state, &object_ids, &[]),
+                "changes": changes,
+            }))
+        },
+        ("POST", path) if path == ["inventory", "changes", "batch-retrieve"] => {
+            let body = &request.body;
+            let object_ids = strings(&body["catalog_object_ids"]);
+            let location_ids = strings(&body["location_ids"]);
+            let types = strings(&body["types"]);
+
+            let changes = state.inventory_changes.iter()
+                .filter(|change| {
+                    let inner = inner(change);
+
+                    matches(&object_ids, &inner["catalog_object_id"])
+                        && (location_ids.is_empty()
+                            || location_ids.iter().any(|id| involves_location(inner, id)))
+                        && matches(&types, &change["type"])
+                })
+                .cloned()
+                .collect();
+
+            list("changes", changes)
+        },
+        ("POST", path) if path == ["inventory", "counts", "batch-retrieve"] => {
+            let object_ids = strings(&request.body["catalog_object_ids"]);
+            let location_ids = strings(&request.body["location_ids"]);
+
+            list("counts", counts(state, &object_ids, &location_ids))
+        },
+        ("GET", path) if path.len() == 3 && path[2] == "changes" => {
+            let changes = state.inventory_changes.iter()
+                .filter(|change| inner(change)["catalog_object_id"] == path[1])
+                .cloned()
+                .collect();
+
+            list("changes", changes)
+        },
+        ("GET", path) if path.len() == 3 => {
+            let (key, kind) = match path[1] {
+                "adjustments" => ("adjustment", "Inventory adjustment"),
+                "physical-counts" => ("count", "Inventory physical count"),
+                "transfers" | "transfer" => ("transfer", "Inventory transfer"),
+                _ => return None,
+            };
+
+            let found = state.inventory_changes.iter()
+                .map(inner)
+                .find(|inner| inner["id"] == path[2]);
+
+            match found {
+                Some(inner) => MockResponse::ok(json!({ key: inner })),
+                None => MockResponse::not_found(kind, path[2]),
+            }
+        },
+        ("GET", path) if path.len() == 2 => {
+            let location_ids = request.query("location_ids")
+                .map(|ids| ids.split(',').map(String::from).collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            list("counts", counts(state, &[path[1].to_string()], &location_ids))
+        },
+        _ => return None,
+    };
+
+    Some(res)
+}
+
+// the adjustment, physical count or transfer held by a change
+fn inner(change: &Value) -> &Value {
+    match change["type"].as_str() {
+        Some("ADJUSTMENT") => &change["adjustment"],
+        Some("TRANSFER") => &change["transfer"],
+        _ => &change["physical_count"],
+    }
+}
+
+fn involves_location(inner: &Value, location_id: &str) -> bool {
+    ["location_id", "from_location_id", "to_location_id"].iter()
+        .any(|field| inner[*field] == location_id)
+}
+
+fn matches(filter: &[String], value: &Value) -> bool {
+    filter.is_empty() || filter.iter().any(|f| value.as_str() == Some(f))
+}
+
+// applies the change to the counts, stores it and returns it with its new id
+fn apply(state: &mut State, mut change: Value) -> Value {
+    let key = match change["type"].as_str() {
+        Some("ADJUSTMENT") => "adjustment",
+        Some("TRANSFER") => "transfer",
+        _ => "physical_count",
+    };
+
+    {
+        let inner = &mut change[key];
+        inner["id"] = json!(new_id());
+        inner["created_at"] = json!(now());
+        if inner["occurred_at"].is_null() {
+            inner["occurred_at"] = json!(now());
+        }
+    }
+
+    let inner = change[key].clone();
+    let object_id = inner["catalog_object_id"].as_str().unwrap_or_default();
+    let quantity = quantity(&inner["quantity"]);
+
+    match key {
+        "adjustment" => {
+            let location_id = inner["location_id"].as_str().unwrap_or_default();
+            let from_state = inner["from_state"].as_str().unwrap_or("NONE");
+            let to_state = inner["to_state"].as_str().unwrap_or("NONE");
+
+            add(state, object_id, location_id, from_state, -quantity);
+            add(state, object_id, location_id, to_state, quantity);
+        },
+        "transfer" => {
+            let item_state = inner["state"].as_str().unwrap_or("IN_STOCK");
+
+            add(state, object_id, inner["from_location_id"].as_str().unwrap_or_default(),
+                item_state, -quantity);
+            add(state, object_id, inner["to_location_id"].as_str().unwrap_or_default(),
+                item_state, quantity);
+        },
+        _ => {
+            let location_id = inner["location_id"].as_str().unwrap_or_default();
+            let item_state = inner["state"].as_str().unwrap_or("IN_STOCK");
+
+            set(state, object_id, location_id, item_state, quantity);
+        },
+    }
+
+    state.inventory_changes.push(change.clone());
+
+    change
+}
+
+fn quantity(value: &Value) -> f64 {
+    value.as_str().and_then(|quantity| quantity.parse().ok()).unwrap_or(0.0)
+}
+
+fn add(state: &mut State, object_id: &str, location_id: &str, item_state: &str, quantity: f64) {
+    // quantities in the NONE state are untracked, e.g. stock received from a vendor
+    if item_state == "NONE" {
+        return;
+    }
+
+    let current = state.inventory_counts.iter()
+        .find(|((o, l, s), _)| o == object_id && l == location_id && s == item_state)
+        .map(|(_, quantity)| *quantity)
+        .unwrap_or(0.0);
+
+    set(state, object_id, location_id, item_state, current + quantity);
+}
+
+fn set(state: &mut State, object_id: &str, location_id: &str, item_state: &str, quantity: f64) {
+    let key = (object_id.to_string(), location_id.to_string(), item_state.to_string());
+
+    match state.inventory_counts.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, existing)) => *existing = quantity,
+        None => state.inventory_counts.push((key, quantity)),
+    }
+}
+
+fn counts(state: &State, object_ids: &[String], location_ids: &[String]) -> Vec<Value> {
+    state.inventory_counts.iter()
+        .filter(|((object_id, location_id, _), _)| {
+            (object_ids.is_empty() || object_ids.contains(object_id))
+                && (location_ids.is_empty() || location_ids.contains(location_id))
+        })
+        .map(|((object_id, location_id, item_state), quantity)| json!({
+            "catalog_object_id": object_id,
+            "catalog_object_type": "ITEM_VARIATION",
+            "location_id": location_id,
+            "state": item_state,
+            "quantity": format_quantity(*quantity),
+            "calculated_at": now(),
+        }))
+        .collect()
+}
+
+// formats the quantity as a decimal without trailing zeros, e.g. 5 or 2.5
+fn format_quantity(quantity: f64) -> String {
+    if quantity.fract() == 0.0 {
+        format!("{:.0}", quantity)
+    } else {
+        format!("{}", quantity)
+    }
+}
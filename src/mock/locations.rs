@@ -0,0 +1,35 @@
+// Fake of the Locations endpoint.
+
+use super::{list, merge, new_id, MockRequest, MockResponse, State};
+
+use serde_json::json;
+
+pub(crate) fn handle(state: &mut State, request: &MockRequest) -> Option<MockResponse> {
+    let res = match request.route() {
+        ("GET", path) if path == ["locations"] => list("locations", state.locations.all()),
+        ("POST", path) if path == ["locations"] => {
+            let mut location = request.body["location"].clone();
+            location["id"] = json!(new_id());
+            if location["status"].is_null() {
+                location["status"] = json!("ACTIVE");
+            }
+
+            MockResponse::ok(json!({ "location": state.locations.insert(location) }))
+        },
+        ("GET", path) if path.len() == 2 => match state.locations.get(path[1]) {
+            Some(location) => MockResponse::ok(json!({ "location": location })),
+            None => MockResponse::not_found("Location", path[1]),
+        },
+        ("PUT", path) if path.len() == 2 => match state.locations.get_mut(path[1]) {
+            Some(location) => {
+                merge(location, &request.body["location"]);
+
+                MockResponse::ok(json!({ "location": location }))
+            },
+            None => MockResponse::not_found("Location", path[1]),
+        },
+        _ => return None,
+    };
+
+    Some(res)
+}
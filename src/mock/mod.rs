@@ -0,0 +1,396 @@
+/*!
+An in-process mock of the [Square API](https://developer.squareup.com) for offline testing,
+available behind the `mock` feature.
+
+The [MockServer](MockServer) listens on a random local port and keeps stateful fakes of the
+Locations, Customers, Catalog, Payments, Orders and Inventory endpoints, so that objects created
+through one request can be retrieved, updated and deleted through the following ones. Endpoints
+that are not faked respond with a `NOT_FOUND` error.
+
+# Example: Create and retrieve a customer without network access
+```rust
+use square_ox::{
+    mock::MockServer,
+    objects::Customer,
+};
+
+async {
+    let server = MockServer::start();
+    let client = server.client();
+
+    let res = client.customers()
+        .create(Customer {
+            given_name: Some("Amelia".to_string()),
+            ..Default::default()
+        })
+        .await;
+
+    assert!(res.is_ok());
+};
+```
+ */
+
+mod catalog;
+mod customers;
+mod inventory;
+mod locations;
+mod orders;
+mod payments;
+mod server;
+
+use crate::client::{SquareClient, Url};
+
+use serde_json::{json, Map, Value};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use uuid::Uuid;
+
+/// The id of the location every [MockServer](MockServer) starts out with.
+pub const DEFAULT_LOCATION_ID: &str = "MOCKLOCATION1";
+
+/// A local HTTP server faking the [Square API](https://developer.squareup.com). The server is
+/// shut down when it is dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    running: Arc<AtomicBool>,
+}
+
+impl MockServer {
+    /// Start a new [MockServer](MockServer) on a random local port, with a single location
+    /// with the id [DEFAULT_LOCATION_ID](DEFAULT_LOCATION_ID).
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .expect("the mock server to bind to a local port");
+        let addr = listener.local_addr().expect("the mock server to have a local address");
+        let running = Arc::new(AtomicBool::new(true));
+        let state = Arc::new(Mutex::new(State::seeded()));
+
+        let still_running = running.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if !still_running.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                if let Ok(stream) = stream {
+                    let state = state.clone();
+                    thread::spawn(move || server::serve(stream, &state));
+                }
+            }
+        });
+
+        MockServer { addr, running }
+    }
+
+    /// The base URL of the mock server, e.g. `http://127.0.0.1:49152/v2/`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}/v2/", self.addr)
+    }
+
+    /// A [SquareClient](SquareClient) that sends all of its requests to this mock server.
+    pub fn client(&self) -> SquareClient {
+        let base_url = Url::parse(&self.base_url()).expect("the mock server to have a valid URL");
+
+        SquareClient::new("mock_access_token").custom(base_url)
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+
+        // wake up the listener so that it notices the server is no longer running
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Fake state shared by all endpoints
+// -------------------------------------------------------------------------------------------------
+#[derive(Default)]
+pub(crate) struct State {
+    pub(crate) locations: Collection,
+    pub(crate) customers: Collection,
+    pub(crate) catalog: Collection,
+    pub(crate) payments: Collection,
+    // the ids of the payments created with each idempotency key
+    pub(crate) payment_idempotency_keys: Vec<(String, String)>,
+    pub(crate) orders: Collection,
+    pub(crate) inventory_changes: Vec<Value>,
+    // the quantities keyed by catalog object id, location id and inventory state
+    pub(crate) inventory_counts: Vec<((String, String, String), f64)>,
+}
+
+impl State {
+    fn seeded() -> Self {
+        let mut state = State::default();
+
+        state.locations.insert(json!({
+            "id": DEFAULT_LOCATION_ID,
+            "name": "Default Test Account",
+            "status": "ACTIVE",
+            "type": "PHYSICAL",
+            "currency": "USD",
+            "country": "US",
+        }));
+
+        state
+    }
+}
+
+/// A list of JSON objects that are identified by their `id` field, kept in insertion order.
+#[derive(Default)]
+pub(crate) struct Collection(Vec<Value>);
+
+impl Collection {
+    pub(crate) fn all(&self) -> Vec<Value> {
+        self.0.clone()
+    }
+
+    pub(crate) fn get(&self, id: &str) -> Option<&Value> {
+        self.0.iter().find(|value| value["id"] == id)
+    }
+
+    pub(crate) fn get_mut(&mut self, id: &str) -> Option<&mut Value> {
+        self.0.iter_mut().find(|value| value["id"] == id)
+    }
+
+    // inserts the value, replacing any value with the same id, and returns it
+    pub(crate) fn insert(&mut self, value: Value) -> Value {
+        match self.0.iter_mut().find(|existing| existing["id"] == value["id"]) {
+            Some(existing) => *existing = value.clone(),
+            None => self.0.push(value.clone()),
+        }
+
+        value
+    }
+
+    pub(crate) fn remove(&mut self, id: &str) -> Option<Value> {
+        let index = self.0.iter().position(|value| value["id"] == id)?;
+
+        Some(self.0.remove(index))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Requests and responses of the mock server
+// -------------------------------------------------------------------------------------------------
+pub(crate) struct MockRequest {
+    pub(crate) verb: String,
+    // the segments of the path following /v2/
+    pub(crate) path: Vec<String>,
+    pub(crate) query: Vec<(String, String)>,
+    pub(crate) body: Value,
+    pub(crate) square_version: Option<String>,
+}
+
+impl MockRequest {
+    pub(crate) fn route(&self) -> (&str, Vec<&str>) {
+        (self.verb.as_str(), self.path.iter().map(String::as_str).collect())
+    }
+
+    pub(crate) fn query(&self, key: &str) -> Option<&str> {
+        self.query.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+}
+
+pub(crate) struct MockResponse {
+    pub(crate) status: u16,
+    pub(crate) body: Value,
+}
+
+impl MockResponse {
+    pub(crate) fn ok(body: Value) -> Self {
+        MockResponse { status: 200, body }
+    }
+
+    pub(crate) fn error(status: u16, category: &str, code: &str, detail: String) -> Self {
+        MockResponse {
+            status,
+            body: json!({
+                "errors": [{ "category": category, "code": code, "detail": detail }]
+            }),
+        }
+    }
+
+    pub(crate) fn not_found(kind: &str, id: &str) -> Self {
+        Self::error(404, "INVALID_REQUEST_ERROR", "NOT_FOUND",
+                    format!("{} with id `{}` not found.", kind, id))
+    }
+
+    pub(crate) fn bad_request(code: &str, detail: &str) -> Self {
+        Self::error(400, "INVALID_REQUEST_ERROR", code, detail.to_string())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Helpers shared by the fakes
+// -------------------------------------------------------------------------------------------------
+
+// a new random id in the style of the ids generated by Square
+pub(crate) fn new_id() -> String {
+    Uuid::new_v4().to_simple().to_string().to_uppercase()[..24].to_string()
+}
+
+// a response holding the values under the given key, Square leaves out empty lists
+pub(crate) fn list(key: &str, values: Vec<Value>) -> MockResponse {
+    let mut body = Map::new();
+
+    if !values.is_empty() {
+        body.insert(key.to_string(), Value::Array(values));
+    }
+
+    MockResponse::ok(Value::Object(body))
+}
+
+// copies all fields of the patch, apart from null ones, onto the target
+pub(crate) fn merge(target: &mut Value, patch: &Value) {
+    if let (Some(target), Some(patch)) = (target.as_object_mut(), patch.as_object()) {
+        for (key, value) in patch {
+            if !value.is_null() {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+// the string values of the array at the given key of the body
+pub(crate) fn strings(value: &Value) -> Vec<String> {
+    value.as_array()
+        .map(|values| values.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test_mock {
+    use super::*;
+    use crate::api::customers::CustomerDelete;
+    use crate::api::payment::PaymentRequest;
+    use crate::builder::Builder;
+    use crate::objects::{Customer, Response};
+    use crate::objects::enums::Currency;
+
+    #[tokio::test]
+    async fn test_list_default_location() {
+        let server = MockServer::start();
+
+        let res = server.client().locations().list().await.unwrap();
+
+        match res.response {
+            Some(Response::Locations(locations)) => {
+                assert_eq!(locations.len(), 1);
+                assert_eq!(locations[0].id, Some(DEFAULT_LOCATION_ID.into()));
+            },
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_customer_lifecycle() {
+        let server = MockServer::start();
+        let client = server.client();
+
+        let res = client.customers()
+            .create(Customer {
+                given_name: Some("Amelia".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let id = match res.response {
+            Some(Response::Customer(customer)) => customer.id.unwrap(),
+            other => panic!("unexpected response: {:?}", other),
+        };
+
+        let delete = Builder::from(CustomerDelete::default())
+            .customer_id(id.clone())
+            .build_sync()
+            .unwrap();
+        assert!(client.customers().delete(delete).await.is_ok());
+
+        let delete = Builder::from(CustomerDelete::default())
+            .customer_id(id)
+            .build_sync()
+            .unwrap();
+        assert!(client.customers().delete(delete).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_payment_and_declined_payment() {
+        let server = MockServer::start();
+        let client = server.client();
+
+        let payment = Builder::from(PaymentRequest::default())
+            .source_id("cnon:card-nonce-ok".to_string())
+            .amount(1000, Currency::USD)
+            .build_sync()
+            .unwrap();
+
+        let id = match client.payments().create(payment).await.unwrap().response {
+            Some(Response::Payment(payment)) => payment.id.unwrap(),
+            other => panic!("unexpected response: {:?}", other),
+        };
+        assert!(client.payments().get(id).await.is_ok());
+
+        let declined = Builder::from(PaymentRequest::default())
+            .source_id("cnon:card-nonce-declined".to_string())
+            .amount(1000, Currency::USD)
+            .build_sync()
+            .unwrap();
+        assert!(client.payments().create(declined).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_payment_with_idempotency_key() {
+        use crate::client::RequestOptions;
+
+        let server = MockServer::start();
+        let client = server.client();
+        let options = RequestOptions::new().idempotency_key("order-1234-payment");
+
+        let mut ids = vec![];
+        for _ in 0..2 {
+            let payment = Builder::from(PaymentRequest::default())
+                .source_id("cnon:card-nonce-ok".to_string())
+                .amount(1000, Currency::USD)
+                .build_sync()
+                .unwrap();
+
+            match client.with_options(options.clone()).payments().create(payment).await {
+                Ok(res) => match res.response {
+                    Some(Response::Payment(payment)) => ids.push(payment.id.unwrap()),
+                    other => panic!("unexpected response: {:?}", other),
+                },
+                Err(e) => panic!("unexpected error: {:?}", e),
+            }
+        }
+
+        assert_eq!(ids[0], ids[1]);
+    }
+
+    #[tokio::test]
+    async fn test_square_version() {
+        use crate::client::SQUARE_VERSION;
+
+        let server = MockServer::start();
+        let client = server.client();
+
+        let res = client.locations().list().await.unwrap();
+        assert_eq!(res.square_version, Some(SQUARE_VERSION.to_string()));
+
+        let res = client.with_square_version("2022-08-23").locations().list().await.unwrap();
+        assert_eq!(res.square_version, Some("2022-08-23".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_endpoint() {
+        let server = MockServer::start();
+
+        let res = server.client().sites().list().await;
+
+        assert!(res.is_err())
+    }
+}
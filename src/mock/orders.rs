@@ -0,0 +1,147 @@
+// Fake of the Orders endpoint. The totals of an order are calculated from the base prices and
+// quantities of its line items, taxes, discounts and service charges are not taken into account.
+
+use super::{list, merge, new_id, strings, MockRequest, MockResponse, State};
+use crate::datetime::now;
+
+use serde_json::{json, Value};
+
+pub(crate) fn handle(state: &mut State, request: &MockRequest) -> Option<MockResponse> {
+    let res = match request.route() {
+        ("POST", path) if path == ["orders"] => {
+            let mut order = request.body["order"].clone();
+            order["id"] = json!(new_id());
+            order["state"] = json!("OPEN");
+            order["version"] = json!(1);
+            order["created_at"] = json!(now());
+            calculate(&mut order);
+
+            MockResponse::ok(json!({ "order": state.orders.insert(order) }))
+        },
+        ("POST", path) if path == ["orders", "calculate"] => {
+            let mut order = request.body["order"].clone();
+            calculate(&mut order);
+
+            MockResponse::ok(json!({ "order": order }))
+        },
+        ("POST", path) if path == ["orders", "batch-retrieve"] => {
+            let orders = strings(&request.body["order_ids"])
+                .iter()
+                .filter_map(|id| state.orders.get(id).cloned())
+                .collect();
+
+            list("orders", orders)
+        },
+        ("POST", path) if path == ["orders", "search"] => search(state, &request.body),
+        ("POST", path) if path == ["orders", "clone"] => {
+            let id = request.body["order_id"].as_str().unwrap_or_default();
+
+            match state.orders.get(id).cloned() {
+                Some(mut order) => {
+                    order["id"] = json!(new_id());
+                    order["state"] = json!("DRAFT");
+                    order["version"] = json!(1);
+                    order["created_at"] = json!(now());
+
+                    MockResponse::ok(json!({ "order": state.orders.insert(order) }))
+                },
+                None => MockResponse::not_found("Order", id),
+            }
+        },
+        ("GET", path) if path.len() == 2 => match state.orders.get(path[1]) {
+            Some(order) => MockResponse::ok(json!({ "order": order })),
+            None => MockResponse::not_found("Order", path[1]),
+        },
+        ("PUT", path) if path.len() == 2 => {
+            let patch = &request.body["order"];
+
+            match state.orders.get_mut(path[1]) {
+                Some(order) if !patch["version"].is_null() && patch["version"] != order["version"] => {
+                    MockResponse::bad_request("VERSION_MISMATCH", "Order version mismatch.")
+                },
+                Some(order) => {
+                    merge(order, patch);
+                    bump_version(order);
+                    calculate(order);
+
+                    MockResponse::ok(json!({ "order": order }))
+                },
+                None => MockResponse::not_found("Order", path[1]),
+            }
+        },
+        ("POST", path) if path.len() == 3 && path[2] == "pay" => {
+            match state.orders.get_mut(path[1]) {
+                Some(order) => {
+                    order["state"] = json!("COMPLETED");
+                    order["closed_at"] = json!(now());
+                    bump_version(order);
+
+                    MockResponse::ok(json!({ "order": order }))
+                },
+                None => MockResponse::not_found("Order", path[1]),
+            }
+        },
+        _ => return None,
+    };
+
+    Some(res)
+}
+
+fn search(state: &State, body: &Value) -> MockResponse {
+    let location_ids = strings(&body["location_ids"]);
+    let orders: Vec<Value> = state.orders.all()
+        .into_iter()
+        .filter(|order| {
+            location_ids.is_empty()
+                || location_ids.iter().any(|id| order["location_id"].as_str() == Some(id))
+        })
+        .collect();
+
+    if body["return_entries"] == true {
+        let entries = orders.iter()
+            .map(|order| json!({
+                "order_id": order["id"],
+                "location_id": order["location_id"],
+                "version": order["version"],
+            }))
+            .collect();
+
+        list("order_entries", entries)
+    } else {
+        list("orders", orders)
+    }
+}
+
+fn bump_version(order: &mut Value) {
+    order["version"] = json!(order["version"].as_i64().unwrap_or(0) + 1);
+    order["updated_at"] = json!(now());
+}
+
+// sets the total money of every line item and of the order
+fn calculate(order: &mut Value) {
+    let mut total = 0;
+    let mut currency = json!("USD");
+
+    if let Some(line_items) = order["line_items"].as_array_mut() {
+        for line_item in line_items {
+            if line_item["uid"].is_null() {
+                line_item["uid"] = json!(new_id());
+            }
+
+            let quantity: f64 = line_item["quantity"].as_str()
+                .and_then(|quantity| quantity.parse().ok())
+                .unwrap_or(0.0);
+            let base_price = line_item["base_price_money"]["amount"].as_i64().unwrap_or(0);
+            let amount = (base_price as f64 * quantity).round() as i64;
+
+            if let Some(line_item_currency) = line_item["base_price_money"].get("currency") {
+                currency = line_item_currency.clone();
+            }
+
+            line_item["total_money"] = json!({ "amount": amount, "currency": currency });
+            total += amount;
+        }
+    }
+
+    order["total_money"] = json!({ "amount": total, "currency": currency });
+}
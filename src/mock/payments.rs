@@ -0,0 +1,106 @@
+// Fake of the Payments endpoint. Payments are completed right away unless `autocomplete` is
+// false, and the sandbox nonce `cnon:card-nonce-declined` is declined. A payment created with an
+// idempotency key that was used before returns the payment created the first time.
+
+use super::{list, merge, new_id, MockRequest, MockResponse, State};
+use crate::datetime::now;
+
+use serde_json::{json, Value};
+
+pub(crate) fn handle(state: &mut State, request: &MockRequest) -> Option<MockResponse> {
+    let res = match request.route() {
+        ("GET", path) if path == ["payments"] => {
+            let payments = state.payments.all()
+                .into_iter()
+                .filter(|payment| match request.query("location_id") {
+                    Some(location_id) => payment["location_id"] == location_id,
+                    None => true,
+                })
+                .collect();
+
+            list("payments", payments)
+        },
+        ("POST", path) if path == ["payments"] => create(state, &request.body),
+        ("POST", path) if path == ["payments", "cancel"] => MockResponse::ok(json!({})),
+        ("GET", path) if path.len() == 2 => match state.payments.get(path[1]) {
+            Some(payment) => MockResponse::ok(json!({ "payment": payment })),
+            None => MockResponse::not_found("Payment", path[1]),
+        },
+        ("PUT", path) if path.len() == 2 => match state.payments.get_mut(path[1]) {
+            Some(payment) => {
+                merge(payment, &request.body["payment"]);
+                payment["updated_at"] = json!(now());
+
+                MockResponse::ok(json!({ "payment": payment }))
+            },
+            None => MockResponse::not_found("Payment", path[1]),
+        },
+        ("POST", path) if path.len() == 3 && (path[2] == "cancel" || path[2] == "complete") => {
+            let status = if path[2] == "cancel" { "CANCELED" } else { "COMPLETED" };
+
+            match state.payments.get_mut(path[1]) {
+                Some(payment) if payment["status"] == "APPROVED" => {
+                    payment["status"] = json!(status);
+                    payment["updated_at"] = json!(now());
+
+                    MockResponse::ok(json!({ "payment": payment }))
+                },
+                Some(_) => MockResponse::bad_request(
+                    "BAD_REQUEST", "Only APPROVED payments can be canceled or completed."
+                ),
+                None => MockResponse::not_found("Payment", path[1]),
+            }
+        },
+        _ => return None,
+    };
+
+    Some(res)
+}
+
+fn create(state: &mut State, body: &Value) -> MockResponse {
+    let source_id = body["source_id"].as_str().unwrap_or_default();
+
+    if source_id.is_empty() {
+        return MockResponse::bad_request("MISSING_REQUIRED_PARAMETER", "Missing source_id.");
+    }
+    if source_id == "cnon:card-nonce-declined" {
+        return MockResponse::error(400, "PAYMENT_METHOD_ERROR", "GENERIC_DECLINE",
+                                   "Authorization error: 'GENERIC_DECLINE'".to_string());
+    }
+
+    let idempotency_key = body["idempotency_key"].as_str().unwrap_or_default().to_string();
+    let previous = state.payment_idempotency_keys.iter()
+        .find(|(key, _)| !key.is_empty() && key == &idempotency_key)
+        .and_then(|(_, id)| state.payments.get(id));
+    if let Some(payment) = previous {
+        return MockResponse::ok(json!({ "payment": payment }));
+    }
+
+    let source_type = match source_id {
+        "CASH" => "CASH",
+        "EXTERNAL" => "EXTERNAL",
+        _ => "CARD",
+    };
+    let status = if body["autocomplete"] == false { "APPROVED" } else { "COMPLETED" };
+
+    let mut payment = json!({
+        "id": new_id(),
+        "created_at": now(),
+        "updated_at": now(),
+        "status": status,
+        "source_type": source_type,
+        "amount_money": body["amount_money"],
+        "total_money": body["amount_money"],
+    });
+
+    for field in ["location_id", "order_id", "customer_id", "reference_id", "note",
+                      "app_fee_money", "buyer_email_address"] {
+        if !body[field].is_null() {
+            payment[field] = body[field].clone();
+        }
+    }
+
+    state.payment_idempotency_keys.push((idempotency_key, payment["id"].as_str().unwrap().to_string()));
+
+    MockResponse::ok(json!({ "payment": state.payments.insert(payment) }))
+}
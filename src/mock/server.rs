@@ -0,0 +1,191 @@
+// A minimal HTTP/1.1 server for the mock. Every connection handles a single request and is closed
+// after the response has been written.
+
+use super::{catalog, customers, inventory, locations, orders, payments};
+use super::{MockRequest, MockResponse, State};
+use crate::client::SQUARE_VERSION;
+
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub(crate) fn serve(stream: TcpStream, state: &Mutex<State>) {
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+
+    let mut square_version = SQUARE_VERSION.to_string();
+    let response = match read_request(&stream) {
+        Some((request, authorized)) => {
+            if let Some(version) = &request.square_version {
+                square_version = version.clone();
+            }
+
+            if authorized {
+                let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+                dispatch(&mut state, &request)
+            } else {
+                MockResponse::error(401, "AUTHENTICATION_ERROR", "UNAUTHORIZED",
+                                    "This request could not be authorized.".to_string())
+            }
+        },
+        None => MockResponse::bad_request("BAD_REQUEST", "Malformed HTTP request."),
+    };
+
+    let _ = write_response(stream, response, &square_version);
+}
+
+fn dispatch(state: &mut State, request: &MockRequest) -> MockResponse {
+    let handled = match request.path.first().map(String::as_str) {
+        Some("locations") => locations::handle(state, request),
+        Some("customers") => customers::handle(state, request),
+        Some("catalog") => catalog::handle(state, request),
+        Some("payments") => payments::handle(state, request),
+        Some("orders") => orders::handle(state, request),
+        Some("inventory") => inventory::handle(state, request),
+        _ => None,
+    };
+
+    handled.unwrap_or_else(|| MockResponse::error(
+        404, "INVALID_REQUEST_ERROR", "NOT_FOUND",
+        format!("The mock server does not implement {} /v2/{}.",
+                request.verb, request.path.join("/")),
+    ))
+}
+
+// reads the request from the stream, returning it together with whether it carried a bearer token
+fn read_request(stream: &TcpStream) -> Option<(MockRequest, bool)> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let mut request_line = request_line.split_whitespace();
+    let verb = request_line.next()?.to_string();
+    let target = request_line.next()?.to_string();
+
+    let mut content_length = 0;
+    let mut authorized = false;
+    let mut square_version = None;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        let (name, value) = line.split_once(':')?;
+        let value = value.trim();
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => content_length = value.parse().ok()?,
+            "authorization" => authorized = value.len() > "Bearer ".len()
+                && value.starts_with("Bearer "),
+            "square-version" => square_version = Some(value.to_string()),
+            _ => {},
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_query(query)),
+        None => (target, vec![]),
+    };
+
+    let path = path.trim_start_matches('/')
+        .trim_start_matches("v2")
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(decode)
+        .collect();
+
+    // bodies that are not JSON, such as multipart uploads, are treated as empty
+    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+    Some((MockRequest { verb, path, query, body, square_version }, authorized))
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (decode(key), decode(value)),
+            None => (decode(pair), String::new()),
+        })
+        .collect()
+}
+
+// decodes a percent-encoded URL component
+fn decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut res = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => res.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+                match hex {
+                    Some(byte) => {
+                        res.push(byte);
+                        i += 2;
+                    },
+                    None => res.push(b'%'),
+                }
+            },
+            byte => res.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&res).into_owned()
+}
+
+// the response echoes the version of the request, like Square does for versions it supports
+fn write_response(mut stream: TcpStream, response: MockResponse, square_version: &str)
+                  -> std::io::Result<()> {
+    let body = response.body.to_string();
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        _ => "Error",
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Square-Version: {}\r\nConnection: close\r\n\r\n{}",
+        response.status, reason, body.len(), square_version, body
+    )?;
+
+    stream.flush()
+}
+
+#[cfg(test)]
+mod test_server {
+    use super::*;
+
+    #[test]
+    fn test_parse_query() {
+        let expected = vec![
+            ("types".to_string(), "ITEM,TAX".to_string()),
+            ("cursor".to_string(), "a b".to_string()),
+        ];
+
+        assert_eq!(expected, parse_query("types=ITEM%2CTAX&cursor=a+b"));
+    }
+
+    #[test]
+    fn test_decode_truncated_escape() {
+        assert_eq!("100%", decode("100%"));
+        assert_eq!("5%2", decode("5%2"));
+    }
+}
@@ -4,62 +4,195 @@ use serde::{Deserialize, Serialize};
 
 // TODO change the implementation of existing Response Object fields to use the right enums
 
-/// The Currency code corresponding to the amount of Money.
-#[non_exhaustive]
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum Currency {
-    GBP,
-    USD,
-    EUR,
-    JPY,
-    SGD
+// Defines an enum of values returned by the Square API with an additional `Unknown(String)`
+// variant. Values that Square adds after this version of the crate deserialize into it instead of
+// failing the whole response, and serialize back unchanged.
+macro_rules! forward_compatible {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// A value that is not known to this version of the crate.
+            #[serde(untagged)]
+            Unknown(String),
+        }
+    };
 }
 
-/// The CustomerCreationSource type, indicating how the customer was created.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CustomerCreationSource {
-    Other,
-    Appointments,
-    Coupon,
-    DeletionRecovery,
-    Directory,
-    Egifting,
-    EmailCollection,
-    Feedback,
-    Import,
-    Invoices,
-    Loyalty,
-    Marketing,
-    Merge,
-    OnlineStore,
-    InstantProfile,
-    Terminal,
-    ThirdParty,
-    ThirdPartyImport,
-    UnmergeRecovery
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CatalogObjectTypeEnum {
-    Item,
-    Image,
-    Category,
-    ItemVariation,
-    Tax,
-    Discount,
-    ModifierList,
-    Modifier,
-    PricingRule,
-    TimePeriod,
-    ProductSet,
-    MeasurementUnit,
-    SubscriptionPlan,
-    ItemOption,
-    ItemOptionVal,
-    CustomAttributeDefinition,
-    QuickAmountsSettings,
+macro_rules! currencies {
+    ($($code:ident => $exponent:expr),* $(,)?) => {
+        /// The [ISO 4217](https://www.iso.org/iso-4217-currency-codes.html) code of the currency
+        /// of an amount of [Money](crate::objects::Money), together with the Square specific codes
+        /// `BTC` and `XUS`. Codes the crate does not know yet are kept as
+        /// [Other](Currency::Other).
+        #[non_exhaustive]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum Currency {
+            $($code,)*
+            Other(String),
+        }
+
+        impl Currency {
+            /// The three letter code of the currency, e.g. `USD`.
+            pub fn code(&self) -> &str {
+                match self {
+                    $(Currency::$code => stringify!($code),)*
+                    Currency::Other(code) => code,
+                }
+            }
+
+            /// The number of digits after the decimal separator of the currency, e.g. 2 for
+            /// `USD` and 0 for `JPY`. Amounts of [Money](crate::objects::Money) are given in this
+            /// smallest denomination. Unknown currencies are assumed to have 2 digits.
+            pub fn exponent(&self) -> u32 {
+                match self {
+                    $(Currency::$code => $exponent,)*
+                    Currency::Other(_) => 2,
+                }
+            }
+
+            /// The currency with the given code, or [Other](Currency::Other) if the code is not
+            /// known.
+            pub fn from_code(code: &str) -> Self {
+                match code {
+                    $(stringify!($code) => Currency::$code,)*
+                    other => Currency::Other(other.to_string()),
+                }
+            }
+        }
+    };
+}
+
+currencies! {
+    AED => 2, AFN => 2, ALL => 2, AMD => 2, ANG => 2, AOA => 2, ARS => 2, AUD => 2, AWG => 2,
+    AZN => 2, BAM => 2, BBD => 2, BDT => 2, BGN => 2, BHD => 3, BIF => 0, BMD => 2, BND => 2,
+    BOB => 2, BOV => 2, BRL => 2, BSD => 2, BTN => 2, BWP => 2, BYN => 2, BYR => 0, BZD => 2,
+    CAD => 2, CDF => 2, CHE => 2, CHF => 2, CHW => 2, CLF => 4, CLP => 0, CNY => 2, COP => 2,
+    COU => 2, CRC => 2, CUC => 2, CUP => 2, CVE => 2, CZK => 2, DJF => 0, DKK => 2, DOP => 2,
+    DZD => 2, EGP => 2, ERN => 2, ETB => 2, EUR => 2, FJD => 2, FKP => 2, GBP => 2, GEL => 2,
+    GHS => 2, GIP => 2, GMD => 2, GNF => 0, GTQ => 2, GYD => 2, HKD => 2, HNL => 2, HRK => 2,
+    HTG => 2, HUF => 2, IDR => 2, ILS => 2, INR => 2, IQD => 3, IRR => 2, ISK => 0, JMD => 2,
+    JOD => 3, JPY => 0, KES => 2, KGS => 2, KHR => 2, KMF => 0, KPW => 2, KRW => 0, KWD => 3,
+    KYD => 2, KZT => 2, LAK => 2, LBP => 2, LKR => 2, LRD => 2, LSL => 2, LTL => 2, LVL => 2,
+    LYD => 3, MAD => 2, MDL => 2, MGA => 2, MKD => 2, MMK => 2, MNT => 2, MOP => 2, MRO => 2,
+    MRU => 2, MUR => 2, MVR => 2, MWK => 2, MXN => 2, MXV => 2, MYR => 2, MZN => 2, NAD => 2,
+    NGN => 2, NIO => 2, NOK => 2, NPR => 2, NZD => 2, OMR => 3, PAB => 2, PEN => 2, PGK => 2,
+    PHP => 2, PKR => 2, PLN => 2, PYG => 0, QAR => 2, RON => 2, RSD => 2, RUB => 2, RWF => 0,
+    SAR => 2, SBD => 2, SCR => 2, SDG => 2, SEK => 2, SGD => 2, SHP => 2, SLE => 2, SLL => 2,
+    SOS => 2, SRD => 2, SSP => 2, STD => 2, STN => 2, SVC => 2, SYP => 2, SZL => 2, THB => 2,
+    TJS => 2, TMT => 2, TND => 3, TOP => 2, TRY => 2, TTD => 2, TWD => 2, TZS => 2, UAH => 2,
+    UGX => 0, USD => 2, USN => 2, UYI => 0, UYU => 2, UYW => 4, UZS => 2, VED => 2, VEF => 2,
+    VES => 2, VND => 0, VUV => 0, WST => 2, XAF => 0, XAG => 0, XAU => 0, XBA => 0, XBB => 0,
+    XBC => 0, XBD => 0, XCD => 2, XDR => 0, XOF => 0, XPD => 0, XPF => 0, XPT => 0, XTS => 0,
+    XXX => 0, YER => 2, ZAR => 2, ZMK => 2, ZMW => 2, ZWL => 2, BTC => 8, XUS => 2
+}
+
+impl Currency {
+    /// The symbol the currency is commonly written with, e.g. `$` for `USD`, if it has one that
+    /// is not ambiguous.
+    pub fn symbol(&self) -> Option<&'static str> {
+        let symbol = match self {
+            Currency::USD => "$",
+            Currency::EUR => "€",
+            Currency::GBP => "£",
+            Currency::JPY => "¥",
+            Currency::CNY => "CN¥",
+            Currency::CAD => "CA$",
+            Currency::AUD => "A$",
+            Currency::NZD => "NZ$",
+            Currency::HKD => "HK$",
+            Currency::SGD => "S$",
+            Currency::MXN => "MX$",
+            Currency::BRL => "R$",
+            Currency::INR => "₹",
+            Currency::KRW => "₩",
+            Currency::ILS => "₪",
+            Currency::NGN => "₦",
+            Currency::PHP => "₱",
+            Currency::VND => "₫",
+            Currency::THB => "฿",
+            Currency::UAH => "₴",
+            Currency::TRY => "₺",
+            Currency::RUB => "₽",
+            _ => return None,
+        };
+
+        Some(symbol)
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Currency::from_code(&String::deserialize(deserializer)?))
+    }
+}
+
+forward_compatible! {
+    /// The CustomerCreationSource type, indicating how the customer was created.
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CustomerCreationSource {
+        Other,
+        Appointments,
+        Coupon,
+        DeletionRecovery,
+        Directory,
+        Egifting,
+        EmailCollection,
+        Feedback,
+        Import,
+        Invoices,
+        Loyalty,
+        Marketing,
+        Merge,
+        OnlineStore,
+        InstantProfile,
+        Terminal,
+        ThirdParty,
+        ThirdPartyImport,
+        UnmergeRecovery,
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CatalogObjectTypeEnum {
+        Item,
+        Image,
+        Category,
+        ItemVariation,
+        Tax,
+        Discount,
+        ModifierList,
+        Modifier,
+        PricingRule,
+        TimePeriod,
+        ProductSet,
+        MeasurementUnit,
+        SubscriptionPlan,
+        ItemOption,
+        ItemOptionVal,
+        CustomAttributeDefinition,
+        QuickAmountsSettings,
+    }
 }
 
 impl fmt::Display for CatalogObjectTypeEnum {
@@ -83,58 +216,141 @@ impl fmt::Display for CatalogObjectTypeEnum {
             CatalogObjectTypeEnum::CustomAttributeDefinition
             => write!(f, "CustomAttributeDefinition"),
             CatalogObjectTypeEnum::QuickAmountsSettings => write!(f, "QuickAmountsSettings"),
+            CatalogObjectTypeEnum::Unknown(value) => write!(f, "{}", value),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BusinessAppointmentSettingsBookingLocationType {
-    BusinessLocation,
-    CustomerLocation,
-    Phone,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BusinessAppointmentSettingsBookingLocationType {
+        BusinessLocation,
+        CustomerLocation,
+        Phone,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BookingBookingSource {
-    FirstPartyMerchant,
-    FirstPartyBuyer,
-    ThirdPartyBuyer,
-    Api,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BookingBookingSource {
+        FirstPartyMerchant,
+        FirstPartyBuyer,
+        ThirdPartyBuyer,
+        Api,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BookingStatus {
-    Pending,
-    CancelledByCustomer,
-    CancelledBySeller,
-    Declined,
-    Accepted,
-    NoShow,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BookingStatus {
+        Pending,
+        CancelledByCustomer,
+        CancelledBySeller,
+        Declined,
+        Accepted,
+        NoShow,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub enum LocationStatus {
-    ACTIVE,
-    INACTIVE,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    pub enum LocationStatus {
+        ACTIVE,
+        INACTIVE,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "snake_case")]
-pub enum TaxIds {
-    EsNif,
-    EuVat,
-    FrNaf,
-    FrSiret,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "snake_case")]
+    pub enum TaxIds {
+        EsNif,
+        EuVat,
+        FrNaf,
+        FrSiret,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum LocationType {
-    Physical,
-    Mobile,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum LocationType {
+        Physical,
+        Mobile,
+    }
+}
+
+forward_compatible! {
+    /// The features a [Location](crate::objects::Location) can make use of.
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum LocationCapability {
+        CreditCardProcessing,
+        AutomaticTransfers,
+    }
+}
+
+forward_compatible! {
+    /// The [ISO 18245](https://www.iso.org/standard/33365.html) merchant category code of a
+    /// [Location](crate::objects::Location), describing the kind of goods or services it sells.
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    pub enum MerchantCategoryCode {
+        #[serde(rename = "1520")]
+        GeneralContractors,
+        #[serde(rename = "4121")]
+        Taxicabs,
+        #[serde(rename = "5411")]
+        GroceryStores,
+        #[serde(rename = "5499")]
+        FoodStores,
+        #[serde(rename = "5691")]
+        ClothingStores,
+        #[serde(rename = "5734")]
+        ComputerSoftwareStores,
+        #[serde(rename = "5812")]
+        Restaurants,
+        #[serde(rename = "5813")]
+        DrinkingPlaces,
+        #[serde(rename = "5814")]
+        FastFoodRestaurants,
+        #[serde(rename = "5942")]
+        BookStores,
+        #[serde(rename = "5945")]
+        ToyStores,
+        #[serde(rename = "5947")]
+        GiftShops,
+        #[serde(rename = "5992")]
+        Florists,
+        #[serde(rename = "5999")]
+        RetailStores,
+        #[serde(rename = "7011")]
+        Lodging,
+        #[serde(rename = "7230")]
+        BeautyAndBarberShops,
+        #[serde(rename = "7298")]
+        HealthAndBeautySpas,
+        #[serde(rename = "7299")]
+        PersonalServices,
+        #[serde(rename = "7399")]
+        BusinessServices,
+        #[serde(rename = "7542")]
+        CarWashes,
+        #[serde(rename = "7997")]
+        ClubsAndFitness,
+        #[serde(rename = "8011")]
+        Doctors,
+        #[serde(rename = "8021")]
+        Dentists,
+        #[serde(rename = "8099")]
+        HealthPractitioners,
+        #[serde(rename = "8299")]
+        Schools,
+        #[serde(rename = "8398")]
+        CharitableOrganizations,
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -153,203 +369,249 @@ impl fmt::Display for SortOrder {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BusinessBookingProfileBookingPolicy {
-    AcceptAll,
-    RequiresAcceptance,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BusinessBookingProfileBookingPolicy {
+        AcceptAll,
+        RequiresAcceptance,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BusinessAppointmentSettingsCancellationPolicy {
-    CancellationTreatedAsNoShow,
-    CustomPolicy,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BusinessAppointmentSettingsCancellationPolicy {
+        CancellationTreatedAsNoShow,
+        CustomPolicy,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BusinessAppointmentSettingsMaxAppointmentsPerDayLimitType {
-    PerTeamMember,
-    PerLocation,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BusinessAppointmentSettingsMaxAppointmentsPerDayLimitType {
+        PerTeamMember,
+        PerLocation,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BusinessBookingProfileCustomerTimezoneChoice {
-    BusinessLocationTimezone,
-    CustomerChoice,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BusinessBookingProfileCustomerTimezoneChoice {
+        BusinessLocationTimezone,
+        CustomerChoice,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderLineItemDiscountScope {
-    OtherDiscountScope,
-    LineItem,
-    Order,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderLineItemDiscountScope {
+        OtherDiscountScope,
+        LineItem,
+        Order,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderLineItemDiscountType {
-    UnknownDiscount,
-    FixedPercentage,
-    FixedAmount,
-    VariablePercentage,
-    VariableAmount,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderLineItemDiscountType {
+        UnknownDiscount,
+        FixedPercentage,
+        FixedAmount,
+        VariablePercentage,
+        VariableAmount,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderFulfillmentFulfillmentLineItemApplication {
-    All,
-    EntryList
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderFulfillmentFulfillmentLineItemApplication {
+        All,
+        EntryList,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderFulfillmentPickupDetailsScheduleType {
-    Scheduled,
-    Asap
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderFulfillmentPickupDetailsScheduleType {
+        Scheduled,
+        Asap,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderLineItemItemType {
-    Item,
-    CustomAmount,
-    GiftCard,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderLineItemItemType {
+        Item,
+        CustomAmount,
+        GiftCard,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum RefundStatus {
-    Pending,
-    Approved,
-    Rejected,
-    Failed,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum RefundStatus {
+        Pending,
+        Approved,
+        Rejected,
+        Failed,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderLineItemTaxScope {
-    OtherTaxScope,
-    LineItem,
-    Order
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderLineItemTaxScope {
+        OtherTaxScope,
+        LineItem,
+        Order,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderLineItemTaxType {
-    UnknownTax,
-    Additive,
-    Inclusive,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderLineItemTaxType {
+        UnknownTax,
+        Additive,
+        Inclusive,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderState {
-    Open,
-    Completed,
-    Canceled,
-    Draft
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderState {
+        Open,
+        Completed,
+        Canceled,
+        Draft,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderServiceChargeCalculationPhase {
-    SubtotalPhase,
-    TotalPhase,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderServiceChargeCalculationPhase {
+        SubtotalPhase,
+        TotalPhase,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderServiceChargeType {
-    AutoGratuity,
-    Custom,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderServiceChargeType {
+        AutoGratuity,
+        Custom,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum TenderType {
-    Card,
-    Cash,
-    ThirdPartyCard,
-    SquareGiftCard,
-    NoSale,
-    Wallet,
-    Other,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum TenderType {
+        Card,
+        Cash,
+        ThirdPartyCard,
+        SquareGiftCard,
+        NoSale,
+        Wallet,
+        Other,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum TenderCardDetailsStatus {
-    Authorized,
-    Captured,
-    Voided,
-    Failed,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum TenderCardDetailsStatus {
+        Authorized,
+        Captured,
+        Voided,
+        Failed,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum TenderCardDetailsEntryMethod {
-    Swiped,
-    Keyed,
-    Emv,
-    OnFile,
-    Contactless
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum TenderCardDetailsEntryMethod {
+        Swiped,
+        Keyed,
+        Emv,
+        OnFile,
+        Contactless,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CatalogObjectType {
-    Item,
-    Image,
-    Category,
-    ItemVariation,
-    Tax,
-    Discount,
-    ModifierList,
-    PricingRule,
-    ProductSet,
-    TimePeriod,
-    MeasurementUnit,
-    SubscriptionPlan,
-    ItemOption,
-    ItemOptionVal,
-    CustomAttributeDefinition,
-    QuickAmountSetting,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CatalogObjectType {
+        Item,
+        Image,
+        Category,
+        ItemVariation,
+        Tax,
+        Discount,
+        ModifierList,
+        PricingRule,
+        ProductSet,
+        TimePeriod,
+        MeasurementUnit,
+        SubscriptionPlan,
+        ItemOption,
+        ItemOptionVal,
+        CustomAttributeDefinition,
+        QuickAmountSetting,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CatalogCustomAttributeDefinitionType {
-    String,
-    Boolean,
-    Number,
-    Selection
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CatalogCustomAttributeDefinitionType {
+        String,
+        Boolean,
+        Number,
+        Selection,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CatalogItemProductType {
-    Regular,
-    GiftCard,  /// Deprecated
-    AppointmentsService,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CatalogItemProductType {
+        Regular,
+        GiftCard,  /// Deprecated
+        AppointmentsService,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum InventoryAlertType {
-    None,
-    LowQuantity,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum InventoryAlertType {
+        None,
+        LowQuantity,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CatalogPricingType {
-    FixedPricing,
-    VariablePricing,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CatalogPricingType {
+        FixedPricing,
+        VariablePricing,
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -359,32 +621,36 @@ pub enum SearchCatalogItemsRequestStockLevel {
     Low,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum InventoryState {
-    Custom,
-    InStock,
-    Sold,
-    ReturnedByCustomer,
-    ReservedForSale,
-    SoldOnline,
-    OrderedFromVendor,
-    ReceivedFromVendor,
-    InTransitTo,
-    None,
-    Waste,
-    UnlinkedReturn,
-    Composed,
-    Decomposed,
-    SupportedByNewerVersion,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum InventoryState {
+        Custom,
+        InStock,
+        Sold,
+        ReturnedByCustomer,
+        ReservedForSale,
+        SoldOnline,
+        OrderedFromVendor,
+        ReceivedFromVendor,
+        InTransitTo,
+        None,
+        Waste,
+        UnlinkedReturn,
+        Composed,
+        Decomposed,
+        SupportedByNewerVersion,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum InventoryChangeType {
-    PhysicalCount,
-    Adjustment,
-    Transfer,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum InventoryChangeType {
+        PhysicalCount,
+        Adjustment,
+        Transfer,
+    }
 }
 
 impl Default for InventoryChangeType {
@@ -393,191 +659,430 @@ impl Default for InventoryChangeType {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum PaymentType {
-    Check,
-    BankTransfer,
-    OtherGiftCard,
-    Crypto,
-    SquareCash,
-    Social,
-    External,
-    Emoney,
-    Card,
-    StoredBalance,
-    FoodVoucher,
-    Other,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum PaymentType {
+        Check,
+        BankTransfer,
+        OtherGiftCard,
+        Crypto,
+        SquareCash,
+        Social,
+        External,
+        Emoney,
+        Card,
+        StoredBalance,
+        FoodVoucher,
+        Other,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum ApplicationDetailsExternalSquareProduct {
-    Appointments,
-    EcommerceApi,
-    Invoices,
-    OnlineStore,
-    Other,
-    Restaurants,
-    Retail,
-    SquarePos,
-    TerminalApi,
-    VirtualTerminal,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum ApplicationDetailsExternalSquareProduct {
+        Appointments,
+        EcommerceApi,
+        Invoices,
+        OnlineStore,
+        Other,
+        Restaurants,
+        Retail,
+        SquarePos,
+        TerminalApi,
+        VirtualTerminal,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BankAccountOwnershipType {
-    Individual,
-    Company,
-    Unknown
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BankAccountOwnershipType {
+        Individual,
+        Company,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BankAccountType {
-    Checking,
-    Savings,
-    Unknown,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BankAccountType {
+        Checking,
+        Savings,
+        Investment,
+        Other,
+        BusinessChecking,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum BuyNowPayLaterBrand {
-    Afterpay,
-    Unknown
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BankAccountStatus {
+        VerificationInProgress,
+        Verified,
+        Disabled,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CCVStatus {
-    CvvAccepted,
-    CvvRejected,
-    CvvNotChecked,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum BuyNowPayLaterBrand {
+        Afterpay,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum PaymentStatus {
-    Authorized,
-    Captured,
-    Voided,
-    Failed
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CCVStatus {
+        CvvAccepted,
+        CvvRejected,
+        CvvNotChecked,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum PaymentVerificationResults {
-    Success,
-    Failure,
-    Unknown
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum PaymentStatus {
+        Approved,
+        Pending,
+        Completed,
+        Canceled,
+        Authorized,
+        Captured,
+        Voided,
+        Failed,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum PaymentVerificationMethod {
-    Pin,
-    Signature,
-    PinAndSignature,
-    OnDevice,
-    None
+forward_compatible! {
+    /// The actions that can be performed on a [Payment](crate::objects::Payment) after it has
+    /// been created.
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum PaymentCapability {
+        EditAmountUp,
+        EditAmountDown,
+        EditTipAmountUp,
+        EditTipAmountDown,
+        EditDelayAction,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum ProcessingFeeType {
-    Initial,
-    Adjustment,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum PaymentVerificationResults {
+        Success,
+        Failure,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum RiskEvaluationRiskLevel {
-    Pending,
-    Normal,
-    Moderate,
-    High,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum PaymentVerificationMethod {
+        Pin,
+        Signature,
+        PinAndSignature,
+        OnDevice,
+        None,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum PaymentSourceType {
-    BankAccount,
-    Wallet,
-    BuyNowPayLater,
-    Cash,
-    Card,
-    External,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum ProcessingFeeType {
+        Initial,
+        Adjustment,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum DigitalWalletBrand {
-    CashApp,
-    Unknown
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum RiskEvaluationRiskLevel {
+        Pending,
+        Normal,
+        Moderate,
+        High,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum DigitalWalletStatus {
-    Authorized,
-    Captured,
-    Voided,
-    Failed,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum PaymentSourceType {
+        BankAccount,
+        Wallet,
+        BuyNowPayLater,
+        Cash,
+        Card,
+        External,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum ActionCancelReason {
-    BuyerCanceled,
-    SellerCanceled,
-    TimedOut,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum DigitalWalletBrand {
+        CashApp,
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CheckoutOptionsPaymentType {
-    CardPresent,
-    ManualCardEntry,
-    FelicaId,
-    FelicaQuicpay,
-    FelicaTransportationGroup,
-    FelicaAll,
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum DigitalWalletStatus {
+        Authorized,
+        Captured,
+        Voided,
+        Failed,
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum ActionCancelReason {
+        BuyerCanceled,
+        SellerCanceled,
+        TimedOut,
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CheckoutOptionsPaymentType {
+        CardPresent,
+        ManualCardEntry,
+        FelicaId,
+        FelicaQuicpay,
+        FelicaTransportationGroup,
+        FelicaAll,
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum TerminalCheckoutStatus {
+        Pending,
+        InProgress,
+        CancelRequested,
+        Canceled,
+        Completed,
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderFulfillmentState {
+        Proposed,
+        Reserved,
+        Prepared,
+        Completed,
+        Canceled,
+        Failed,
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderFulfillmentType {
+        Pickup,
+        Shipment,
+        Delivery,
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum TerminalCheckoutStatus {
-    Pending,
-    InProgress,
-    CancelRequested,
-    Canceled,
-    Completed,
+pub enum SearchOrdersSortField {
+    CreatedAt,
+    UpdatedAt,
+    ClosedAt,
+}
+
+
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CashDrawerShiftState {
+        Open,
+        Ended,
+        Closed,
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CashDrawerEventType {
+        NoSale,
+        CashTenderPayment,
+        OtherTenderPayment,
+        CashTenderCancelledPayment,
+        OtherTenderCancelledPayment,
+        CashTenderRefund,
+        OtherTenderRefund,
+        PaidIn,
+        PaidOut,
+    }
+}
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum VendorStatus {
+        Active,
+        Inactive,
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderFulfillmentState {
-    Proposed,
-    Preserved,
-    Prepared,
-    Completed,
-    Canceled,
-    Failed
+pub enum SearchVendorsSortField {
+    Name,
+    CreatedAt,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderFulfillmentType {
-    Pickup,
-    Shipment,
+pub enum SearchEventsSortField {
+    Default,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum SearchOrdersSortField {
+pub enum CustomerSortField {
+    Default,
     CreatedAt,
-    UpdatedAt,
-    ClosedAt,
 }
 
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum RegisterDomainResponseStatus {
+        Pending,
+        Verified,
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CardBrand {
+        OtherBrand,
+        Visa,
+        Mastercard,
+        AmericanExpress,
+        Discover,
+        DiscoverDiners,
+        Jcb,
+        ChinaUnionpay,
+        SquareGiftCard,
+        SquareCapitalCard,
+        Interac,
+        Eftpos,
+        Felica,
+        Ebt,
+    }
+}
+
+impl fmt::Display for CardBrand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CardBrand::OtherBrand => write!(f, "OTHER_BRAND"),
+            CardBrand::Visa => write!(f, "VISA"),
+            CardBrand::Mastercard => write!(f, "MASTERCARD"),
+            CardBrand::AmericanExpress => write!(f, "AMERICAN_EXPRESS"),
+            CardBrand::Discover => write!(f, "DISCOVER"),
+            CardBrand::DiscoverDiners => write!(f, "DISCOVER_DINERS"),
+            CardBrand::Jcb => write!(f, "JCB"),
+            CardBrand::ChinaUnionpay => write!(f, "CHINA_UNIONPAY"),
+            CardBrand::SquareGiftCard => write!(f, "SQUARE_GIFT_CARD"),
+            CardBrand::SquareCapitalCard => write!(f, "SQUARE_CAPITAL_CARD"),
+            CardBrand::Interac => write!(f, "INTERAC"),
+            CardBrand::Eftpos => write!(f, "EFTPOS"),
+            CardBrand::Felica => write!(f, "FELICA"),
+            CardBrand::Ebt => write!(f, "EBT"),
+            CardBrand::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CardType {
+        UnknownCardType,
+        Credit,
+        Debit,
+    }
+}
+
+impl fmt::Display for CardType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CardType::UnknownCardType => write!(f, "UNKNOWN_CARD_TYPE"),
+            CardType::Credit => write!(f, "CREDIT"),
+            CardType::Debit => write!(f, "DEBIT"),
+            CardType::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+forward_compatible! {
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum CardPrepaidType {
+        UnknownPrepaidType,
+        NotPrepaid,
+        Prepaid,
+    }
+}
+
+#[cfg(test)]
+mod test_enums {
+    use super::*;
+    use crate::objects::Location;
+
+    #[test]
+    fn test_unknown_variant() {
+        let known: CustomerCreationSource = serde_json::from_str("\"THIRD_PARTY\"").unwrap();
+        assert_eq!(known, CustomerCreationSource::ThirdParty);
+
+        let unknown: CustomerCreationSource = serde_json::from_str("\"KIOSK\"").unwrap();
+        assert_eq!(unknown, CustomerCreationSource::Unknown("KIOSK".to_string()));
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"KIOSK\"");
+
+        let brand: CardBrand = serde_json::from_str("\"UNKNOWN\"").unwrap();
+        assert_eq!(brand.to_string(), "UNKNOWN");
+
+        let account_type: BankAccountType = serde_json::from_str("\"UNKNOWN\"").unwrap();
+        assert!(matches!(account_type, BankAccountType::Unknown(value) if value == "UNKNOWN"));
+    }
+
+    #[test]
+    fn test_typed_location_fields() {
+        let location: Location = serde_json::from_str(r#"{
+            "capabilities": ["CREDIT_CARD_PROCESSING", "CASH_ADVANCES"],
+            "mcc": "5812"
+        }"#).unwrap();
+
+        assert_eq!(location.capabilities, Some(vec![
+            LocationCapability::CreditCardProcessing,
+            LocationCapability::Unknown("CASH_ADVANCES".to_string()),
+        ]));
+        assert_eq!(location.mcc, Some(MerchantCategoryCode::Restaurants));
+    }
+}
@@ -0,0 +1,142 @@
+/*!
+Identifiers of the resources of the [Square API](https://developer.squareup.com).
+
+Every kind of resource has its own identifier type, so that e.g. a
+[CustomerId](CustomerId) can not be passed where a [LocationId](LocationId) is expected. The
+identifiers convert from and into strings, and the API methods accept anything that converts
+into the expected identifier.
+
+# Example: Retrieve a location by its id
+```rust
+use square_ox::{
+    client::SquareClient,
+    objects::ids::LocationId,
+};
+
+async {
+    let location_id = LocationId::from("L1JC53TYHS40Z");
+
+    let location = SquareClient::new("some_token")
+        .locations()
+        .retrieve(location_id)
+        .await;
+};
+```
+ */
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+macro_rules! ids {
+    ($($(#[$doc:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq, Hash,
+                     PartialOrd, Ord)]
+            #[serde(transparent)]
+            pub struct $name(String);
+
+            impl $name {
+                pub fn as_str(&self) -> &str {
+                    &self.0
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    self.0.is_empty()
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+
+            impl AsRef<str> for $name {
+                fn as_ref(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl From<String> for $name {
+                fn from(id: String) -> Self {
+                    $name(id)
+                }
+            }
+
+            impl From<&str> for $name {
+                fn from(id: &str) -> Self {
+                    $name(id.to_string())
+                }
+            }
+
+            impl From<&String> for $name {
+                fn from(id: &String) -> Self {
+                    $name(id.clone())
+                }
+            }
+
+            impl From<$name> for String {
+                fn from(id: $name) -> Self {
+                    id.0
+                }
+            }
+
+            impl PartialEq<str> for $name {
+                fn eq(&self, other: &str) -> bool {
+                    self.0 == other
+                }
+            }
+
+            impl PartialEq<&str> for $name {
+                fn eq(&self, other: &&str) -> bool {
+                    self.0 == *other
+                }
+            }
+        )*
+    };
+}
+
+ids! {
+    /// The id of a [Location](crate::objects::Location).
+    LocationId,
+    /// The id of a [Customer](crate::objects::Customer).
+    CustomerId,
+    /// The id of an [Order](crate::objects::Order).
+    OrderId,
+    /// The id of a [Payment](crate::objects::Payment).
+    PaymentId,
+    /// The id of a [Refund](crate::objects::Refund).
+    RefundId,
+    /// The id of a [CatalogObject](crate::objects::CatalogObject), such as an item or one of its
+    /// variations.
+    CatalogObjectId,
+    /// The id of a [Booking](crate::objects::Booking).
+    BookingId,
+    /// The id of a [Card](crate::objects::Card).
+    CardId,
+    /// The id of a team member.
+    TeamMemberId,
+    /// The id of an [InventoryAdjustment](crate::objects::InventoryAdjustment).
+    InventoryAdjustmentId,
+    /// The id of an [InventoryTransfer](crate::objects::InventoryTransfer).
+    InventoryTransferId,
+    /// The id of an [InventoryPhysicalCount](crate::objects::InventoryPhysicalCount).
+    InventoryPhysicalCountId,
+}
+
+#[cfg(test)]
+mod test_ids {
+    use super::*;
+
+    #[test]
+    fn test_id_conversions() {
+        let id = LocationId::from("L1JC53TYHS40Z");
+
+        assert_eq!(id, "L1JC53TYHS40Z");
+        assert_eq!(id.to_string(), "L1JC53TYHS40Z");
+        assert_eq!(String::from(id.clone()), "L1JC53TYHS40Z".to_string());
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""L1JC53TYHS40Z""#);
+        assert_eq!(serde_json::from_str::<LocationId>(r#""L1JC53TYHS40Z""#).unwrap(), id);
+    }
+}
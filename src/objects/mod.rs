@@ -4,29 +4,44 @@ A non-comprehensive list of the Objects used by the
 */
 
 pub mod enums;
+pub mod ids;
+mod money;
+
+pub use money::MoneyLocale;
 
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::api::orders::Orders;
+use crate::builder::SquareBuilder;
+use crate::datetime::{IsoDuration, Timestamp};
+use crate::errors::{FieldPath, ValidationError};
 use crate::api::terminal::Terminal;
 use crate::objects::enums::{
     ActionCancelReason, ApplicationDetailsExternalSquareProduct,
-    BankAccountOwnershipType, BusinessAppointmentSettingsBookingLocationType,
+    BankAccountOwnershipType, BankAccountStatus, BankAccountType,
+    BusinessAppointmentSettingsBookingLocationType,
     BusinessAppointmentSettingsCancellationPolicy,
     BusinessAppointmentSettingsMaxAppointmentsPerDayLimitType,
-    BusinessBookingProfileBookingPolicy, BusinessBookingProfileCustomerTimezoneChoice,
-    BuyNowPayLaterBrand, CatalogCustomAttributeDefinitionType, CatalogItemProductType,
-    CatalogObjectType, CatalogPricingType, CCVStatus, CheckoutOptionsPaymentType, Currency,
+    BookingBookingSource, BookingStatus, BusinessBookingProfileBookingPolicy,
+    BusinessBookingProfileCustomerTimezoneChoice,
+    BuyNowPayLaterBrand, CardBrand, CardPrepaidType, CardType, CatalogCustomAttributeDefinitionType, CatalogItemProductType,
+    CashDrawerEventType, CashDrawerShiftState, CatalogObjectType, CatalogPricingType, CCVStatus, CheckoutOptionsPaymentType, Currency,
     CustomerCreationSource, DigitalWalletBrand, DigitalWalletStatus, InventoryAlertType,
-    InventoryChangeType, InventoryState, LocationStatus, LocationType,
+    InventoryChangeType, InventoryState, LocationCapability, LocationStatus, LocationType,
+    MerchantCategoryCode,
     OrderFulfillmentFulfillmentLineItemApplication, OrderFulfillmentPickupDetailsScheduleType,
     OrderFulfillmentState, OrderFulfillmentType, OrderLineItemDiscountScope,
     OrderLineItemDiscountType, OrderLineItemItemType, OrderLineItemTaxScope,
     OrderLineItemTaxType, OrderServiceChargeCalculationPhase, OrderServiceChargeType,
-    OrderState, PaymentSourceType, PaymentStatus, PaymentType, PaymentVerificationMethod,
-    PaymentVerificationResults, ProcessingFeeType, RefundStatus, RiskEvaluationRiskLevel,
-    SearchOrdersSortField, SortOrder, TenderCardDetailsEntryMethod, TenderCardDetailsStatus,
-    TenderType, TerminalCheckoutStatus
+    OrderState, PaymentCapability, PaymentSourceType, PaymentStatus, PaymentType, PaymentVerificationMethod,
+    PaymentVerificationResults, ProcessingFeeType, RefundStatus, RegisterDomainResponseStatus,
+    RiskEvaluationRiskLevel, SearchEventsSortField, SearchOrdersSortField, SearchVendorsSortField,
+    SortOrder, TenderCardDetailsEntryMethod, TenderCardDetailsStatus, TenderType,
+    TerminalCheckoutStatus, VendorStatus
+};
+use crate::objects::ids::{
+    BookingId, CardId, CatalogObjectId, CustomerId, InventoryAdjustmentId, InventoryPhysicalCountId,
+    InventoryTransferId, LocationId, OrderId, PaymentId, RefundId, TeamMemberId
 };
 use crate::response::ResponseError;
 
@@ -59,14 +74,18 @@ pub enum Response {
     Limits(CatalogInfoResponseLimits),
     StandardUnitDescriptionGroup(StandardUnitDescriptionGroup),
     RelatedObjects(Vec<CatalogObject>),
+    IdMappings(Vec<CatalogIdMapping>),
+    Image(CatalogObject),
 
     // Bookings Endpoint Responses
     Booking(Booking),
-    Bookings(Vec<Booking>),
+    Bookings(BookingsEnum),
     Availabilities(Vec<Availability>),
     BusinessBookingProfile(BusinessBookingProfile),
-    TeamMemberBookingProfiles(Vec<TeamMemberBookingProfile>),
+    TeamMemberBookingProfiles(TeamMemberBookingProfilesEnum),
     TeamMemberBookingProfile(TeamMemberBookingProfile),
+    LocationBookingProfiles(Vec<LocationBookingProfile>),
+    LocationBookingProfile(LocationBookingProfile),
 
     // Cards Endpoint Responses
     Cards(Vec<Card>),
@@ -79,12 +98,42 @@ pub enum Response {
 
     // Inventory Endpoint Responses
     Counts(Vec<InventoryCount>),
+    Changes(Vec<InventoryChange>),
 
     // Sites Endpoint Responses
     Sites(Vec<Site>),
+    Snippet(Snippet),
+
+    // Apple Pay Endpoint Responses
+    Status(RegisterDomainResponseStatus),
 
     // Terminal Endpoint Responses
     Checkouts(Vec<TerminalCheckout>),
+
+    // Bank Accounts Endpoint Responses
+    BankAccounts(Vec<BankAccount>),
+    BankAccount(BankAccount),
+
+    // Cash Drawers Endpoint Responses
+    CashDrawerShifts(Vec<CashDrawerShift>),
+    CashDrawerShift(CashDrawerShift),
+    CashDrawerShiftEvents(Vec<CashDrawerShiftEvent>),
+
+    // Vendors Endpoint Responses
+    Vendor(Vendor),
+    Vendors(Vec<Vendor>),
+    Responses(HashMap<String, VendorResponse>),
+
+    // Webhook Subscriptions Endpoint Responses
+    Subscriptions(Vec<WebhookSubscription>),
+    Subscription(WebhookSubscription),
+    SubscriptionTestResult(SubscriptionTestResult),
+    SignatureKey(String),
+    EventTypes(Vec<String>),
+    Metadata(MetadataEnum), // Also a possible Events Endpoint Response
+
+    // Events Endpoint Responses
+    Events(Vec<Event>),
 }
 
 // Since both the Checkout and Terminal endpoint can return a field tagged with checkout it is
@@ -96,10 +145,36 @@ pub enum CheckoutEnum {
     TerminalCheckout(TerminalCheckout),
 }
 
+// The list endpoints of the Bookings API return a list of bookings and team member booking
+// profiles while the bulk retrieve endpoints return a map keyed by the requested ids under the
+// same field, which is why it is necessary to define these return fields as untagged enums
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BookingsEnum {
+    List(Vec<Booking>),
+    Map(HashMap<String, RetrieveBookingResponse>),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TeamMemberBookingProfilesEnum {
+    List(Vec<TeamMemberBookingProfile>),
+    Map(HashMap<String, RetrieveTeamMemberBookingProfileResponse>),
+}
+
+// Both the Webhook Subscriptions and Events endpoints return a field tagged with metadata, which
+// is why it is necessary to define this return field as an untagged enum
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MetadataEnum {
+    EventTypes(Vec<EventTypeMetadata>),
+    Events(Vec<EventMetadata>),
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct Location {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<LocationId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -109,7 +184,7 @@ pub struct Location {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timezone: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<LocationCapability>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<LocationStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -119,7 +194,7 @@ pub struct Location {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -135,7 +210,7 @@ pub struct Location {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub mcc: Option<String>,
+    pub mcc: Option<MerchantCategoryCode>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merchant_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -196,15 +271,15 @@ pub struct BusinessHoursPeriod {
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct Availability {
-    pub start_at: String,
-    pub location_id: String,
+    pub start_at: Timestamp,
+    pub location_id: LocationId,
     pub appointment_segments: Vec<AppointmentSegment>
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct AppointmentSegment {
     pub duration_minutes: f64,
-    pub team_member_id: String,
+    pub team_member_id: TeamMemberId,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub any_team_member_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -215,27 +290,31 @@ pub struct AppointmentSegment {
     pub service_variation_version: i64,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
 pub struct Customer {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CustomerId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub birthday: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub address: Option<Address>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "identity")]
     pub company_name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub creation_source: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "identity")]
     pub email_address: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "identity")]
     pub family_name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "identity")]
     pub  given_name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_ids: Option<Vec<String>>,
@@ -244,6 +323,7 @@ pub struct Customer {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "identity")]
     pub phone_number: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub preferences: Option<Preferences>,
@@ -258,6 +338,7 @@ pub struct Customer {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cards: Option<Vec<Card>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[idempotency_key]
     pub idempotency_key: Option<String>,
 }
 
@@ -283,21 +364,21 @@ pub struct TaxIds {
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct Card {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CardId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub billing_address: Option<Address>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bin: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub card_brand: Option<String>,
+    pub card_brand: Option<CardBrand>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub card_co_brand: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub card_type: Option<String>,
+    pub card_type: Option<CardType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cardholder_name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -311,7 +392,7 @@ pub struct Card {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merchant_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub prepaid_type: Option<String>,
+    pub prepaid_type: Option<CardPrepaidType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -331,11 +412,11 @@ pub struct FilterValue {
 #[derive(Clone, Serialize, Debug, Deserialize, Default)]
 pub struct CatalogObject {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<CatalogObjectId>,
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
     pub type_name: Option<CatalogObjectType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub absent_at_location_ids: Option<Vec<String>>,
+    pub absent_at_location_ids: Option<Vec<LocationId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_v1_ids: Option<Vec<CatalogV1ID>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -365,7 +446,7 @@ pub struct CatalogObject {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub present_at_all_locations: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub present_at_location_ids: Option<Vec<String>>,
+    pub present_at_location_ids: Option<Vec<LocationId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pricing_rule_data: Option<CatalogPricingRule>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -379,9 +460,9 @@ pub struct CatalogObject {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub time_period_data: Option<CatalogTimePeriod>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub version: Option<i64>,
 }
@@ -393,7 +474,7 @@ pub struct CatalogObjectVariation {
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
     pub type_name: Option<CatalogObjectType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub absent_at_location_ids: Option<Vec<String>>,
+    pub absent_at_location_ids: Option<Vec<LocationId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_v1_ids: Option<Vec<CatalogV1ID>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -421,7 +502,7 @@ pub struct CatalogObjectVariation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub present_at_all_locations: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub present_at_location_ids: Option<Vec<String>>,
+    pub present_at_location_ids: Option<Vec<LocationId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pricing_rule_data: Option<CatalogPricingRule>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -435,9 +516,9 @@ pub struct CatalogObjectVariation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub time_period_data: Option<CatalogTimePeriod>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub version: Option<i64>,
 }
@@ -449,7 +530,7 @@ pub struct CatalogObjectOption {
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
     pub type_name: Option<CatalogObjectType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub absent_at_location_ids: Option<Vec<String>>,
+    pub absent_at_location_ids: Option<Vec<LocationId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_v1_ids: Option<Vec<CatalogV1ID>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -477,7 +558,7 @@ pub struct CatalogObjectOption {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub present_at_all_locations: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub present_at_location_ids: Option<Vec<String>>,
+    pub present_at_location_ids: Option<Vec<LocationId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pricing_rule_data: Option<CatalogPricingRule>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -491,9 +572,9 @@ pub struct CatalogObjectOption {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub time_period_data: Option<CatalogTimePeriod>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub version: Option<i64>,
 }
@@ -503,7 +584,7 @@ pub struct CatalogV1ID {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_v1_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    pub location_id: Option<LocationId>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
@@ -621,7 +702,22 @@ pub struct CatalogDiscount {
     pub pin_required: Option<String>,
 }
 
+/// Maps a client supplied temporary id (e.g. `#new_item`) to the permanent id the
+/// [Square API](https://developer.squareup.com) assigned to the created object.
 #[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct CatalogIdMapping {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_object_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<String>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+pub struct CatalogObjectBatch {
+    pub objects: Vec<CatalogObject>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
 pub struct CatalogImage {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
@@ -762,7 +858,7 @@ pub struct CatalogItemVariation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stockable_conversion: Option<CatalogStockConversion>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub team_member_ids: Option<Vec<String>>,
+    pub team_member_ids: Option<Vec<TeamMemberId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub track_inventory: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -786,7 +882,7 @@ pub struct ItemVariationLocationOverrides {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inventory_alert_type: Option<InventoryAlertType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    pub location_id: Option<LocationId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub price_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -990,35 +1086,35 @@ pub struct CatalogTimePeriod {
 #[derive(Clone, Serialize, Debug, Deserialize, Default)]
 pub struct Booking {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<BookingId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub all_day: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub appointment_segments: Option<Vec<AppointmentSegment>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub booking_creator_details: Option<BookingCreatorDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub customer_note: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    pub location_id: Option<LocationId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location_type: Option<BusinessAppointmentSettingsBookingLocationType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub seller_note: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
+    pub source: Option<BookingBookingSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub start_at: Option<String>,
+    pub start_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<BookingStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transition_time_minutes: Option<i32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub version: Option<i32>
 }
@@ -1028,15 +1124,20 @@ pub struct BookingCreatorDetails {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub creator_type: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub team_member_id: Option<String>,
+    pub team_member_id: Option<TeamMemberId>,
 }
 
 /// Representation of Money for the crate.
 /// The amount is given in the lowest possible denomination.
 /// So for GBP the amount is in pence.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// Amounts can be combined with [checked_add](Money::checked_add),
+/// [checked_sub](Money::checked_sub), [checked_mul](Money::checked_mul) and
+/// [split](Money::split), which refuse to mix currencies, and are displayed in the usual notation
+/// of their currency, e.g. `$12.34` or `¥1234`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Money {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub amount: Option<i64>,
@@ -1054,7 +1155,7 @@ pub struct BusinessBookingProfile {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub business_appointment_settings: Option<BusinessAppointmentSettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub customer_timezone_choice: Option<BusinessBookingProfileCustomerTimezoneChoice>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1104,7 +1205,36 @@ pub struct TeamMemberBookingProfile {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile_image_url: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub team_member_id: Option<String>,
+    pub team_member_id: Option<TeamMemberId>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LocationBookingProfile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub booking_site_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<LocationId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub online_booking_enabled: Option<bool>,
+}
+
+/// A single entry of a bulk retrieve bookings response, keyed by the requested booking id.
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct RetrieveBookingResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub booking: Option<Booking>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<ResponseError>>,
+}
+
+/// A single entry of a bulk retrieve team member booking profiles response, keyed by the requested
+/// team member id.
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct RetrieveTeamMemberBookingProfileResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_member_booking_profile: Option<TeamMemberBookingProfile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<ResponseError>>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
@@ -1113,23 +1243,27 @@ pub struct CreateOrderRequest {
     pub order: Order,
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
 pub struct Order {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<OrderId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    #[required]
+    pub location_id: Option<LocationId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub close_at: Option<String>,
+    pub close_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sub_builder]
     pub discounts: Option<Vec<OrderLineItemDiscount>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fulfillments: Option<OrderFulfillment>,
+    #[sub_builder]
+    pub fulfillments: Option<Vec<OrderFulfillment>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sub_builder]
     pub line_items: Option<Vec<OrderLineItem>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
@@ -1150,12 +1284,14 @@ pub struct Order {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rounding_adjustment: Option<OrderRoundingAdjustment>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sub_builder]
     pub service_charges: Option<Vec<OrderServiceCharge>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<OrderSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<OrderState>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sub_builder]
     pub taxes: Option<Vec<OrderLineItemTax>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tenders: Option<Vec<Tender>>,
@@ -1172,7 +1308,7 @@ pub struct Order {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total_tip_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub version: Option<i64>,
 }
@@ -1182,52 +1318,65 @@ pub struct ChargeRequestAdditionalRecipient {
 
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "crate::builder::implementations::ad_hoc_discount")]
 pub struct OrderLineItemDiscount {
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(skip)]
     pub amount_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub applied_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    #[validate(one_of = "source")]
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    metadata: Option<HashMap<String, String>>,
+    pub metadata: Option<HashMap<String, String>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "source")]
     pub name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(skip)]
     pub percentage: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pricing_rule_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reward_ids: Option<Vec<String>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(preset(
+        order_scope = "OrderLineItemDiscountScope::Order",
+        line_item_scope = "OrderLineItemDiscountScope::LineItem",
+    ))]
     pub scope: Option<OrderLineItemDiscountScope>,
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
     pub discount_type: Option<OrderLineItemDiscountType>,
+    /// A unique id that line items use to reference a line item scoped discount.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uid: Option<String>,
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "crate::builder::implementations::fulfillment_details")]
 pub struct OrderFulfillment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    entries: Option<Vec<OrderFulfillmentFulfillmentEntry>>,
+    pub entries: Option<Vec<OrderFulfillmentFulfillmentEntry>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    line_item_application: Option<OrderFulfillmentFulfillmentLineItemApplication>,
+    pub line_item_application: Option<OrderFulfillmentFulfillmentLineItemApplication>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pickup_details: Option<OrderFulfillmentPickupDetails>,
+    #[builder(skip)]
+    pub pickup_details: Option<OrderFulfillmentPickupDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    shipment_details: Option<OrderFulfillmentShipmentDetails>,
+    #[builder(skip)]
+    pub shipment_details: Option<OrderFulfillmentShipmentDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    state: Option<String>,
+    pub state: Option<OrderFulfillmentState>,
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
-    fulfillment_type: Option<String>,
+    pub fulfillment_type: Option<OrderFulfillmentType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    uid: Option<String>,
+    pub uid: Option<String>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
@@ -1240,42 +1389,42 @@ pub struct OrderFulfillmentFulfillmentEntry {
     pub uid: Option<String>,
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
 pub struct OrderFulfillmentPickupDetails {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub accepted_at: Option<String>,
+    pub accepted_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub auto_complete_duration: Option<String>,
+    pub auto_complete_duration: Option<IsoDuration>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cancel_reason: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub canceled_at: Option<String>,
+    pub canceled_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub curbside_pickup_details: Option<OrderFulfillmentPickupDetailsCurbsidePickupDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub expired_at: Option<String>,
+    pub expired_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<String>,
+    pub expires_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_curbside_pickup: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub picked_up_at: Option<String>,
+    pub picked_up_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub pickup_at: Option<String>,
+    pub pickup_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub pickup_window_duration: Option<String>,
+    pub pickup_window_duration: Option<IsoDuration>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub placed_at: Option<String>,
+    pub placed_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub prep_time_duration: Option<String>,
+    pub prep_time_duration: Option<IsoDuration>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub ready_at: Option<String>,
+    pub ready_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recipient: Option<OrderFulfillmentRecipient>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub rejected_at: Option<String>,
+    pub rejected_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schedule_type: Option<OrderFulfillmentPickupDetailsScheduleType>,
 }
@@ -1283,17 +1432,17 @@ pub struct OrderFulfillmentPickupDetails {
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct OrderFulfillmentPickupDetailsCurbsidePickupDetails {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub buyer_arrived_at: Option<String>,
+    pub buyer_arrived_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub curbside_details: Option<String>,
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
 pub struct OrderFulfillmentRecipient {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub address: Option<Address>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1302,49 +1451,58 @@ pub struct OrderFulfillmentRecipient {
     pub phone_number: Option<String>,
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
 pub struct OrderFulfillmentShipmentDetails {
-    cancel_reason: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    canceled_at: Option<String>,
+    pub cancel_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    carrier: Option<String>,
+    pub carrier: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    expected_shipped_at: Option<String>,
+    pub expected_shipped_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    failed_at: Option<String>,
+    pub failed_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    failure_reason: Option<String>,
+    pub failure_reason: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    in_progress_at: Option<String>,
+    pub in_progress_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    packaged_at: Option<String>,
+    pub packaged_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    placed_at: Option<String>,
+    pub placed_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    recipient: Option<OrderFulfillmentRecipient>,
-    shipped_at: Option<String>,
+    pub recipient: Option<OrderFulfillmentRecipient>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    shipping_note: Option<String>,
+    pub shipped_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    shipping_type: Option<String>,
+    pub shipping_note: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    tracking_number: Option<String>,
+    pub shipping_type: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    tracking_url: Option<String>,
+    pub tracking_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_url: Option<String>,
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "crate::builder::implementations::ad_hoc_line_item")]
 pub struct OrderLineItem {
+    /// The quantity purchased, formatted as a decimal number, e.g. `"2"`.
+    #[required]
     pub quantity: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(skip)]
     pub applied_discounts: Option<Vec<OrderLineItemAppliedDiscount>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(skip)]
     pub applied_taxes: Option<Vec<OrderLineItemAppliedTax>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub base_price_money: Option<Money>,
+    /// The id of the catalog item variation this line item references.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    #[validate(one_of = "source")]
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1356,6 +1514,7 @@ pub struct OrderLineItem {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub modifiers: Option<Vec<OrderLineItemModifier>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "source")]
     pub name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
@@ -1402,7 +1561,7 @@ pub struct OrderLineItemModifier {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub base_price_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1448,7 +1607,7 @@ pub struct OrderLineItemPricingBlocklistsBlockedTax {
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct OrderQuantityUnit {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1481,14 +1640,14 @@ pub struct OrderPricingOptions {
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct Refund {
-    pub id: String,
+    pub id: RefundId,
     pub amount_money: Money,
-    pub location_id: String,
+    pub location_id: LocationId,
     pub reason: String,
     pub status: RefundStatus,
     pub tender_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub processing_fee_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1522,7 +1681,7 @@ pub struct OrderReturnDiscount {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub applied_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1549,7 +1708,7 @@ pub struct OrderReturnLineItem {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub base_price_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1585,7 +1744,7 @@ pub struct OrderReturnLineItemModifier {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub base_price_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1609,7 +1768,7 @@ pub struct OrderReturnServiceCharge {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub calculation_phase: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1633,7 +1792,7 @@ pub struct OrderReturnTax {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub applied_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1666,27 +1825,35 @@ pub struct OrderReward {
     pub reward_tier_id: String
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
 pub struct OrderServiceCharge {
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[required]
     pub amount_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub applied_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub applied_taxes: Option<Vec<OrderLineItemAppliedTax>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[required]
+    #[builder(preset(
+        total_phase = "OrderServiceChargeCalculationPhase::TotalPhase",
+        subtotal_phase = "OrderServiceChargeCalculationPhase::SubtotalPhase",
+    ))]
     pub calculation_phase: Option<OrderServiceChargeCalculationPhase>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[required]
     pub name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub percentage: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(skip, preset(taxable = "true", not_taxable = "false"))]
     pub taxable: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total_money: Option<Money>,
@@ -1704,25 +1871,39 @@ pub struct OrderSource {
     pub name: Option<String>
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "crate::builder::implementations::ad_hoc_tax")]
 pub struct OrderLineItemTax {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub applied_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_applied: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    #[validate(one_of = "source")]
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_version: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, String>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "source")]
     pub name: Option<String>,
+    /// The percentage of the tax as a decimal string, e.g. `"7.25"`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub percentage: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(preset(
+        order_scope = "OrderLineItemTaxScope::Order",
+        line_item_scope = "OrderLineItemTaxScope::LineItem",
+    ))]
     pub scope: Option<OrderLineItemTaxScope>,
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    #[builder(preset(
+        additive = "OrderLineItemTaxType::Additive",
+        inclusive = "OrderLineItemTaxType::Inclusive",
+    ))]
     pub calculation_method_type: Option<OrderLineItemTaxType>,
+    /// A unique id that line items use to reference a line item scoped tax.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uid: Option<String>,
 }
@@ -1740,15 +1921,15 @@ pub struct Tender {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cash_details: Option<TenderCashDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    pub location_id: Option<LocationId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub payment_id: Option<String>,
+    pub payment_id: Option<PaymentId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub processing_fee_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1783,7 +1964,7 @@ pub struct Checkout {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub checkout_page_url: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merchant_support_email: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1804,17 +1985,17 @@ pub struct PaymentLink {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub checkout_options: Option<CheckoutOptions>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub order_id: Option<String>,
+    pub order_id: Option<OrderId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payment_note: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pre_populated_data: Option<PrePopulatedData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
@@ -1866,7 +2047,7 @@ pub struct PrePopulatedData {
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct QuickPay {
-    pub location_id: String,
+    pub location_id: LocationId,
     pub name: String,
     pub price_money: Money,
 }
@@ -2029,22 +2210,22 @@ pub struct Range {
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct InventoryCount {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub calculated_at: Option<String>,
+    pub calculated_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catalog_object_id: Option<String>,
+    pub catalog_object_id: Option<CatalogObjectId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_object_type: Option<CatalogObjectType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_estimated: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    pub location_id: Option<LocationId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub quantity: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<InventoryState>,
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
 pub struct InventoryChange {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub adjustment: Option<InventoryAdjustment>,
@@ -2057,47 +2238,60 @@ pub struct InventoryChange {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transfer: Option<InventoryTransfer>,
     #[serde(rename = "type")]
+    #[builder(setter = "change_type")]
     pub inventory_change_type: InventoryChangeType,
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+#[validate(with = "crate::builder::implementations::vendor_adjustment")]
 pub struct InventoryAdjustment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<InventoryAdjustmentId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub adjustment_group: Option<InventoryAdjustmentGroup>,
-    pub catalog_object_id: String,
+    #[required]
+    pub catalog_object_id: CatalogObjectId,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_object_type: Option<CatalogObjectType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub employee_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[required]
     pub from_state: Option<InventoryState>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub goods_receipt_id: Option<String>,
-    pub location_id: String,
+    #[required]
+    pub location_id: LocationId,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub occurred_at: Option<String>,
+    pub occurred_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub purchase_order_id: Option<String>,
+    /// The number of items affected by the adjustment as a decimal string.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[required]
     pub quantity: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub refund_id: Option<String>,
+    pub refund_id: Option<RefundId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<SourceApplication>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub team_member_id: Option<String>,
+    pub team_member_id: Option<TeamMemberId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[required]
     pub to_state: Option<InventoryState>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total_price_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transaction_id: Option<String>,
+    /// The id of the [Vendor](crate::objects::Vendor) the stock is coming from. Only valid if
+    /// the adjustment moves stock out of `NONE`, `ORDERED_FROM_VENDOR` or `RECEIVED_FROM_VENDOR`,
+    /// or into one of the vendor states.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor_id: Option<String>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
@@ -2115,14 +2309,14 @@ pub struct InventoryAdjustmentGroup {
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct InventoryPhysicalCount {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    pub catalog_object_id: String,
+    pub id: Option<InventoryPhysicalCountId>,
+    pub catalog_object_id: CatalogObjectId,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_object_type: Option<CatalogObjectType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
-    pub location_id: String,
-    pub occurred_at: String,
+    pub created_at: Option<Timestamp>,
+    pub location_id: LocationId,
+    pub occurred_at: Timestamp,
     pub quantity: String, /// As decimal with up to 5 digits after the decimal point
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference_id: Option<String>,
@@ -2130,22 +2324,22 @@ pub struct InventoryPhysicalCount {
     pub source: Option<SourceApplication>,
     pub state: InventoryState,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub team_member_id: Option<String>,
+    pub team_member_id: Option<TeamMemberId>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct InventoryTransfer {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    pub catalog_object_id: String,
+    pub id: Option<InventoryTransferId>,
+    pub catalog_object_id: CatalogObjectId,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catalog_object_type: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub employee_id: Option<String>,
-    pub from_location_id: String,
-    pub occurred_at: String,
+    pub from_location_id: LocationId,
+    pub occurred_at: Timestamp,
     pub quantity: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference_id: Option<String>,
@@ -2153,14 +2347,14 @@ pub struct InventoryTransfer {
     pub source: Option<SourceApplication>,
     pub state: InventoryState,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub team_member_id: Option<String>,
-    pub to_location_id: String,
+    pub team_member_id: Option<TeamMemberId>,
+    pub to_location_id: LocationId,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, Default)]
 pub struct Payment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<PaymentId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub amount_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2178,19 +2372,19 @@ pub struct Payment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub buyer_email_address: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<PaymentCapability>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub card_details: Option<CardPaymentDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cash_details: Option<CashPaymentDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delay_action: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub delay_duration: Option<String>,
+    pub delay_duration: Option<IsoDuration>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delayed_until: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2198,11 +2392,11 @@ pub struct Payment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub external_details: Option<ExternalPaymentDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    pub location_id: Option<LocationId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub order_id: Option<String>,
+    pub order_id: Option<OrderId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub processing_fee: Option<ProcessingFee>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2212,7 +2406,7 @@ pub struct Payment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub refund_ids: Option<Vec<String>>,
+    pub refund_ids: Option<Vec<RefundId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub refunded_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2224,15 +2418,15 @@ pub struct Payment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub statement_description_identifier: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PaymentStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub team_member_id: Option<String>,
+    pub team_member_id: Option<TeamMemberId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tip_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub version_token: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2285,6 +2479,47 @@ pub struct BankAccountPaymentDetails {
     pub transfer_type: Option<String>,
 }
 
+/// Representation of a seller's bank account, used for payouts and verification.
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+pub struct BankAccount {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_number_suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<BankAccountType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bank_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creditable: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debit_mandate_reference_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debitable: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<LocationId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_bank_identification_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_bank_identification_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<BankAccountStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_ownership_type: Option<BankAccountOwnershipType>,
+}
+
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct ACHDetails {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2345,11 +2580,11 @@ pub struct CardPaymentDetails {
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct CardPaymentTimeline {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub authorized_at: Option<String>,
+    pub authorized_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub captured_at: Option<String>,
+    pub captured_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub voided_at: Option<String>,
+    pub voided_at: Option<Timestamp>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
@@ -2366,7 +2601,7 @@ pub struct ProcessingFee {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub amount_money: Option<Money>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub effective_at: Option<String>,
+    pub effective_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fee_type: Option<ProcessingFeeType>,
 }
@@ -2374,7 +2609,7 @@ pub struct ProcessingFee {
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct RiskEvaluation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub risk_level: Option<RiskEvaluationRiskLevel>,
 }
@@ -2399,12 +2634,278 @@ pub struct CashAppDetails {
     pub buyer_full_name: Option<String>,
 }
 
+/// A cash drawer shift, covering the cash movements of a single drawer from opening until it is
+/// closed. All money totals are expressed in the currency of the [Location](Location).
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+pub struct CashDrawerShift {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_paid_in_money: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_paid_out_money: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_payment_money: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_refunds_money: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_cash_money: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closing_team_member_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<CashDrawerDevice>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ending_team_member_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_cash_money: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<LocationId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opened_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opened_cash_money: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opening_team_member_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<CashDrawerShiftState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_member_ids: Option<Vec<TeamMemberId>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+pub struct CashDrawerDevice {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct CashDrawerShiftEvent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_money: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<CashDrawerEventType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_member_id: Option<TeamMemberId>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+pub struct Vendor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contacts: Option<Vec<VendorContact>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<VendorStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+pub struct VendorContact {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ordinal: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed: Option<bool>,
+}
+
+/// The result for a single [Vendor](Vendor) within a bulk create, retrieve or update response,
+/// keyed by the key that was used in the request.
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct VendorResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<ResponseError>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<Vendor>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct SearchVendorsFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Vec<VendorStatus>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct SearchVendorsSort {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SearchVendorsSortField>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
+pub struct WebhookSubscription {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// /// The API version of the subscription. If not set, the application's current API version
+    /// /// is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "update")]
+    pub api_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "update")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "update")]
+    pub event_types: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "update")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(one_of = "update")]
+    pub notification_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct SubscriptionTestResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct EventTypeMetadata {
+    pub event_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_version_introduced: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_status: Option<String>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct EventMetadata {
+    pub event_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+}
+
+/// An [Event](Event) as it is returned by the Events endpoint and as it is delivered to a
+/// webhook notification URL. A webhook receiver can deserialize the request body directly into
+/// an [Event](Event).
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct Event {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<EventData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<LocationId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merchant_id: Option<String>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<String>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize)]
+pub struct EventData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object: Option<EventObject>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<String>,
+}
+
+// The object of an event is keyed by the type of the object it holds (e.g. payment, booking),
+// which matches the tagging of the Response enum. Objects that have no typed representation
+// are kept as raw json.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EventObject {
+    Typed(Response),
+    Raw(serde_json::Value),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct SearchEventsQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<SearchEventsFilter>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SearchEventsSort>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct SearchEventsFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<TimeRange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_types: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_ids: Option<Vec<LocationId>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merchant_ids: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct SearchEventsSort {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SearchEventsSortField>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+}
+
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct Site {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2412,7 +2913,20 @@ pub struct Site {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub site_title: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
+}
+
+#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+pub struct Snippet {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, Default)]
@@ -2428,19 +2942,19 @@ pub struct TerminalCheckout {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cancel_reason: Option<ActionCancelReason>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer_id: Option<String>,
+    pub customer_id: Option<CustomerId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deadline_duration: Option<String>,
+    pub deadline_duration: Option<IsoDuration>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    pub location_id: Option<LocationId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub order_id: Option<String>,
+    pub order_id: Option<OrderId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub payment_ids: Option<Vec<String>>,
+    pub payment_ids: Option<Vec<PaymentId>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payment_options: Option<PaymentOptions>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2450,18 +2964,22 @@ pub struct TerminalCheckout {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<TerminalCheckoutStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
 }
 
-#[derive(Clone, Serialize, Debug, Deserialize, Default)]
+#[derive(Clone, Serialize, Debug, Deserialize, Default, SquareBuilder)]
 pub struct DeviceCheckoutOptions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[required]
     pub device_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(skip, preset(collect_signature = "true"))]
     pub collect_signature: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(skip, preset(show_itemized_cart = "true"))]
     pub show_itemized_cart: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(skip, preset(skip_receipt_screen = "true"))]
     pub skip_receipt_screen: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tip_settings: Option<TipSettings>,
@@ -2501,18 +3019,27 @@ pub struct PaymentOptions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub autocomplete: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub delay_duration: Option<String>,
+    pub delay_duration: Option<IsoDuration>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
 pub struct TerminalCheckoutQuery {
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(flatten(
+        created_at: Option<TimeRange>,
+        device_id: Option<String>,
+        status: Option<TerminalCheckoutStatus>,
+    ))]
     pub filter: Option<TerminalCheckoutQueryFilter>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(flatten(
+        #[builder(skip, preset(sort_ascending = "SortOrder::Asc", sort_descending = "SortOrder::Desc"))]
+        sort_order: Option<SortOrder>,
+    ))]
     pub sort: Option<TerminalCheckoutQuerySort>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct TerminalCheckoutQueryFilter {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created_at: Option<TimeRange>,
@@ -2522,7 +3049,7 @@ pub struct TerminalCheckoutQueryFilter {
     pub status: Option<TerminalCheckoutStatus>
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct TerminalCheckoutQuerySort {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sort_order: Option<SortOrder>,
@@ -2537,7 +3064,7 @@ pub struct TerminalRefund {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub device_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub payment_id: Option<String>,
+    pub payment_id: Option<PaymentId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2545,26 +3072,42 @@ pub struct TerminalRefund {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cancel_reason: Option<ActionCancelReason>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deadline_duration: Option<String>,
+    pub deadline_duration: Option<IsoDuration>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    pub location_id: Option<LocationId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub order_id: Option<String>,
+    pub order_id: Option<OrderId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub refund_id: Option<String>,
+    pub refund_id: Option<RefundId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TerminalCheckoutStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<TerminalCheckoutStatus>,
+    pub updated_at: Option<Timestamp>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
 pub struct TerminalRefundQuery {
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(flatten(
+        created_at: Option<TimeRange>,
+        device_id: Option<String>,
+        #[builder(skip, preset(
+            pending = "TerminalCheckoutStatus::Pending",
+            in_progress = "TerminalCheckoutStatus::InProgress",
+            cancel_requested = "TerminalCheckoutStatus::CancelRequested",
+            canceled = "TerminalCheckoutStatus::Canceled",
+            completed = "TerminalCheckoutStatus::Completed",
+        ))]
+        status: Option<TerminalCheckoutStatus>,
+    ))]
     pub filter: Option<TerminalRefundQueryFilter>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[builder(flatten(
+        #[builder(skip, preset(sort_ascending = "SortOrder::Asc", sort_descending = "SortOrder::Desc"))]
+        sort_order: Option<SortOrder>,
+    ))]
     pub sort: Option<TerminalCheckoutQuerySort>,
 }
 
@@ -2578,7 +3121,8 @@ pub struct TerminalRefundQueryFilter {
     pub status: Option<TerminalCheckoutStatus>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, SquareBuilder)]
+#[validate(with = "crate::builder::implementations::date_time_filter")]
 pub struct SearchOrdersQuery {
     pub filter: Option<SearchOrdersFilter>,
     pub sort: Option<SearchOrdersSort>,
@@ -2601,7 +3145,7 @@ pub struct SearchOrdersFilter {
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct SearchOrdersCustomerFilter {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub customer_ids: Option<String>,
+    pub customer_ids: Option<Vec<CustomerId>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
@@ -2689,9 +3233,23 @@ pub struct CustomerSort {
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct TimeRange {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_at: Option<String>,
+    pub end_at: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_at: Option<String>,
+    pub start_at: Option<Timestamp>,
+}
+
+impl TimeRange {
+    // records a violation at `path` for every bound that Square would not accept
+    pub(crate) fn check(&self, path: impl Into<FieldPath>, errors: &mut ValidationError) {
+        let path = path.into();
+
+        if let Some(end_at) = &self.end_at {
+            end_at.check(path.clone().field("end_at"), errors);
+        }
+        if let Some(start_at) = &self.start_at {
+            start_at.check(path.field("start_at"), errors);
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
@@ -2712,8 +3270,8 @@ pub struct CreationSource {
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
 pub struct StartAtRange {
-    pub end_at: String,
-    pub start_at: String,
+    pub end_at: Timestamp,
+    pub start_at: Timestamp,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize)]
@@ -2728,9 +3286,9 @@ pub struct AvailabilityQueryFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_at_range: Option<StartAtRange>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub booking_id: Option<String>,
+    pub booking_id: Option<BookingId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub location_id: Option<String>,
+    pub location_id: Option<LocationId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub segment_filters: Option<Vec<SegmentFilter>>
 }
\ No newline at end of file
@@ -0,0 +1,243 @@
+// Arithmetic and formatting of amounts of Money.
+
+use crate::errors::MoneyError;
+use crate::objects::enums::Currency;
+use crate::objects::Money;
+
+use std::fmt;
+
+impl Money {
+    /// Create an amount of [Money](Money), given in the smallest denomination of the currency.
+    ///
+    /// # Example
+    /// ```
+    /// use square_ox::objects::{enums::Currency, Money};
+    ///
+    /// let price = Money::new(1234, Currency::USD);
+    /// assert_eq!(price.to_string(), "$12.34");
+    /// ```
+    pub fn new(amount: i64, currency: Currency) -> Self {
+        Money {
+            amount: Some(amount),
+            currency,
+        }
+    }
+
+    /// Add two amounts of the same currency. A missing amount is treated as zero.
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.same_currency(other)?;
+
+        self.amount()
+            .checked_add(other.amount())
+            .map(|amount| Money::new(amount, self.currency.clone()))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Subtract an amount of the same currency. A missing amount is treated as zero.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.same_currency(other)?;
+
+        self.amount()
+            .checked_sub(other.amount())
+            .map(|amount| Money::new(amount, self.currency.clone()))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Multiply the amount, e.g. by the quantity of a line item.
+    pub fn checked_mul(&self, factor: i64) -> Result<Money, MoneyError> {
+        self.amount()
+            .checked_mul(factor)
+            .map(|amount| Money::new(amount, self.currency.clone()))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Split the amount into the given number of parts that add up to the amount. The remainder
+    /// is spread over the first parts, so splitting `$10.00` into three parts gives `$3.34`,
+    /// `$3.33` and `$3.33`.
+    pub fn split(&self, parts: usize) -> Result<Vec<Money>, MoneyError> {
+        if parts == 0 {
+            return Err(MoneyError::InvalidSplit);
+        }
+
+        let count = parts as i64;
+        let (share, remainder) = (self.amount() / count, self.amount() % count);
+
+        Ok((0..count)
+            .map(|i| {
+                let extra = if i < remainder.abs() { remainder.signum() } else { 0 };
+
+                Money::new(share + extra, self.currency.clone())
+            })
+            .collect())
+    }
+
+    fn amount(&self) -> i64 {
+        self.amount.unwrap_or(0)
+    }
+
+    fn same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency.code() == other.currency.code() {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch(self.currency.clone(), other.currency.clone()))
+        }
+    }
+}
+
+/// The conventions of a locale for writing amounts of [Money](Money), see
+/// [Money::format](Money::format).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoneyLocale {
+    /// Separates the whole units from the minor units.
+    pub decimal_separator: char,
+    /// Separates each group of three digits of the whole units, if any.
+    pub group_separator: Option<char>,
+    /// Writes the currency symbol after the number, separated by a space, instead of before it.
+    pub symbol_after: bool,
+}
+
+impl MoneyLocale {
+    /// English (United States), e.g. `$1,234.56`.
+    pub const EN_US: MoneyLocale = MoneyLocale {
+        decimal_separator: '.',
+        group_separator: Some(','),
+        symbol_after: false,
+    };
+
+    /// English (United Kingdom), e.g. `£1,234.56`.
+    pub const EN_GB: MoneyLocale = MoneyLocale::EN_US;
+
+    /// German (Germany), e.g. `1.234,56 €`.
+    pub const DE_DE: MoneyLocale = MoneyLocale {
+        decimal_separator: ',',
+        group_separator: Some('.'),
+        symbol_after: true,
+    };
+
+    /// French (France), e.g. `1 234,56 €` with a narrow no-break space between the groups.
+    pub const FR_FR: MoneyLocale = MoneyLocale {
+        decimal_separator: ',',
+        group_separator: Some('\u{202f}'),
+        symbol_after: true,
+    };
+
+    /// Japanese (Japan), e.g. `¥1,234`.
+    pub const JA_JP: MoneyLocale = MoneyLocale::EN_US;
+
+    // the conventions of the Display implementation of Money
+    const PLAIN: MoneyLocale = MoneyLocale {
+        decimal_separator: '.',
+        group_separator: None,
+        symbol_after: false,
+    };
+}
+
+impl Money {
+    /// Format the amount by the conventions of a [MoneyLocale](MoneyLocale). Currencies without a
+    /// symbol are written with their code after the number.
+    ///
+    /// # Example
+    /// ```
+    /// use square_ox::objects::{enums::Currency, Money, MoneyLocale};
+    ///
+    /// assert_eq!(Money::new(1234, Currency::EUR).format(&MoneyLocale::DE_DE), "12,34 €");
+    /// assert_eq!(Money::new(123456, Currency::USD).format(&MoneyLocale::EN_US), "$1,234.56");
+    /// ```
+    pub fn format(&self, locale: &MoneyLocale) -> String {
+        let amount = self.amount();
+        let exponent = self.currency.exponent();
+        let sign = if amount < 0 { "-" } else { "" };
+        let divisor = 10u64.pow(exponent);
+        let (units, minor_units) = (amount.unsigned_abs() / divisor, amount.unsigned_abs() % divisor);
+
+        let mut number = units.to_string();
+        if let Some(separator) = locale.group_separator {
+            let digits: Vec<char> = number.chars().collect();
+            number = digits
+                .rchunks(3)
+                .rev()
+                .map(|group| group.iter().collect::<String>())
+                .collect::<Vec<String>>()
+                .join(&separator.to_string());
+        }
+        if exponent > 0 {
+            number = format!("{}{}{:0width$}", number, locale.decimal_separator, minor_units,
+                             width = exponent as usize);
+        }
+
+        match self.currency.symbol() {
+            Some(symbol) if locale.symbol_after => format!("{}{} {}", sign, number, symbol),
+            Some(symbol) => format!("{}{}{}", sign, symbol, number),
+            None => format!("{}{} {}", sign, number, self.currency.code()),
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    /// Formats the amount with the symbol of its currency, without grouping the digits and with a
+    /// `.` before the minor units, e.g. `$12.34`, `-€0.50` or `¥1234`. Currencies without a symbol
+    /// are written with their code, e.g. `12.34 CHF`. Use [Money::format](Money::format) for the
+    /// conventions of a locale.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(&MoneyLocale::PLAIN))
+    }
+}
+
+#[cfg(test)]
+mod test_money {
+    use super::*;
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let price = Money::new(1234, Currency::USD);
+
+        assert_eq!(price.checked_add(&Money::new(66, Currency::USD)), Ok(Money::new(1300, Currency::USD)));
+        assert_eq!(price.checked_sub(&Money::new(1300, Currency::USD)), Ok(Money::new(-66, Currency::USD)));
+        assert_eq!(price.checked_mul(3), Ok(Money::new(3702, Currency::USD)));
+        assert_eq!(Money::new(i64::MAX, Currency::USD).checked_mul(2), Err(MoneyError::Overflow));
+        assert_eq!(
+            price.checked_add(&Money::new(1234, Currency::EUR)),
+            Err(MoneyError::CurrencyMismatch(Currency::USD, Currency::EUR))
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let parts = Money::new(1000, Currency::USD).split(3).unwrap();
+        let amounts: Vec<i64> = parts.iter().filter_map(|part| part.amount).collect();
+
+        assert_eq!(amounts, vec![334, 333, 333]);
+        assert_eq!(Money::new(-1000, Currency::USD).split(3).unwrap()[0].amount, Some(-334));
+        assert_eq!(Money::new(1000, Currency::USD).split(0), Err(MoneyError::InvalidSplit));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Money::new(1234, Currency::USD).to_string(), "$12.34");
+        assert_eq!(Money::new(1234, Currency::JPY).to_string(), "¥1234");
+        assert_eq!(Money::new(-50, Currency::EUR).to_string(), "-€0.50");
+        assert_eq!(Money::new(1234, Currency::KWD).to_string(), "1.234 KWD");
+        assert_eq!(Money::new(1234, Currency::CHF).to_string(), "12.34 CHF");
+        assert_eq!(Money::new(123456789, Currency::USD).to_string(), "$1234567.89");
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Money::new(1234, Currency::EUR).format(&MoneyLocale::DE_DE), "12,34 €");
+        assert_eq!(Money::new(-123456789, Currency::EUR).format(&MoneyLocale::DE_DE), "-1.234.567,89 €");
+        assert_eq!(Money::new(123456, Currency::EUR).format(&MoneyLocale::FR_FR), "1\u{202f}234,56 €");
+        assert_eq!(Money::new(123456, Currency::USD).format(&MoneyLocale::EN_US), "$1,234.56");
+        assert_eq!(Money::new(123456, Currency::JPY).format(&MoneyLocale::JA_JP), "¥123,456");
+        assert_eq!(Money::new(5, Currency::GBP).format(&MoneyLocale::EN_GB), "£0.05");
+        assert_eq!(Money::new(123456, Currency::CHF).format(&MoneyLocale::DE_DE), "1.234,56 CHF");
+    }
+
+    #[test]
+    fn test_deserialize_unknown_currency() {
+        let money: Money = serde_json::from_str(r#"{"amount":100,"currency":"XYZ"}"#).unwrap();
+
+        assert_eq!(money.currency, Currency::Other("XYZ".to_string()));
+        assert_eq!(serde_json::to_string(&money).unwrap(), r#"{"amount":100,"currency":"XYZ"}"#);
+        assert_eq!(serde_json::to_string(&Currency::CAD).unwrap(), r#""CAD""#);
+    }
+}
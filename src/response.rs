@@ -6,9 +6,12 @@ The most of the structs have almost all of their fields set as optional as this
 with the [Square API](https://developer.squareup.com)'s response pattern more manageable.
  */
 
+use crate::errors::ChunkError;
+use crate::objects::Response;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
 #[non_exhaustive]
 /// The [SquareResponse](SquareResponse) response defines the generic response type that encompasses
 /// almost all possible [Square API](https://developer.squareup.com) responses. All fields are
@@ -39,6 +42,12 @@ pub struct SquareResponse {
     pub deleted_at: Option<String>,
     #[serde(default)]
     pub latest_time: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// The version of the [Square API](https://developer.squareup.com) that handled the request,
+    /// as returned in the `Square-Version` header of the response.
+    #[serde(skip)]
+    pub square_version: Option<String>,
 }
 
 
@@ -53,4 +62,142 @@ pub struct ResponseError {
     pub detail: Option<String>,
     #[serde(default)]
     pub field: Option<String>,
-}
\ No newline at end of file
+}
+
+impl SquareResponse {
+    // Merges the response of another chunk or page of the same request into this one. List
+    // responses of the same kind are concatenated, any other response replaces the one of the same
+    // kind or fills the next free response field. The merged response has no cursor, as the
+    // cursors of the chunks are not valid for the merged request. Fails without changing this
+    // response if the merged responses do not fit into the response fields.
+    pub(crate) fn merge(&mut self, other: SquareResponse) -> Result<(), ChunkError> {
+        let mut responses: Vec<Response> = IntoIterator::into_iter([
+            self.response.take(),
+            self.opt_response01.take(),
+            self.opt_response02.take(),
+            self.opt_response03.take(),
+        ]).flatten().collect();
+        let other_responses: Vec<Response> = IntoIterator::into_iter([
+            other.response,
+            other.opt_response01,
+            other.opt_response02,
+            other.opt_response03,
+        ]).flatten().collect();
+
+        let mut kinds = vec![];
+        for kind in responses.iter().chain(&other_responses).map(std::mem::discriminant) {
+            if !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+        }
+        if kinds.len() > 4 {
+            self.set_responses(responses);
+            return Err(ChunkError::TooManyResponseKinds);
+        }
+
+        for response in other_responses {
+            merge_response(&mut responses, response);
+        }
+        self.set_responses(responses);
+
+        if let Some(errors) = other.errors {
+            self.errors.get_or_insert_with(Vec::new).extend(errors);
+        }
+        self.cursor = None;
+
+        if let Some(id_mapping) = other.id_mapping {
+            self.id_mapping.get_or_insert_with(Vec::new).extend(id_mapping);
+        }
+        if let Some(deleted_object_ids) = other.deleted_object_ids {
+            self.deleted_object_ids.get_or_insert_with(Vec::new).extend(deleted_object_ids);
+        }
+        if other.deleted_at.is_some() {
+            self.deleted_at = other.deleted_at;
+        }
+        if other.updated_at.is_some() {
+            self.updated_at = other.updated_at;
+        }
+        if self.square_version.is_none() {
+            self.square_version = other.square_version;
+        }
+
+        Ok(())
+    }
+
+    fn set_responses(&mut self, responses: Vec<Response>) {
+        let mut responses = responses.into_iter();
+        self.response = responses.next();
+        self.opt_response01 = responses.next();
+        self.opt_response02 = responses.next();
+        self.opt_response03 = responses.next();
+    }
+}
+
+// concatenates the list responses of the given kinds, returns any other response
+macro_rules! extend_lists {
+    ($existing:expr, $response:expr, [$($kind:ident),*], [$($outer:ident($nested:ident::$variant:ident)),*]) => {
+        match ($existing, $response) {
+            $((Response::$kind(list), Response::$kind(other)) => {
+                list.extend(other);
+                None
+            })*
+            $((Response::$outer($nested::$variant(list)), Response::$outer($nested::$variant(other))) => {
+                list.extend(other);
+                None
+            })*
+            (_, response) => Some(response),
+        }
+    };
+}
+
+fn merge_response(responses: &mut Vec<Response>, mut response: Response) {
+    use crate::objects::{BookingsEnum, MetadataEnum, TeamMemberBookingProfilesEnum};
+
+    for existing in responses.iter_mut() {
+        response = match extend_lists!(&mut *existing, response, [
+            Orders, OrderEntries, Locations, Customers, Objects, Items, MatchedVariationIds,
+            RelatedObjects, IdMappings, Availabilities, LocationBookingProfiles, Cards,
+            PaymentLinks, Counts, Changes, Sites, Checkouts, BankAccounts, CashDrawerShifts,
+            CashDrawerShiftEvents, Vendors, Responses, Subscriptions, EventTypes, Events
+        ], [
+            Bookings(BookingsEnum::List), Bookings(BookingsEnum::Map),
+            TeamMemberBookingProfiles(TeamMemberBookingProfilesEnum::List),
+            TeamMemberBookingProfiles(TeamMemberBookingProfilesEnum::Map),
+            Metadata(MetadataEnum::EventTypes), Metadata(MetadataEnum::Events)
+        ]) {
+            None => return,
+            Some(response) if std::mem::discriminant(existing) == std::mem::discriminant(&response) => {
+                return *existing = response
+            }
+            Some(response) => response,
+        }
+    }
+
+    responses.push(response);
+}
+
+/// The merged result of a request that was split into multiple chunks to stay within the
+/// [Square API](https://developer.squareup.com)'s per-request limits.
+///
+/// `response` holds the merged responses of all successful chunks, while `failures` holds the
+/// error and the inputs of every chunk that failed, so that they can be inspected or retried.
+#[derive(Debug)]
+pub struct ChunkedResponse<T> {
+    pub response: SquareResponse,
+    pub failures: Vec<ChunkFailure<T>>,
+}
+
+impl<T> ChunkedResponse<T> {
+    /// Returns true if every chunk of the request succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// A chunk of a [ChunkedResponse](ChunkedResponse) that failed, together with the inputs that
+/// were sent in the chunk.
+#[derive(Debug)]
+pub struct ChunkFailure<T> {
+    pub inputs: Vec<T>,
+    pub error: ChunkError,
+}
@@ -25,7 +25,7 @@ async fn test_list_customer() {
 
     let sut = square_ox::client::SquareClient::new(&access_token);
 
-    let input = CustomerListParametersBuilder::new().build().await;
+    let input = CustomerListParametersBuilder::new().build_sync();
 
     assert!(input.is_ok());
 
@@ -41,7 +41,7 @@ async fn test_list_catalog() {
 
     let sut = square_ox::client::SquareClient::new(&access_token);
 
-    let input = catalog::CatalogListParameterBuilder::new().build().await;
+    let input = catalog::CatalogListParameterBuilder::new().build_sync();
 
     let res = sut.catalog()
         .list(Some(input))